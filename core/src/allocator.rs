@@ -2,8 +2,10 @@
 
 use crate::qjs;
 
+mod counting;
 mod rust;
 
+pub use counting::{AllocatorStats, CountingAllocator};
 pub use rust::RustAllocator;
 
 /// The allocator interface
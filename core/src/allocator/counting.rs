@@ -0,0 +1,224 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use super::Allocator;
+
+#[derive(Default, Debug)]
+struct Counters {
+    live_bytes: AtomicUsize,
+    live_allocations: AtomicUsize,
+    peak_bytes: AtomicUsize,
+}
+
+impl Counters {
+    fn add(&self, size: usize) {
+        let live = self.live_bytes.fetch_add(size, Ordering::AcqRel) + size;
+        self.live_allocations.fetch_add(1, Ordering::AcqRel);
+        self.peak_bytes.fetch_max(live, Ordering::AcqRel);
+    }
+
+    fn sub(&self, size: usize) {
+        self.live_bytes.fetch_sub(size, Ordering::AcqRel);
+        self.live_allocations.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// A handle to the counters of a [`CountingAllocator`].
+///
+/// The handle can be cloned and kept around after the allocator has been moved into a runtime.
+/// All sizes are the usable sizes reported by the wrapped allocator.
+#[derive(Clone, Default, Debug)]
+pub struct AllocatorStats(Arc<Counters>);
+
+impl AllocatorStats {
+    /// Returns the number of bytes currently allocated.
+    pub fn live_bytes(&self) -> usize {
+        self.0.live_bytes.load(Ordering::Acquire)
+    }
+
+    /// Returns the number of allocations which are currently not freed.
+    pub fn live_allocations(&self) -> usize {
+        self.0.live_allocations.load(Ordering::Acquire)
+    }
+
+    /// Returns the highest number of bytes which were allocated at the same time.
+    pub fn peak_bytes(&self) -> usize {
+        self.0.peak_bytes.load(Ordering::Acquire)
+    }
+}
+
+/// An allocator which wraps another allocator and keeps track of the memory it hands out.
+///
+/// ```
+/// # use rquickjs::{allocator::{CountingAllocator, RustAllocator}, Runtime};
+/// let allocator = CountingAllocator::new(RustAllocator);
+/// let stats = allocator.stats();
+/// let rt = Runtime::new_with_alloc(allocator).unwrap();
+/// assert!(stats.live_bytes() > 0);
+/// ```
+pub struct CountingAllocator<A> {
+    inner: A,
+    stats: AllocatorStats,
+}
+
+impl<A> CountingAllocator<A> {
+    /// Wrap an allocator.
+    pub fn new(inner: A) -> Self {
+        CountingAllocator {
+            inner,
+            stats: AllocatorStats::default(),
+        }
+    }
+
+    /// Returns a handle to the counters of this allocator.
+    pub fn stats(&self) -> AllocatorStats {
+        self.stats.clone()
+    }
+}
+
+unsafe impl<A: Allocator> Allocator for CountingAllocator<A> {
+    fn alloc(&mut self, size: usize) -> *mut u8 {
+        let res = self.inner.alloc(size);
+        if !res.is_null() {
+            self.stats.0.add(unsafe { A::usable_size(res) });
+        }
+        res
+    }
+
+    fn calloc(&mut self, count: usize, size: usize) -> *mut u8 {
+        let res = self.inner.calloc(count, size);
+        if !res.is_null() {
+            self.stats.0.add(unsafe { A::usable_size(res) });
+        }
+        res
+    }
+
+    unsafe fn dealloc(&mut self, ptr: *mut u8) {
+        self.stats.0.sub(A::usable_size(ptr));
+        self.inner.dealloc(ptr);
+    }
+
+    unsafe fn realloc(&mut self, ptr: *mut u8, new_size: usize) -> *mut u8 {
+        let old_size = if ptr.is_null() {
+            None
+        } else {
+            Some(A::usable_size(ptr))
+        };
+
+        let res = self.inner.realloc(ptr, new_size);
+        match old_size {
+            // Like `realloc` from libc, reallocating to zero bytes may free the memory.
+            Some(old_size) if res.is_null() && new_size == 0 => self.stats.0.sub(old_size),
+            _ if res.is_null() => {}
+            Some(old_size) => {
+                self.stats.0.sub(old_size);
+                self.stats.0.add(A::usable_size(res));
+            }
+            None => self.stats.0.add(A::usable_size(res)),
+        }
+        res
+    }
+
+    unsafe fn usable_size(ptr: *mut u8) -> usize
+    where
+        Self: Sized,
+    {
+        A::usable_size(ptr)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CountingAllocator;
+    use crate::{
+        allocator::{Allocator, RustAllocator},
+        Context, Runtime,
+    };
+    use std::ptr;
+
+    #[test]
+    fn live_bytes_follow_gc() {
+        let allocator = CountingAllocator::new(RustAllocator);
+        let stats = allocator.stats();
+        let rt = Runtime::new_with_alloc(allocator).unwrap();
+        let context = Context::full(&rt).unwrap();
+        rt.run_gc();
+
+        let before = stats.live_bytes();
+        context.with(|ctx| {
+            ctx.eval::<(), _>(
+                r#"
+                globalThis.objects = [];
+                for(let i = 0;i < 10_000;i++){
+                    objects.push({ i });
+                }
+            "#,
+            )
+            .unwrap();
+        });
+        let allocated = stats.live_bytes();
+        assert!(allocated > before + 10_000);
+        assert!(stats.peak_bytes() >= allocated);
+
+        context.with(|ctx| {
+            ctx.eval::<(), _>("delete globalThis.objects").unwrap();
+        });
+        rt.run_gc();
+        assert!(stats.live_bytes() < allocated);
+
+        drop(context);
+        drop(rt);
+        assert_eq!(stats.live_bytes(), 0);
+        assert_eq!(stats.live_allocations(), 0);
+    }
+
+    /// An allocator which frees the memory when reallocating to zero bytes, like some libc
+    /// implementations do.
+    struct FreeOnZero;
+
+    unsafe impl Allocator for FreeOnZero {
+        fn alloc(&mut self, size: usize) -> *mut u8 {
+            RustAllocator.alloc(size)
+        }
+
+        fn calloc(&mut self, count: usize, size: usize) -> *mut u8 {
+            RustAllocator.calloc(count, size)
+        }
+
+        unsafe fn dealloc(&mut self, ptr: *mut u8) {
+            RustAllocator.dealloc(ptr)
+        }
+
+        unsafe fn realloc(&mut self, ptr: *mut u8, new_size: usize) -> *mut u8 {
+            if new_size == 0 {
+                RustAllocator.dealloc(ptr);
+                return ptr::null_mut();
+            }
+            RustAllocator.realloc(ptr, new_size)
+        }
+
+        unsafe fn usable_size(ptr: *mut u8) -> usize {
+            RustAllocator::usable_size(ptr)
+        }
+    }
+
+    #[test]
+    fn realloc_to_zero_frees() {
+        let mut allocator = CountingAllocator::new(FreeOnZero);
+        let stats = allocator.stats();
+
+        let ptr = allocator.alloc(64);
+        assert!(!ptr.is_null());
+        let ptr = unsafe { allocator.realloc(ptr, 128) };
+        assert!(!ptr.is_null());
+        assert_eq!(stats.live_allocations(), 1);
+        assert!(stats.live_bytes() >= 128);
+
+        let ptr = unsafe { allocator.realloc(ptr, 0) };
+        assert!(ptr.is_null());
+        assert_eq!(stats.live_allocations(), 0);
+        assert_eq!(stats.live_bytes(), 0);
+    }
+}
@@ -1,6 +1,7 @@
 //! JavaScript classes defined from Rust.
 
 use crate::{
+    atom::PredefinedAtom,
     function::Params,
     qjs::{self},
     value::Constructor,
@@ -50,6 +51,26 @@ pub trait JsClass<'js>: Trace<'js> + JsLifetime<'js> + Sized {
     }
 }
 
+/// The JavaScript name of a generic class.
+///
+/// The class macro can't derive a name for every instantiation of a generic type, so for a
+/// generic class it uses [`ClassName::NAME`] as [`JsClass::NAME`] unless the class is renamed.
+/// Implement this trait for each instantiation which is exposed to JavaScript.
+///
+/// ```
+/// # use rquickjs::class::ClassName;
+/// // With `#[rquickjs::class]` applied to the struct.
+/// pub struct Wrapper<T>(T);
+///
+/// impl ClassName for Wrapper<u32> {
+///     const NAME: &'static str = "WrappedU32";
+/// }
+/// ```
+pub trait ClassName {
+    /// The name the constructor has in JavaScript
+    const NAME: &'static str;
+}
+
 /// A object which is instance of a Rust class.
 #[repr(transparent)]
 pub struct Class<'js, C: JsClass<'js>>(pub(crate) Object<'js>, PhantomData<C>);
@@ -148,9 +169,18 @@ impl<'js, C: JsClass<'js>> Class<'js, C> {
         unsafe { ctx.get_opaque().get_or_insert_prototype::<C>(ctx) }
     }
 
+    /// Returns the name of the class, i.e. [`JsClass::NAME`].
+    pub fn name() -> &'static str {
+        C::NAME
+    }
+
     /// Create a constructor for the current class using its definition.
+    ///
+    /// The `name` of the returned constructor is always set to [`JsClass::NAME`].
     pub fn create_constructor(ctx: &Ctx<'js>) -> Result<Option<Constructor<'js>>> {
-        C::constructor(ctx)
+        C::constructor(ctx)?
+            .map(|constr| constr.with_name(C::NAME))
+            .transpose()
     }
 
     /// Defines the predefined constructor of this class, if there is one, onto the given object.
@@ -161,6 +191,32 @@ impl<'js, C: JsClass<'js>> Class<'js, C> {
         Ok(())
     }
 
+    /// Defines the predefined constructor of this class onto the given object, unless it is
+    /// already defined.
+    ///
+    /// A constructor counts as already defined when the object has a property named
+    /// [`JsClass::NAME`] whose `prototype` is the prototype of this class. In that case the
+    /// existing value is left untouched, even if it was modified after being defined.
+    ///
+    /// Returns whether the constructor was defined by this call.
+    pub fn define_once(object: &Object<'js>) -> Result<bool> {
+        let ctx = object.ctx();
+        if let Some(proto) = Self::prototype(ctx)? {
+            let existing: Value = object.get(C::NAME)?;
+            if let Some(existing) = existing.as_object() {
+                let existing_proto: Value = existing.get(PredefinedAtom::Prototype)?;
+                if existing_proto.as_object() == Some(&proto) {
+                    return Ok(false);
+                }
+            }
+        }
+        let Some(constructor) = Self::create_constructor(ctx)? else {
+            return Ok(false);
+        };
+        object.set(C::NAME, constructor)?;
+        Ok(true)
+    }
+
     /// Returns a reference to the underlying object contained in a cell.
     #[inline]
     pub(crate) fn get_class_cell<'a>(&self) -> &'a ClassCell<JsCell<'js, C>> {
@@ -522,6 +578,81 @@ mod test {
 
             let name: String = ctx.eval("new Vec3(1,2,3).constructor.name").unwrap();
             assert_eq!(name, Vec3::NAME);
+            let length: usize = ctx.eval("Vec3.length").unwrap();
+            assert_eq!(length, 3);
+        })
+    }
+
+    #[test]
+    fn constructor_name_and_length() {
+        pub struct Point;
+
+        impl<'js> Trace<'js> for Point {
+            fn trace<'a>(&self, _tracer: Tracer<'a, 'js>) {}
+        }
+
+        unsafe impl<'js> JsLifetime<'js> for Point {
+            type Changed<'to> = Point;
+        }
+
+        impl<'js> JsClass<'js> for Point {
+            const NAME: &'static str = "Point";
+
+            type Mutable = Readable;
+
+            fn prototype(ctx: &crate::Ctx<'js>) -> crate::Result<Option<crate::Object<'js>>> {
+                Object::new(ctx.clone()).map(Some)
+            }
+
+            fn constructor(ctx: &crate::Ctx<'js>) -> crate::Result<Option<Constructor<'js>>> {
+                let constr = Constructor::new_class::<Point, _, _>(
+                    ctx.clone(),
+                    |ctx: crate::Ctx<'js>, _x: f64, _y: f64| Class::instance(ctx, Point),
+                )?
+                .with_name("SomethingElse")?;
+                Ok(Some(constr))
+            }
+        }
+
+        test_with(|ctx| {
+            assert_eq!(Class::<Point>::name(), "Point");
+
+            let constr = Class::<Point>::create_constructor(&ctx).unwrap().unwrap();
+            ctx.globals().set(Class::<Point>::name(), constr).unwrap();
+
+            let res: String = ctx
+                .eval("[Point.name, Point.length, new Point(1, 2) instanceof Point].join()")
+                .catch(&ctx)
+                .unwrap();
+            assert_eq!(res, "Point,2,true");
+        })
+    }
+
+    #[test]
+    fn define_once() {
+        test_with(|ctx| {
+            let globals = ctx.globals();
+            assert!(Class::<Vec3>::define_once(&globals).unwrap());
+
+            ctx.eval::<(), _>("Vec3.patched = true;")
+                .catch(&ctx)
+                .unwrap();
+            assert!(!Class::<Vec3>::define_once(&globals).unwrap());
+
+            let res: bool = ctx
+                .eval("Vec3.patched === true && new Vec3(1, 2, 3) instanceof Vec3")
+                .catch(&ctx)
+                .unwrap();
+            assert!(res);
+
+            // An unrelated value with the same name is replaced.
+            globals.set("Vec3", 1).unwrap();
+            assert!(Class::<Vec3>::define_once(&globals).unwrap());
+            let res: bool = ctx
+                .eval("new Vec3(1, 2, 3) instanceof Vec3")
+                .catch(&ctx)
+                .unwrap();
+            assert!(res);
         })
     }
 
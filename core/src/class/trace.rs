@@ -1,5 +1,7 @@
 use super::JsClass;
-use crate::{markers::Invariant, qjs, Class, Ctx, Module, Value};
+use crate::{
+    markers::Invariant, qjs, Class, Ctx, JsLifetime, Module, Persistent, PersistentFunction, Value,
+};
 use std::marker::PhantomData;
 
 #[cfg(feature = "either")]
@@ -72,6 +74,33 @@ impl<'js> Trace<'js> for Value<'js> {
     }
 }
 
+/// Traces the persisted value if it belongs to the runtime being traced.
+///
+/// A traced `Persistent` is treated as a normal reference held by the object containing it, so
+/// the GC is able to collect cycles running through it. The reference itself is released when
+/// the `Persistent` is dropped, i.e. when the finalizer of the containing class drops the class.
+impl<'js, T> Trace<'js> for Persistent<T>
+where
+    T: JsLifetime<'static>,
+    T::Changed<'js>: Trace<'js>,
+{
+    fn trace<'a>(&self, tracer: Tracer<'a, 'js>) {
+        if self.rt != tracer.rt {
+            return;
+        }
+        // Safety: The value belongs to the runtime currently being traced so it is valid for
+        // `'js`, and `JsLifetime` ensures both types have the same layout.
+        let value = unsafe { &*(&self.value as *const T).cast::<T::Changed<'js>>() };
+        value.trace(tracer)
+    }
+}
+
+impl<'js> Trace<'js> for PersistentFunction {
+    fn trace<'a>(&self, tracer: Tracer<'a, 'js>) {
+        self.0.trace(tracer)
+    }
+}
+
 impl<'js> Trace<'js> for Ctx<'js> {
     fn trace<'a>(&self, tracer: Tracer<'a, 'js>) {
         tracer.mark_ctx(self);
@@ -87,6 +116,12 @@ where
     }
 }
 
+impl<'js> Trace<'js> for crate::Map<'js> {
+    fn trace<'a>(&self, tracer: Tracer<'a, 'js>) {
+        self.as_object().trace(tracer)
+    }
+}
+
 impl<'js, T> Trace<'js> for Module<'js, T> {
     fn trace<'a>(&self, _tracer: Tracer<'a, 'js>) {}
 }
@@ -117,6 +152,17 @@ where
     }
 }
 
+#[cfg(feature = "array-buffer")]
+#[cfg_attr(
+    feature = "doc-cfg",
+    doc(cfg(all(feature = "classes", feature = "array-buffer")))
+)]
+impl<'js, T> Trace<'js> for crate::TypedArray<'js, T> {
+    fn trace<'a>(&self, tracer: Tracer<'a, 'js>) {
+        self.as_value().trace(tracer)
+    }
+}
+
 macro_rules! trace_impls {
 
     (primitive: $( $(#[$meta:meta])* $($type:ident)::+$(<$lt:lifetime>)?,)*) => {
@@ -222,6 +268,11 @@ trace_impls! {
     crate::Symbol,
     crate::Exception,
     crate::String,
+    crate::Promise,
+    crate::function::Constructor,
+    #[cfg(feature = "array-buffer")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(all(feature = "classes", feature = "array-buffer"))))]
+    crate::ArrayBuffer,
 }
 
 trace_impls! {
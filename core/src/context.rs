@@ -4,6 +4,7 @@ mod base;
 mod builder;
 mod ctx;
 mod r#ref;
+mod snapshot;
 
 #[cfg(feature = "futures")]
 mod r#async;
@@ -24,7 +25,8 @@ pub trait MultiWith<'js> {
 
 pub use base::Context;
 pub use builder::{intrinsic, ContextBuilder, Intrinsic};
-pub use ctx::{Ctx, EvalOptions};
+pub use ctx::{Ctx, EvalOptions, SourceEncoding};
+pub use snapshot::GlobalSnapshot;
 
 #[cfg(feature = "futures")]
 pub use r#async::AsyncContext;
@@ -1,5 +1,10 @@
 use super::{intrinsic, r#ref::ContextRef, ContextBuilder, Intrinsic};
-use crate::{markers::ParallelSend, qjs, runtime::AsyncRuntime, Ctx, Error, Result};
+use crate::{
+    markers::ParallelSend,
+    qjs,
+    runtime::{AsyncRuntime, ContextUserData},
+    Ctx, Error, Result,
+};
 use std::{future::Future, mem, pin::Pin, ptr::NonNull};
 
 mod future;
@@ -87,13 +92,15 @@ macro_rules! async_with{
 pub(crate) struct Inner {
     pub(crate) ctx: NonNull<qjs::JSContext>,
     pub(crate) rt: AsyncRuntime,
+    pub(crate) userdata: ContextUserData,
 }
 
 impl Clone for Inner {
     fn clone(&self) -> Inner {
         let ctx = unsafe { NonNull::new_unchecked(qjs::JS_DupContext(self.ctx.as_ptr())) };
         let rt = self.rt.clone();
-        Self { ctx, rt }
+        let userdata = self.userdata.clone();
+        Self { ctx, rt, userdata }
     }
 }
 
@@ -118,6 +125,8 @@ impl Drop for Inner {
                         // following assertion to trigger
                         assert!(std::thread::panicking());
                     }
+                    // The runtime is locked by this thread.
+                    unsafe { self.userdata.detach(self.ctx.as_ptr()) };
                     unsafe { qjs::JS_FreeContext(self.ctx.as_ptr()) }
                     return;
                 }
@@ -132,6 +141,7 @@ impl Drop for Inner {
             }
         };
         guard.runtime.update_stack_top();
+        unsafe { self.userdata.detach(self.ctx.as_ptr()) };
         unsafe { qjs::JS_FreeContext(self.ctx.as_ptr()) }
         // Explicitly drop the guard to ensure it is valid during the entire use of runtime
         mem::drop(guard);
@@ -153,7 +163,9 @@ impl AsyncContext {
     /// The context must also have valid reference count, one which can be decremented when this
     /// object is dropped without going negative.
     pub unsafe fn from_raw(ctx: NonNull<qjs::JSContext>, rt: AsyncRuntime) -> Self {
-        AsyncContext(ContextRef::new(Inner { ctx, rt }))
+        let rt = rt.context_handle();
+        let userdata = ContextUserData::attach(ctx.as_ptr());
+        AsyncContext(ContextRef::new(Inner { ctx, rt, userdata }))
     }
 
     /// Creates a base context with only the required functions registered.
@@ -173,7 +185,8 @@ impl AsyncContext {
         unsafe { I::add_intrinsic(ctx) };
         let res = Inner {
             ctx,
-            rt: runtime.clone(),
+            rt: runtime.context_handle(),
+            userdata: unsafe { ContextUserData::attach(ctx.as_ptr()) },
         };
         guard.drop_pending();
         mem::drop(guard);
@@ -190,7 +203,8 @@ impl AsyncContext {
             .ok_or_else(|| Error::Allocation)?;
         let res = Inner {
             ctx,
-            rt: runtime.clone(),
+            rt: runtime.context_handle(),
+            userdata: unsafe { ContextUserData::attach(ctx.as_ptr()) },
         };
         // Explicitly drop the guard to ensure it is valid during the entire use of runtime
         guard.drop_pending();
@@ -1,17 +1,50 @@
 use super::{ctx::RefCountHeader, intrinsic, r#ref::ContextRef, ContextBuilder, Intrinsic};
-use crate::{qjs, Ctx, Error, Result, Runtime};
+use crate::{qjs, runtime::ContextUserData, Ctx, Error, Result, Runtime};
 use std::{mem, ptr::NonNull};
 
 pub(crate) struct Inner {
     pub(crate) ctx: NonNull<qjs::JSContext>,
     pub(crate) rt: Runtime,
+    pub(crate) userdata: ContextUserData,
 }
 
 impl Clone for Inner {
     fn clone(&self) -> Inner {
         let ctx = unsafe { NonNull::new_unchecked(qjs::JS_DupContext(self.ctx.as_ptr())) };
         let rt = self.rt.clone();
-        Self { ctx, rt }
+        let userdata = self.userdata.clone();
+        Self { ctx, rt, userdata }
+    }
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        //TODO
+        let guard = match self.rt.inner.try_lock() {
+            Some(x) => x,
+            None => {
+                let p = unsafe { &mut *(self.ctx.as_ptr() as *mut RefCountHeader) };
+                if p.ref_count <= 1 {
+                    // Lock was poisoned, this should only happen on a panic.
+                    // We should still free the context.
+                    // TODO see if there is a way to recover from a panic which could cause the
+                    // following assertion to trigger
+                    assert!(std::thread::panicking());
+                }
+                // The runtime is locked by this thread.
+                #[cfg(not(feature = "parallel"))]
+                unsafe {
+                    self.userdata.detach(self.ctx.as_ptr())
+                };
+                unsafe { qjs::JS_FreeContext(self.ctx.as_ptr()) }
+                return;
+            }
+        };
+        guard.update_stack_top();
+        unsafe { self.userdata.detach(self.ctx.as_ptr()) };
+        unsafe { qjs::JS_FreeContext(self.ctx.as_ptr()) }
+        // Explicitly drop the guard to ensure it is valid during the entire use of runtime
+        mem::drop(guard);
     }
 }
 
@@ -29,7 +62,8 @@ impl Context {
     /// The context must also have valid reference count, one which can be decremented when this
     /// object is dropped without going negative.
     pub unsafe fn from_raw(ctx: NonNull<qjs::JSContext>, rt: Runtime) -> Self {
-        Context(ContextRef::new(Inner { ctx, rt }))
+        let userdata = ContextUserData::attach(ctx.as_ptr());
+        Context(ContextRef::new(Inner { ctx, rt, userdata }))
     }
 
     pub fn as_raw(&self) -> NonNull<qjs::JSContext> {
@@ -56,6 +90,7 @@ impl Context {
         let res = Inner {
             ctx,
             rt: runtime.clone(),
+            userdata: unsafe { ContextUserData::attach(ctx.as_ptr()) },
         };
         mem::drop(guard);
 
@@ -72,6 +107,7 @@ impl Context {
         let res = Inner {
             ctx,
             rt: runtime.clone(),
+            userdata: unsafe { ContextUserData::attach(ctx.as_ptr()) },
         };
         // Explicitly drop the guard to ensure it is valid during the entire use of runtime
         mem::drop(guard);
@@ -113,31 +149,6 @@ impl Context {
     }
 }
 
-impl Drop for Context {
-    fn drop(&mut self) {
-        //TODO
-        let guard = match self.0.rt.inner.try_lock() {
-            Some(x) => x,
-            None => {
-                let p = unsafe { &mut *(self.0.ctx.as_ptr() as *mut RefCountHeader) };
-                if p.ref_count <= 1 {
-                    // Lock was poisoned, this should only happen on a panic.
-                    // We should still free the context.
-                    // TODO see if there is a way to recover from a panic which could cause the
-                    // following assertion to trigger
-                    assert!(std::thread::panicking());
-                }
-                unsafe { qjs::JS_FreeContext(self.0.ctx.as_ptr()) }
-                return;
-            }
-        };
-        guard.update_stack_top();
-        unsafe { qjs::JS_FreeContext(self.0.ctx.as_ptr()) }
-        // Explicitly drop the guard to ensure it is valid during the entire use of runtime
-        mem::drop(guard);
-    }
-}
-
 // Since the reference to runtime is behind a Arc this object is send
 //
 #[cfg(feature = "parallel")]
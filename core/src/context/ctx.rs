@@ -2,21 +2,27 @@
 use std::future::Future;
 use std::{
     any::Any,
+    cell::RefCell,
     ffi::{CStr, CString},
-    fs,
+    fs, io,
     mem::{self, MaybeUninit},
     path::Path,
     ptr::NonNull,
+    rc::Rc,
     result::Result as StdResult,
+    string::String as StdString,
 };
 
 #[cfg(feature = "futures")]
 use crate::AsyncContext;
 use crate::{
+    function::{Rest, This},
     markers::Invariant,
+    module::Declared,
     qjs,
-    runtime::{opaque::Opaque, UserDataError, UserDataGuard},
-    Atom, Error, FromJs, Function, IntoJs, JsLifetime, Object, Promise, Result, String, Value,
+    runtime::{opaque::Opaque, ContextUserData, UserDataError, UserDataGuard},
+    Atom, CaughtError, Error, FromJs, Function, InspectOptions, IntoJs, JsLifetime, Module, Object,
+    Promise, Result, String, Value,
 };
 
 use super::Context;
@@ -32,9 +38,34 @@ pub struct EvalOptions {
     pub backtrace_barrier: bool,
     /// Support top-level-await.
     pub promise: bool,
+    /// The name of the script used in error stack traces, `eval_script` if `None`.
+    ///
+    /// Ignored when evaluating a file, the name of the file is used instead.
+    pub file_name: Option<StdString>,
+    /// The line number of the first line of the source, used for line numbers in errors and
+    /// stack traces.
+    ///
+    /// Useful when the source is a snippet taken from a larger file. Defaults to `1`.
+    ///
+    /// QuickJS can't be given a line offset, so the source is prefixed with `first_line - 1`
+    /// empty lines, which costs a copy of the source and one byte per line. A shebang at the start
+    /// of the source is turned into a line comment to keep it valid after the prefix.
+    pub first_line: u32,
 }
 
 impl EvalOptions {
+    /// Set the name of the script used in error stack traces, see [`EvalOptions::file_name`].
+    pub fn with_file_name<N: Into<StdString>>(mut self, file_name: N) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Set the line number of the first line of the source, see [`EvalOptions::first_line`].
+    pub fn with_first_line(mut self, first_line: u32) -> Self {
+        self.first_line = first_line;
+        self
+    }
+
     fn to_flag(&self) -> i32 {
         let mut flag = if self.global {
             qjs::JS_EVAL_TYPE_GLOBAL
@@ -56,6 +87,22 @@ impl EvalOptions {
 
         flag as i32
     }
+
+    /// Offset the source so its first line is reported as `first_line`.
+    fn offset_source(&self, source: Vec<u8>) -> Vec<u8> {
+        let offset = self.first_line.saturating_sub(1) as usize;
+        if offset == 0 {
+            return source;
+        }
+        let mut res = Vec::with_capacity(offset + source.len());
+        res.resize(offset, b'\n');
+        res.extend(source);
+        // QuickJS only skips a shebang at the very start of the source.
+        if res[offset..].starts_with(b"#!") {
+            res[offset..offset + 2].copy_from_slice(b"//");
+        }
+        res
+    }
 }
 
 impl Default for EvalOptions {
@@ -65,10 +112,89 @@ impl Default for EvalOptions {
             strict: true,
             backtrace_barrier: false,
             promise: false,
+            file_name: None,
+            first_line: 1,
         }
     }
 }
 
+/// Encoding of script source passed to [`Ctx::eval_encoded`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SourceEncoding {
+    /// UTF-8, the encoding QuickJS expects.
+    Utf8,
+    /// ISO-8859-1, every byte is a single code point.
+    Latin1,
+    /// UTF-16 with little-endian code units.
+    Utf16Le,
+    /// UTF-16 with big-endian code units.
+    Utf16Be,
+}
+
+impl SourceEncoding {
+    fn name(self) -> &'static str {
+        match self {
+            SourceEncoding::Utf8 => "UTF-8",
+            SourceEncoding::Latin1 => "Latin-1",
+            SourceEncoding::Utf16Le => "UTF-16LE",
+            SourceEncoding::Utf16Be => "UTF-16BE",
+        }
+    }
+
+    /// Transcode the source into UTF-8.
+    fn decode(self, src: &[u8]) -> Result<Vec<u8>> {
+        let invalid = |msg: std::string::String| {
+            Error::Io(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid {} source: {}", self.name(), msg),
+            ))
+        };
+
+        let from_unit = match self {
+            SourceEncoding::Utf8 => {
+                return match std::str::from_utf8(src) {
+                    Ok(_) => Ok(src.to_vec()),
+                    Err(e) => Err(invalid(e.to_string())),
+                }
+            }
+            SourceEncoding::Latin1 => {
+                return Ok(src
+                    .iter()
+                    .map(|&b| b as char)
+                    .collect::<std::string::String>()
+                    .into())
+            }
+            SourceEncoding::Utf16Le => u16::from_le_bytes,
+            SourceEncoding::Utf16Be => u16::from_be_bytes,
+        };
+
+        let chunks = src.chunks_exact(2);
+        if !chunks.remainder().is_empty() {
+            return Err(invalid(format!("odd number of bytes ({})", src.len())));
+        }
+        let units = chunks.map(|c| from_unit([c[0], c[1]]));
+        let mut res = std::string::String::with_capacity(src.len() / 2);
+        let mut offset = 0;
+        for c in char::decode_utf16(units) {
+            match c {
+                Ok(c) => {
+                    res.push(c);
+                    offset += c.len_utf16() * 2;
+                }
+                Err(e) => {
+                    return Err(invalid(format!(
+                        "unpaired surrogate 0x{:04X} at byte offset {}",
+                        e.unpaired_surrogate(),
+                        offset
+                    )))
+                }
+            }
+        }
+        Ok(res.into())
+    }
+}
+
 /// Context in use, passed to [`Context::with`].
 #[derive(Debug)]
 pub struct Ctx<'js> {
@@ -99,6 +225,45 @@ pub(crate) struct RefCountHeader {
     pub ref_count: i32, // `int` in C is usually equivalent to `i32` in Rust
 }
 
+/// Records promises which are rejected without a handler while alive.
+///
+/// Rejections are recorded with the host promise rejection tracker which is only installed for
+/// the lifetime of the guard.
+struct RejectionTracker<'a, 'js> {
+    ctx: &'a Ctx<'js>,
+}
+
+impl<'a, 'js> RejectionTracker<'a, 'js> {
+    fn new(ctx: &'a Ctx<'js>) -> Self {
+        unsafe {
+            ctx.get_opaque()
+                .track_rejections(qjs::JS_GetRuntime(ctx.as_ptr()))
+        };
+        RejectionTracker { ctx }
+    }
+
+    /// Stops tracking, returning the reason of the first rejected promise which still has no
+    /// handler.
+    fn finish(self) -> Option<Value<'js>> {
+        let ctx = self.ctx;
+        mem::forget(self);
+        unsafe {
+            ctx.get_opaque()
+                .untrack_rejections(qjs::JS_GetRuntime(ctx.as_ptr()))
+        }
+    }
+}
+
+impl<'a, 'js> Drop for RejectionTracker<'a, 'js> {
+    fn drop(&mut self) {
+        unsafe {
+            self.ctx
+                .get_opaque()
+                .untrack_rejections(qjs::JS_GetRuntime(self.ctx.as_ptr()));
+        }
+    }
+}
+
 impl<'js> Ctx<'js> {
     pub(crate) fn as_ptr(&self) -> *mut qjs::JSContext {
         self.ctx.as_ptr()
@@ -150,6 +315,13 @@ impl<'js> Ctx<'js> {
     }
 
     /// Evaluate a script in global context.
+    ///
+    /// If the script throws, [`Error::Exception`] is returned and the thrown value can be
+    /// retrieved with [`Ctx::catch`]. If the script completes but its result can't be converted
+    /// into `V`, a conversion error like [`Error::FromJs`] is returned instead. Evaluate into a
+    /// [`Value`] to handle the conversion separately.
+    ///
+    /// A shebang line at the start of the script, like `#!/usr/bin/env qjs`, is ignored.
     pub fn eval<V: FromJs<'js>, S: Into<Vec<u8>>>(&self, source: S) -> Result<V> {
         self.eval_with_options(source, Default::default())
     }
@@ -168,20 +340,230 @@ impl<'js> Ctx<'js> {
         )
     }
 
+    /// Evaluate a script with top level await support and run it to completion.
+    ///
+    /// After evaluating, pending jobs are executed until the job queue is empty, like with
+    /// [`Ctx::run_microtasks`]. If the script throws, either directly or after awaiting, the
+    /// exception is returned as [`Error::Exception`]. If the script still hasn't finished when
+    /// no jobs are left, [`Error::WouldBlock`] is returned.
+    ///
+    /// Promises which are rejected without a handler while running are reported as well, the
+    /// reason of the first one which still has no handler is returned as [`Error::Exception`].
+    /// Spawned futures are not polled.
+    pub fn eval_to_completion<S: Into<Vec<u8>>>(&self, source: S) -> Result<()> {
+        let tracker = RejectionTracker::new(self);
+        let promise = self.eval_promise(source)?;
+        self.run_microtasks().map_err(|e| e.throw(self))?;
+        promise.finish::<()>()?;
+        match tracker.finish() {
+            Some(reason) => Err(self.throw(reason)),
+            None => Ok(()),
+        }
+    }
+
     /// Evaluate a script with the given options.
     pub fn eval_with_options<V: FromJs<'js>, S: Into<Vec<u8>>>(
         &self,
         source: S,
         options: EvalOptions,
     ) -> Result<V> {
-        let file_name = CStr::from_bytes_with_nul(b"eval_script\0").unwrap();
+        let file_name = match options.file_name {
+            Some(ref name) => CString::new(name.as_str())?,
+            None => CString::new("eval_script").unwrap(),
+        };
+        let source = options.offset_source(source.into());
 
         V::from_js(self, unsafe {
-            let val = self.eval_raw(source, file_name, options.to_flag())?;
+            let val = self.eval_raw(source, file_name.as_c_str(), options.to_flag())?;
             Value::from_js_value(self.clone(), val)
         })
     }
 
+    /// Evaluate a script which is not encoded as UTF-8.
+    ///
+    /// The source is transcoded into UTF-8 before being evaluated like [`Ctx::eval`]. Invalid byte
+    /// sequences, like unpaired surrogates in UTF-16, result in an [`Error::Io`] with
+    /// [`InvalidData`](io::ErrorKind::InvalidData) as kind.
+    pub fn eval_encoded<V: FromJs<'js>>(&self, src: &[u8], encoding: SourceEncoding) -> Result<V> {
+        self.eval(encoding.decode(src)?)
+    }
+
+    /// Evaluate a script with the given value as `this`.
+    ///
+    /// Like [`Ctx::eval`] the result is the completion value of the script and the script is
+    /// evaluated in strict mode. The script is evaluated inside a block, so `let`, `const`,
+    /// `class` and function declarations don't leak into the global scope. `var` declarations
+    /// are not block scoped and still create properties on the global object.
+    pub fn eval_with_this<V: FromJs<'js>, T: IntoJs<'js>, S: Into<Vec<u8>>>(
+        &self,
+        this: T,
+        source: S,
+    ) -> Result<V> {
+        let this = this.into_js(self)?;
+        let file_name = CString::new("eval_script").unwrap();
+        // The newline ends a trailing line comment, the opening brace shares the first line so
+        // line numbers stay the same.
+        let mut src = b"{".to_vec();
+        src.extend(source.into());
+        src.extend(b"\n}");
+        let len = src.len();
+        let src = CString::new(src)?;
+        let flag = qjs::JS_EVAL_TYPE_GLOBAL | qjs::JS_EVAL_FLAG_STRICT;
+        V::from_js(self, unsafe {
+            let val = qjs::JS_EvalThis(
+                self.ctx.as_ptr(),
+                this.as_js_value(),
+                src.as_ptr(),
+                len as _,
+                file_name.as_ptr(),
+                flag as i32,
+            );
+            let val = self.handle_exception(val)?;
+            Value::from_js_value(self.clone(), val)
+        })
+    }
+
+    /// Evaluate a script with the given object as its global scope.
+    ///
+    /// Free variables in the script are resolved against `globals` instead of the global object
+    /// of the context, so the script can only see the bindings which are defined on `globals`.
+    /// Identifiers which are not defined on `globals` evaluate to `undefined` and assignments to
+    /// them create a property on `globals`. `this` in the script also refers to `globals`.
+    ///
+    /// This is implemented by evaluating the script inside a `with` statement over a proxy of
+    /// `globals`, which means the script is evaluated in sloppy mode. It is intended for
+    /// isolating scripts from the global object, it is not a security boundary: objects passed in
+    /// through `globals` still expose the context's intrinsics via their prototypes.
+    pub fn eval_sandboxed<V: FromJs<'js>, S: Into<Vec<u8>>>(
+        &self,
+        globals: Object<'js>,
+        source: S,
+    ) -> Result<V> {
+        let source = std::string::String::from_utf8(source.into())?;
+        let func: Function = self.eval_with_options(
+            r#"(function (scope, source) {
+                let entered = false;
+                const proxy = new Proxy(scope, {
+                    has(target, key) {
+                        // Let the wrapper resolve `eval` and `source` itself, every lookup after
+                        // that is answered by the sandbox globals.
+                        if (!entered && (key === "eval" || key === "source")) {
+                            entered = key === "source";
+                            return false;
+                        }
+                        return true;
+                    },
+                    get(target, key) {
+                        if (key === Symbol.unscopables) {
+                            return undefined;
+                        }
+                        return target[key];
+                    },
+                });
+                with (proxy) {
+                    return eval(source);
+                }
+            })"#,
+            EvalOptions {
+                strict: false,
+                ..Default::default()
+            },
+        )?;
+        func.call((This(globals.clone()), globals, source))
+    }
+
+    /// Evaluate a CommonJS module and return its `module.exports`.
+    ///
+    /// The source is wrapped in `(function (exports, require, module) { ... })` and called with a
+    /// fresh `module` object, its `exports` object and the given `require` function. Like scripts
+    /// in Node.js the module is evaluated in sloppy mode and a leading shebang line is ignored.
+    /// Resolving and loading modules is left to `require`.
+    pub fn eval_commonjs<V: FromJs<'js>, S: Into<Vec<u8>>>(
+        &self,
+        source: S,
+        require: Function<'js>,
+    ) -> Result<V> {
+        let mut source = source.into();
+        // QuickJS only skips a shebang at the very start of the source, turn it into a comment
+        // since the source is wrapped.
+        if source.starts_with(b"#!") {
+            source[..2].copy_from_slice(b"//");
+        }
+        // Keep the module source on the first line so line numbers in errors stay correct.
+        let mut wrapped = b"(function (exports, require, module) {".to_vec();
+        wrapped.extend(source);
+        wrapped.extend_from_slice(b"\n})");
+        let func: Function = self.eval_with_options(
+            wrapped,
+            EvalOptions {
+                strict: false,
+                ..Default::default()
+            },
+        )?;
+
+        let exports = Object::new(self.clone())?;
+        let module = Object::new(self.clone())?;
+        module.set("exports", exports.clone())?;
+        func.call::<_, ()>((This(exports.clone()), exports, require, module.clone()))?;
+        module.get("exports")
+    }
+
+    /// Evaluate a script while capturing what it writes to the console.
+    ///
+    /// While the script is evaluated the global `console` is replaced by an object whose `log`,
+    /// `info`, `warn`, `error` and `debug` functions append a line to `sink`. Arguments are
+    /// separated by a space, strings are written as is and other values are formatted with
+    /// [`Value::inspect`]. The previous `console`, if any, is restored afterwards, so output of
+    /// callbacks which run after this function returns, like promise jobs, is not captured.
+    pub fn eval_with_capture<V: FromJs<'js>, S: Into<Vec<u8>>>(
+        &self,
+        source: S,
+        sink: &mut std::string::String,
+    ) -> Result<V> {
+        let buffer = Rc::new(RefCell::new(std::string::String::new()));
+        let log = {
+            let buffer = buffer.clone();
+            Function::new(self.clone(), move |args: Rest<Value<'js>>| -> Result<()> {
+                let mut line = std::string::String::new();
+                for (idx, arg) in args.0.iter().enumerate() {
+                    if idx > 0 {
+                        line.push(' ');
+                    }
+                    match arg.as_string() {
+                        Some(x) => line.push_str(&x.to_string()?),
+                        None => line.push_str(&arg.inspect(InspectOptions::default())?),
+                    }
+                }
+                line.push('\n');
+                buffer.borrow_mut().push_str(&line);
+                Ok(())
+            })?
+            .with_name("log")?
+        };
+        let console = Object::new(self.clone())?;
+        for name in ["log", "info", "warn", "error", "debug"] {
+            console.set(name, log.clone())?;
+        }
+
+        let globals = self.globals();
+        let previous: Option<Value> = if globals.contains_key("console")? {
+            Some(globals.get("console")?)
+        } else {
+            None
+        };
+        globals.set("console", console)?;
+        let res = self.eval(source);
+        let restored = match previous {
+            Some(x) => globals.set("console", x),
+            None => globals.remove("console"),
+        };
+
+        sink.push_str(&buffer.borrow());
+        let res = res?;
+        restored?;
+        Ok(res)
+    }
+
     /// Evaluate a script directly from a file.
     pub fn eval_file<V: FromJs<'js>, P: AsRef<Path>>(&self, path: P) -> Result<V> {
         self.eval_file_with_options(path, Default::default())
@@ -192,7 +574,7 @@ impl<'js> Ctx<'js> {
         path: P,
         options: EvalOptions,
     ) -> Result<V> {
-        let buffer = fs::read(path.as_ref())?;
+        let buffer = options.offset_source(fs::read(path.as_ref())?);
         let file_name = CString::new(
             path.as_ref()
                 .file_name()
@@ -207,6 +589,33 @@ impl<'js> Ctx<'js> {
         })
     }
 
+    /// Compile a module from source without evaluating it.
+    ///
+    /// This is a shorthand for [`Module::declare`]. Syntax errors are returned by this function
+    /// while errors thrown when running the module are only returned once the module is evaluated
+    /// with [`Module::eval`]. The declared module can also be serialized to bytecode with
+    /// [`Module::write`] before it is evaluated.
+    pub fn compile<N, S>(&self, name: N, source: S) -> Result<Module<'js, Declared>>
+    where
+        N: Into<Vec<u8>>,
+        S: Into<Vec<u8>>,
+    {
+        Module::declare(self.clone(), name, source)
+    }
+
+    /// Import a module and return its default export.
+    ///
+    /// The module is imported like [`Module::import`] and the returned promise is settled before
+    /// the `default` export of the module namespace is converted to `T`. This is mainly useful for
+    /// configuration style modules of which the `export default` value is the payload.
+    ///
+    /// Returns [`Error::WouldBlock`] if the module can't finish evaluating without waiting on a
+    /// future.
+    pub fn import_default<T: FromJs<'js>, S: Into<Vec<u8>>>(&self, specifier: S) -> Result<T> {
+        let namespace: Object = Module::import(self, specifier)?.finish()?;
+        namespace.get("default")
+    }
+
     /// Returns the global object of this context.
     pub fn globals(&self) -> Object<'js> {
         unsafe {
@@ -237,6 +646,28 @@ impl<'js> Ctx<'js> {
         }
     }
 
+    /// Takes the pending exception and converts it to a Rust type, clearing the exception.
+    ///
+    /// Returns [`Error::NoException`] if no exception is pending.
+    ///
+    /// ```
+    /// # use rquickjs::{Runtime, Context, Object, Result};
+    /// # let rt = Runtime::new().unwrap();
+    /// # let ctx = Context::full(&rt).unwrap();
+    /// # ctx.with(|ctx|{
+    /// let err = ctx.eval::<(), _>("throw { code: 404 }").unwrap_err();
+    /// assert!(err.is_exception());
+    /// let thrown: Object = ctx.catch_as().unwrap();
+    /// assert_eq!(thrown.get::<_, i32>("code").unwrap(), 404);
+    /// # });
+    /// ```
+    pub fn catch_as<T: FromJs<'js>>(&self) -> Result<T> {
+        if unsafe { qjs::JS_HasException(self.ctx.as_ptr()) } == 0 {
+            return Err(Error::NoException);
+        }
+        T::from_js(self, self.catch())
+    }
+
     /// Throws a JavaScript value as a new exception.
     /// Always returns `Error::Exception`;
     pub fn throw(&self, value: Value<'js>) -> Error {
@@ -379,6 +810,44 @@ impl<'js> Ctx<'js> {
         res != 0
     }
 
+    /// Executes a quickjs job, returning the exception if the job threw one.
+    ///
+    /// Returns whether a job was actually executed, like [`Ctx::execute_pending_job`]. If the job
+    /// threw, the exception is taken from the context which ran the job and returned as a
+    /// [`CaughtError`].
+    ///
+    /// An exception thrown inside a promise reaction, like a `.then` callback, doesn't make the
+    /// job throw but rejects the promise returned from `.then`. If that promise has no handler
+    /// after the job ran, its rejection reason is returned as the error instead.
+    pub fn try_execute_pending_job(&self) -> StdResult<bool, CaughtError<'js>> {
+        let mut ptr = MaybeUninit::<*mut qjs::JSContext>::uninit();
+        let rt = unsafe { qjs::JS_GetRuntime(self.ctx.as_ptr()) };
+        let tracker = RejectionTracker::new(self);
+        let res = unsafe { qjs::JS_ExecutePendingJob(rt, ptr.as_mut_ptr()) };
+        let rejection = tracker.finish();
+        if res < 0 {
+            let ctx = unsafe { Ctx::from_ptr(ptr.assume_init()) };
+            return Err(CaughtError::from_error(&ctx, Error::Exception));
+        }
+        if let Some(reason) = rejection {
+            let ctx = reason.ctx().clone();
+            return Err(CaughtError::from_error(&ctx, ctx.throw(reason)));
+        }
+        Ok(res != 0)
+    }
+
+    /// Executes pending jobs, like promise reactions, until the job queue is empty.
+    ///
+    /// Jobs queued while running are executed as well. Jobs are executed with
+    /// [`Ctx::try_execute_pending_job`], if a job fails execution stops and the error is returned.
+    /// The remaining jobs stay queued.
+    ///
+    /// Spawned futures are not driven by this function.
+    pub fn run_microtasks(&self) -> StdResult<(), CaughtError<'js>> {
+        while self.try_execute_pending_job()? {}
+        Ok(())
+    }
+
     pub(crate) unsafe fn get_opaque(&self) -> &Opaque<'js> {
         Opaque::from_runtime_ptr(qjs::JS_GetRuntime(self.ctx.as_ptr()))
     }
@@ -444,6 +913,11 @@ impl<'js> Ctx<'js> {
 
     /// Store a type in the runtime which can be retrieved later with `Ctx::userdata`.
     ///
+    /// Userdata is stored per type: every type has a single slot which is looked up by its
+    /// [`TypeId`](std::any::TypeId), so retrieving it as a different type is not possible. The
+    /// storage is shared by all contexts of the runtime and is dropped together with the runtime,
+    /// see [`Ctx::store_context_userdata`] for storage of a single context.
+    ///
     /// Returns the value from the argument if the userdata is currently being accessed and
     /// insertion is not possible.
     /// Otherwise returns the exising value for this type if it existed.
@@ -478,6 +952,52 @@ impl<'js> Ctx<'js> {
         unsafe { self.get_opaque().get_userdata() }
     }
 
+    /// Store a type in the userdata of this context which can be retrieved later with
+    /// [`Ctx::context_userdata`].
+    ///
+    /// Like [`Ctx::store_userdata`] but the data is only visible from this context and is dropped
+    /// when the last [`Context`](crate::Context) handle to the context is dropped.
+    ///
+    /// Returns the value from the argument if the userdata is currently being accessed and
+    /// insertion is not possible.
+    /// Otherwise returns the exising value for this type if it existed.
+    pub fn store_context_userdata<U>(&self, data: U) -> StdResult<Option<Box<U>>, UserDataError<U>>
+    where
+        U: JsLifetime<'js>,
+        U::Changed<'static>: Any,
+    {
+        match unsafe { ContextUserData::get(self.as_ptr()) } {
+            Some(map) => map.insert(data),
+            None => Err(UserDataError(data)),
+        }
+    }
+
+    /// Remove the userdata of the given type from the userdata of this context.
+    ///
+    /// Returns Err(()) if the userdata is currently being accessed and removing isn't possible.
+    /// Returns Ok(None) if userdata of the given type wasn't inserted.
+    pub fn remove_context_userdata<U>(&self) -> StdResult<Option<Box<U>>, UserDataError<()>>
+    where
+        U: JsLifetime<'js>,
+        U::Changed<'static>: Any,
+    {
+        match unsafe { ContextUserData::get(self.as_ptr()) } {
+            Some(map) => map.remove(),
+            None => Ok(None),
+        }
+    }
+
+    /// Retrieves a borrow to the userdata of the given type from the userdata of this context.
+    ///
+    /// Returns None if userdata of the given type wasn't inserted.
+    pub fn context_userdata<U>(&self) -> Option<UserDataGuard<'_, U>>
+    where
+        U: JsLifetime<'js>,
+        U::Changed<'static>: Any,
+    {
+        unsafe { ContextUserData::get(self.as_ptr()) }?.get()
+    }
+
     /// Returns the pointer to the C library context.
     pub fn as_raw(&self) -> NonNull<qjs::JSContext> {
         self.ctx
@@ -505,6 +1025,34 @@ mod test {
         });
     }
 
+    #[test]
+    fn compile() {
+        use crate::{Context, Error, Runtime};
+
+        let runtime = Runtime::new().unwrap();
+        let ctx = Context::full(&runtime).unwrap();
+        ctx.with(|ctx| {
+            let err = ctx.compile("invalid", "export let = ;").unwrap_err();
+            assert!(matches!(err, Error::Exception));
+            let exception = ctx.catch();
+            let exception = exception.as_exception().unwrap();
+            assert_eq!(
+                exception.get::<_, std::string::String>("name").unwrap(),
+                "SyntaxError"
+            );
+
+            let module = ctx
+                .compile("valid", "globalThis.ran = true; export const x = 42;")
+                .unwrap();
+            assert!(!ctx.globals().contains_key("ran").unwrap());
+
+            let (module, promise) = module.eval().unwrap();
+            promise.finish::<()>().unwrap();
+            assert!(ctx.globals().get::<_, bool>("ran").unwrap());
+            assert_eq!(module.get::<_, i32>("x").unwrap(), 42);
+        })
+    }
+
     #[test]
     fn eval() {
         use crate::{Context, Runtime};
@@ -621,6 +1169,315 @@ mod test {
         })
     }
 
+    #[test]
+    fn eval_with_this() {
+        use crate::{Context, Object, Runtime};
+
+        let runtime = Runtime::new().unwrap();
+        let ctx = Context::full(&runtime).unwrap();
+        ctx.with(|ctx| {
+            let this = Object::new(ctx.clone()).unwrap();
+            this.set("x", 41).unwrap();
+            let res: i32 = ctx.eval_with_this(this.clone(), "this.x + 1").unwrap();
+            assert_eq!(res, 42);
+
+            let res: i32 = ctx
+                .eval_with_this(
+                    this,
+                    "let leaked = 1; const c = 2; function f() {}; this.x // comment",
+                )
+                .catch(&ctx)
+                .unwrap();
+            assert_eq!(res, 41);
+            assert!(!ctx.globals().contains_key("leaked").unwrap());
+            assert!(!ctx.globals().contains_key("f").unwrap());
+            let res: bool = ctx.eval("typeof leaked == 'undefined'").unwrap();
+            assert!(res);
+
+            ctx.globals().set("eval", ()).unwrap();
+            let res: bool = ctx.eval_with_this((), "this === undefined").unwrap();
+            assert!(res);
+        })
+    }
+
+    #[test]
+    fn eval_error_kinds() {
+        use crate::{Context, Runtime, Value};
+
+        let runtime = Runtime::new().unwrap();
+        let ctx = Context::full(&runtime).unwrap();
+        ctx.with(|ctx| {
+            let err = ctx.eval::<i32, _>("'not a number'").unwrap_err();
+            assert!(err.is_from_js());
+            assert!(!err.is_exception());
+            assert!(!ctx.catch().is_exception());
+
+            let err = ctx.eval::<i32, _>("throw 1").unwrap_err();
+            assert!(err.is_exception());
+            assert_eq!(ctx.catch().as_int(), Some(1));
+
+            let value: Value = ctx.eval("'not a number'").unwrap();
+            assert!(value.get::<i32>().unwrap_err().is_from_js());
+        })
+    }
+
+    #[test]
+    fn eval_encoded() {
+        use super::SourceEncoding;
+        use crate::{Context, Error, Runtime};
+
+        let runtime = Runtime::new().unwrap();
+        let ctx = Context::full(&runtime).unwrap();
+        ctx.with(|ctx| {
+            let src: Vec<u8> = "1+1".encode_utf16().flat_map(u16::to_le_bytes).collect();
+            let res: i32 = ctx.eval_encoded(&src, SourceEncoding::Utf16Le).unwrap();
+            assert_eq!(res, 2);
+
+            let src: Vec<u8> = "'\u{e9}\u{1F600}'"
+                .encode_utf16()
+                .flat_map(u16::to_be_bytes)
+                .collect();
+            let res: std::string::String = ctx.eval_encoded(&src, SourceEncoding::Utf16Be).unwrap();
+            assert_eq!(res, "\u{e9}\u{1F600}");
+
+            let res: std::string::String = ctx
+                .eval_encoded(b"'caf\xe9'", SourceEncoding::Latin1)
+                .unwrap();
+            assert_eq!(res, "caf\u{e9}");
+
+            let err = ctx
+                .eval_encoded::<()>(&[0x31, 0x00, 0x00, 0xD8], SourceEncoding::Utf16Le)
+                .unwrap_err();
+            assert!(matches!(err, Error::Io(ref e) if e.kind() == std::io::ErrorKind::InvalidData));
+            assert!(err
+                .to_string()
+                .contains("unpaired surrogate 0xD800 at byte offset 2"));
+
+            let err = ctx
+                .eval_encoded::<()>(b"1+1", SourceEncoding::Utf16Be)
+                .unwrap_err();
+            assert!(err.to_string().contains("odd number of bytes"));
+        })
+    }
+
+    #[test]
+    fn eval_sandboxed() {
+        use crate::{Context, Object, Runtime};
+
+        let runtime = Runtime::new().unwrap();
+        let ctx = Context::full(&runtime).unwrap();
+        ctx.with(|ctx| {
+            ctx.globals().set("secret", 42).unwrap();
+
+            let globals = Object::new(ctx.clone()).unwrap();
+            globals.set("x", 1).unwrap();
+
+            let res: std::string::String = ctx
+                .eval_sandboxed(globals.clone(), "typeof secret")
+                .catch(&ctx)
+                .unwrap();
+            assert_eq!(res, "undefined");
+
+            let res: i32 = ctx
+                .eval_sandboxed(globals.clone(), "y = x + 1; this.y + 1")
+                .catch(&ctx)
+                .unwrap();
+            assert_eq!(res, 3);
+            assert_eq!(globals.get::<_, i32>("y").unwrap(), 2);
+            assert!(!ctx.globals().contains_key("y").unwrap());
+
+            let res: bool = ctx
+                .eval_sandboxed(globals, "typeof eval === 'undefined'")
+                .catch(&ctx)
+                .unwrap();
+            assert!(res);
+        })
+    }
+
+    #[test]
+    fn eval_commonjs() {
+        use crate::{Context, Function, Object, Runtime};
+
+        let runtime = Runtime::new().unwrap();
+        let ctx = Context::full(&runtime).unwrap();
+        ctx.with(|ctx| {
+            let require = Function::new(ctx.clone(), |name: std::string::String| {
+                format!("required {name}")
+            })
+            .unwrap();
+
+            let res: i32 = ctx
+                .eval_commonjs("module.exports = 42", require.clone())
+                .catch(&ctx)
+                .unwrap();
+            assert_eq!(res, 42);
+
+            let res: Object = ctx
+                .eval_commonjs(
+                    "exports.foo = 1; this.bar = require('bar')",
+                    require.clone(),
+                )
+                .catch(&ctx)
+                .unwrap();
+            assert_eq!(res.get::<_, i32>("foo").unwrap(), 1);
+            assert_eq!(
+                res.get::<_, std::string::String>("bar").unwrap(),
+                "required bar"
+            );
+            assert_eq!(res.keys::<std::string::String>().count(), 2);
+
+            let res: Option<i32> = ctx
+                .eval_commonjs::<Object, _>("var local = 1", require)
+                .catch(&ctx)
+                .unwrap()
+                .get("local")
+                .unwrap();
+            assert_eq!(res, None);
+            assert!(!ctx.globals().contains_key("local").unwrap());
+        })
+    }
+
+    #[test]
+    fn eval_shebang() {
+        use crate::{CaughtError, Context, Function, Runtime};
+
+        let runtime = Runtime::new().unwrap();
+        let ctx = Context::full(&runtime).unwrap();
+        ctx.with(|ctx| {
+            let res: i32 = ctx.eval("#!/usr/bin/env qjs\n40 + 2").unwrap();
+            assert_eq!(res, 42);
+
+            let err = ctx
+                .eval::<(), _>("#!/usr/bin/env qjs\nthrow new Error('oops')")
+                .catch(&ctx)
+                .unwrap_err();
+            let CaughtError::Exception(err) = err else {
+                panic!("expected an exception");
+            };
+            assert!(err.stack().unwrap().contains("eval_script:2"));
+
+            let require = Function::new(ctx.clone(), || ()).unwrap();
+            let res: i32 = ctx
+                .eval_commonjs("#!/usr/bin/env node\nmodule.exports = 42", require.clone())
+                .catch(&ctx)
+                .unwrap();
+            assert_eq!(res, 42);
+            let err = ctx
+                .eval_commonjs::<(), _>("#!/usr/bin/env node\nthrow new Error('oops')", require)
+                .catch(&ctx)
+                .unwrap_err();
+            let CaughtError::Exception(err) = err else {
+                panic!("expected an exception");
+            };
+            assert!(err.stack().unwrap().contains("eval_script:2"));
+        })
+    }
+
+    #[test]
+    fn eval_first_line() {
+        use crate::{context::EvalOptions, CaughtError, Context, Runtime};
+
+        let runtime = Runtime::new().unwrap();
+        let ctx = Context::full(&runtime).unwrap();
+        ctx.with(|ctx| {
+            let options = || {
+                EvalOptions::default()
+                    .with_file_name("template.js")
+                    .with_first_line(100)
+            };
+            let res: i32 = ctx
+                .eval_with_options("#!/usr/bin/env qjs\n40 + 2", options())
+                .unwrap();
+            assert_eq!(res, 42);
+
+            let err = ctx
+                .eval_with_options::<(), _>("let a = 1;\nthrow new Error('oops')", options())
+                .catch(&ctx)
+                .unwrap_err();
+            let CaughtError::Exception(err) = err else {
+                panic!("expected an exception");
+            };
+            assert!(err.stack().unwrap().contains("template.js:101"));
+
+            let err = ctx
+                .eval_with_options::<(), _>("let a = ;", options())
+                .catch(&ctx)
+                .unwrap_err();
+            let CaughtError::Exception(err) = err else {
+                panic!("expected an exception");
+            };
+            assert!(err.stack().unwrap().contains("template.js:100"));
+        })
+    }
+
+    #[test]
+    fn eval_with_capture() {
+        use crate::{Context, Error, Runtime};
+
+        let runtime = Runtime::new().unwrap();
+        let ctx = Context::full(&runtime).unwrap();
+        ctx.with(|ctx| {
+            let mut output = std::string::String::new();
+            let res: i32 = ctx
+                .eval_with_capture(
+                    r#"
+                    console.log("hello", 1, { a: [true] });
+                    console.error("oops");
+                    40 + 2
+                    "#,
+                    &mut output,
+                )
+                .catch(&ctx)
+                .unwrap();
+            assert_eq!(res, 42);
+            assert_eq!(output, "hello 1 { a: [ true ] }\noops\n");
+            assert!(!ctx.globals().contains_key("console").unwrap());
+
+            ctx.globals().set("console", "previous").unwrap();
+            let mut output = std::string::String::new();
+            let err = ctx
+                .eval_with_capture::<(), _>("console.log('before'); throw 1", &mut output)
+                .unwrap_err();
+            assert!(matches!(err, Error::Exception));
+            assert_eq!(ctx.catch().as_int(), Some(1));
+            assert_eq!(output, "before\n");
+            let console: std::string::String = ctx.globals().get("console").unwrap();
+            assert_eq!(console, "previous");
+        })
+    }
+
+    #[test]
+    fn catch_as() {
+        use crate::{Context, Ctx, Error, FromJs, Object, Result, Runtime, Value};
+
+        struct HttpError {
+            status: u16,
+        }
+
+        impl<'js> FromJs<'js> for HttpError {
+            fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> Result<Self> {
+                let obj = Object::from_js(ctx, value)?;
+                Ok(HttpError {
+                    status: obj.get("status")?,
+                })
+            }
+        }
+
+        let runtime = Runtime::new().unwrap();
+        let ctx = Context::full(&runtime).unwrap();
+        ctx.with(|ctx| {
+            assert!(matches!(ctx.catch_as::<Value>(), Err(Error::NoException)));
+
+            let err = ctx.eval::<(), _>("throw { status: 404 }").unwrap_err();
+            assert!(err.is_exception());
+            let http_error: HttpError = ctx.catch_as().unwrap();
+            assert_eq!(http_error.status, 404);
+
+            // The exception was cleared.
+            assert!(matches!(ctx.catch_as::<Value>(), Err(Error::NoException)));
+        })
+    }
+
     #[test]
     fn json_parse() {
         use crate::{Array, Context, Object, Runtime};
@@ -671,6 +1528,63 @@ mod test {
         })
     }
 
+    #[cfg(feature = "loader")]
+    #[test]
+    fn import_default_json() {
+        use crate::{
+            loader::{BuiltinResolver, Loader},
+            module::Declared,
+            Context, Ctx, Error, FromJs, Module, Object, Result, Runtime, Value,
+        };
+
+        struct JsonLoader;
+
+        impl Loader for JsonLoader {
+            fn load<'js>(&mut self, ctx: &Ctx<'js>, path: &str) -> Result<Module<'js, Declared>> {
+                match path {
+                    "config.json" => {
+                        let json = r#"{ "name": "app", "port": 8080 }"#;
+                        Module::declare(ctx.clone(), path, format!("export default {json};"))
+                    }
+                    _ => Err(Error::new_loading(path)),
+                }
+            }
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct Config {
+            name: std::string::String,
+            port: u16,
+        }
+
+        impl<'js> FromJs<'js> for Config {
+            fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> Result<Self> {
+                let obj = Object::from_value(value)?;
+                Ok(Config {
+                    name: obj.get("name")?,
+                    port: obj.get("port")?,
+                })
+            }
+        }
+
+        let rt = Runtime::new().unwrap();
+        rt.set_loader(
+            BuiltinResolver::default().with_module("config.json"),
+            JsonLoader,
+        );
+        let ctx = Context::full(&rt).unwrap();
+        ctx.with(|ctx| {
+            let config: Config = ctx.import_default("config.json").catch(&ctx).unwrap();
+            assert_eq!(
+                config,
+                Config {
+                    name: "app".into(),
+                    port: 8080
+                }
+            );
+        });
+    }
+
     #[test]
     fn userdata() {
         use crate::{Context, Function, Runtime};
@@ -704,4 +1618,272 @@ mod test {
             ctx.remove_userdata::<MyUserData>().unwrap().unwrap();
         })
     }
+
+    #[test]
+    fn try_execute_pending_job() {
+        use crate::{CaughtError, Context, Promise, Runtime};
+
+        let rt = Runtime::new().unwrap();
+        let ctx = Context::full(&rt).unwrap();
+        ctx.with(|ctx| {
+            assert!(matches!(ctx.try_execute_pending_job(), Ok(false)));
+
+            ctx.eval::<(), _>("queueMicrotask(() => { throw new Error('job failed') })")
+                .unwrap();
+            match ctx.try_execute_pending_job() {
+                Err(CaughtError::Exception(e)) => {
+                    assert_eq!(e.message().as_deref(), Some("job failed"))
+                }
+                x => panic!("expected an exception, got {x:?}"),
+            }
+            assert!(matches!(ctx.try_execute_pending_job(), Ok(false)));
+
+            // A throwing reaction rejects the derived promise which has no handler.
+            ctx.eval::<(), _>("Promise.resolve().then(() => { throw new Error('then failed') })")
+                .unwrap();
+            match ctx.try_execute_pending_job() {
+                Err(CaughtError::Exception(e)) => {
+                    assert_eq!(e.message().as_deref(), Some("then failed"))
+                }
+                x => panic!("expected an exception, got {x:?}"),
+            }
+            assert!(matches!(ctx.try_execute_pending_job(), Ok(false)));
+
+            // Rejections which are handled are not reported.
+            let promise: Promise = ctx
+                .eval(
+                    r#"
+                    Promise.resolve()
+                        .then(() => { throw new Error('caught') })
+                        .catch((e) => e.message)
+                    "#,
+                )
+                .unwrap();
+            while ctx.try_execute_pending_job().unwrap() {}
+            let message: String = promise.result().unwrap().unwrap();
+            assert_eq!(message, "caught");
+            assert_eq!(unsafe { crate::qjs::JS_HasException(ctx.as_ptr()) }, 0);
+        })
+    }
+
+    #[test]
+    fn rejection_tracker_restored() {
+        use crate::{CaughtError, Context, Runtime};
+        use std::sync::{Arc, Mutex};
+
+        let rt = Runtime::new().unwrap();
+        let ctx = Context::full(&rt).unwrap();
+        let rejections = Arc::new(Mutex::new(Vec::new()));
+        let tracked = rejections.clone();
+        rt.set_host_promise_rejection_tracker(Some(Box::new(
+            move |_ctx, _promise, reason, is_handled| {
+                let reason: String = reason.get().unwrap();
+                tracked.lock().unwrap().push((reason, is_handled));
+            },
+        )));
+
+        ctx.with(|ctx| {
+            ctx.eval::<(), _>("Promise.reject('before')").unwrap();
+            ctx.eval::<(), _>("Promise.resolve().then(() => { throw 'during' })")
+                .unwrap();
+            assert!(matches!(
+                ctx.try_execute_pending_job(),
+                Err(CaughtError::Value(_))
+            ));
+            ctx.eval::<(), _>("Promise.reject('after')").unwrap();
+        });
+
+        assert_eq!(
+            *rejections.lock().unwrap(),
+            [
+                ("before".to_string(), false),
+                ("during".to_string(), false),
+                ("after".to_string(), false)
+            ]
+        );
+    }
+
+    #[test]
+    fn run_microtasks() {
+        use crate::{promise::PromiseState, CaughtError, Context, Promise, Runtime};
+
+        let rt = Runtime::new().unwrap();
+        let ctx = Context::full(&rt).unwrap();
+        ctx.with(|ctx| {
+            ctx.run_microtasks().unwrap();
+
+            let promise: Promise = ctx
+                .eval("Promise.resolve(20).then((x) => x + 1).then((x) => x * 2)")
+                .unwrap();
+            assert_eq!(promise.state(), PromiseState::Pending);
+            ctx.run_microtasks().unwrap();
+            assert_eq!(promise.state(), PromiseState::Resolved);
+            let res: i32 = promise.result().unwrap().unwrap();
+            assert_eq!(res, 42);
+
+            ctx.eval::<(), _>(
+                r#"
+                queueMicrotask(() => { throw new Error('first') });
+                queueMicrotask(() => { globalThis.second = true });
+                "#,
+            )
+            .unwrap();
+            match ctx.run_microtasks() {
+                Err(CaughtError::Exception(e)) => {
+                    assert_eq!(e.message().as_deref(), Some("first"))
+                }
+                x => panic!("expected an exception, got {x:?}"),
+            }
+            assert!(!ctx.globals().contains_key("second").unwrap());
+            ctx.run_microtasks().unwrap();
+            assert!(ctx.globals().get::<_, bool>("second").unwrap());
+        });
+
+        // Errors are taken from the context which ran the job.
+        let other = Context::full(&rt).unwrap();
+        other.with(|other| {
+            other
+                .eval::<(), _>("queueMicrotask(() => { throw new Error('other') })")
+                .unwrap();
+        });
+        ctx.with(|ctx| match ctx.run_microtasks() {
+            Err(CaughtError::Exception(e)) => {
+                assert_eq!(e.message().as_deref(), Some("other"))
+            }
+            x => panic!("expected an exception, got {x:?}"),
+        })
+    }
+
+    #[test]
+    fn eval_to_completion() {
+        use crate::{CatchResultExt, CaughtError, Context, Error, Runtime};
+
+        let rt = Runtime::new().unwrap();
+        let ctx = Context::full(&rt).unwrap();
+        ctx.with(|ctx| {
+            ctx.eval_to_completion("Promise.resolve().then(() => { globalThis.done = true })")
+                .unwrap();
+            assert!(ctx.globals().get::<_, bool>("done").unwrap());
+
+            ctx.eval_to_completion("await null; globalThis.awaited = true")
+                .unwrap();
+            assert!(ctx.globals().get::<_, bool>("awaited").unwrap());
+
+            match ctx
+                .eval_to_completion("await null; throw new Error('late')")
+                .catch(&ctx)
+            {
+                Err(CaughtError::Exception(e)) => {
+                    assert_eq!(e.message().as_deref(), Some("late"))
+                }
+                x => panic!("expected an exception, got {x:?}"),
+            }
+
+            let err = ctx
+                .eval_to_completion("await new Promise(() => {})")
+                .unwrap_err();
+            assert!(matches!(err, Error::WouldBlock));
+
+            match ctx
+                .eval_to_completion("Promise.reject(new Error('unhandled'))")
+                .catch(&ctx)
+            {
+                Err(CaughtError::Exception(e)) => {
+                    assert_eq!(e.message().as_deref(), Some("unhandled"))
+                }
+                x => panic!("expected an exception, got {x:?}"),
+            }
+
+            ctx.eval_to_completion(
+                "const p = Promise.reject(new Error('handled')); await null; p.catch(() => {})",
+            )
+            .unwrap();
+        })
+    }
+
+    #[test]
+    fn userdata_in_function() {
+        use crate::{Context, Ctx, Function, Runtime};
+
+        struct RequestContext {
+            id: u32,
+            path: std::string::String,
+        }
+
+        unsafe impl<'js> JsLifetime<'js> for RequestContext {
+            type Changed<'to> = RequestContext;
+        }
+
+        let rt = Runtime::new().unwrap();
+        let ctx = Context::full(&rt).unwrap();
+
+        ctx.with(|ctx| {
+            assert!(ctx.context_userdata::<RequestContext>().is_none());
+            ctx.store_context_userdata(RequestContext {
+                id: 7,
+                path: "/index".to_string(),
+            })
+            .unwrap();
+
+            let describe = Function::new(ctx.clone(), |ctx: Ctx| {
+                let request = ctx.context_userdata::<RequestContext>().unwrap();
+                format!("{} {}", request.id, request.path)
+            })
+            .unwrap();
+            ctx.globals().set("describe", describe).unwrap();
+
+            let res: std::string::String = ctx.eval("describe()").catch(&ctx).unwrap();
+            assert_eq!(res, "7 /index");
+            assert!(ctx.context_userdata::<u32>().is_none());
+            assert!(ctx.userdata::<RequestContext>().is_none());
+        })
+    }
+
+    #[test]
+    fn context_userdata() {
+        use crate::{Context, Runtime};
+        use std::{cell::Cell, rc::Rc};
+
+        struct DropFlag(Rc<Cell<bool>>);
+
+        impl Drop for DropFlag {
+            fn drop(&mut self) {
+                self.0.set(true);
+            }
+        }
+
+        unsafe impl<'js> JsLifetime<'js> for DropFlag {
+            type Changed<'to> = DropFlag;
+        }
+
+        let rt = Runtime::new().unwrap();
+        let a = Context::full(&rt).unwrap();
+        let b = Context::full(&rt).unwrap();
+        let dropped = Rc::new(Cell::new(false));
+
+        a.with(|ctx| {
+            ctx.store_context_userdata(DropFlag(dropped.clone()))
+                .unwrap();
+            ctx.store_context_userdata(1u32).unwrap();
+            assert_eq!(*ctx.context_userdata::<u32>().unwrap(), 1);
+        });
+        b.with(|ctx| {
+            assert!(ctx.context_userdata::<DropFlag>().is_none());
+            ctx.store_context_userdata(2u32).unwrap();
+            assert_eq!(*ctx.context_userdata::<u32>().unwrap(), 2);
+        });
+
+        let a_clone = a.clone();
+        drop(a);
+        a_clone.with(|ctx| {
+            assert_eq!(*ctx.context_userdata::<u32>().unwrap(), 1);
+        });
+        assert!(!dropped.get());
+        drop(a_clone);
+        assert!(dropped.get());
+
+        b.with(|ctx| {
+            assert_eq!(*ctx.context_userdata::<u32>().unwrap(), 2);
+        });
+    }
 }
@@ -0,0 +1,128 @@
+use crate::{Ctx, Function, JsLifetime, Object, Result, Value};
+
+/// A snapshot of the properties of the global object of a context.
+///
+/// Created with [`Ctx::snapshot_globals`] and restored with [`Ctx::restore_globals`].
+#[derive(Debug, Clone)]
+pub struct GlobalSnapshot<'js> {
+    /// The property descriptors of the global object, without a prototype.
+    descriptors: Object<'js>,
+    own_keys: Function<'js>,
+    define_property: Function<'js>,
+    delete_property: Function<'js>,
+}
+
+unsafe impl<'js> JsLifetime<'js> for GlobalSnapshot<'js> {
+    type Changed<'to> = GlobalSnapshot<'to>;
+}
+
+impl<'js> Ctx<'js> {
+    /// Record the current properties of the global object.
+    ///
+    /// All own properties are recorded, including non-enumerable properties like the builtin
+    /// constructors and properties with a symbol as key. Values are recorded by reference, so
+    /// changes made to objects reachable from the globals, like adding a method to
+    /// `Array.prototype`, are not undone when the snapshot is restored.
+    pub fn snapshot_globals(&self) -> Result<GlobalSnapshot<'js>> {
+        let globals = self.globals();
+        let object: Object = globals.get("Object")?;
+        let reflect: Object = globals.get("Reflect")?;
+        let descriptors: Object =
+            object.call_method("getOwnPropertyDescriptors", (globals.clone(),))?;
+        descriptors.set_prototype(None)?;
+        Ok(GlobalSnapshot {
+            descriptors,
+            own_keys: reflect.get("ownKeys")?,
+            define_property: reflect.get("defineProperty")?,
+            delete_property: reflect.get("deleteProperty")?,
+        })
+    }
+
+    /// Reset the properties of the global object to a snapshot taken with
+    /// [`Ctx::snapshot_globals`].
+    ///
+    /// Properties added since the snapshot are deleted and properties which were changed or
+    /// deleted get back their recorded value and attributes. This is cheaper than creating a new
+    /// context but doesn't reset everything: non-configurable properties, like globals declared
+    /// with `var` or `function`, can't be deleted or changed and are left as they are, and
+    /// top-level `let`, `const` and `class` declarations are not properties of the global object.
+    pub fn restore_globals(&self, snapshot: &GlobalSnapshot<'js>) -> Result<()> {
+        let globals = self.globals();
+        let keys: Vec<Value> = snapshot.own_keys.call((globals.clone(),))?;
+        for key in keys {
+            if !snapshot.descriptors.contains_key(key.clone())? {
+                snapshot
+                    .delete_property
+                    .call::<_, bool>((globals.clone(), key))?;
+            }
+        }
+
+        let keys: Vec<Value> = snapshot.own_keys.call((snapshot.descriptors.clone(),))?;
+        for key in keys {
+            let descriptor: Value = snapshot.descriptors.get(key.clone())?;
+            snapshot
+                .define_property
+                .call::<_, bool>((globals.clone(), key, descriptor))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn snapshot_and_restore() {
+        test_with(|ctx| {
+            ctx.globals().set("kept", 1).unwrap();
+            let snapshot = ctx.snapshot_globals().unwrap();
+
+            ctx.eval::<(), _>(
+                r#"
+                globalThis.added = 1;
+                globalThis[Symbol.for("added")] = 2;
+                kept = 2;
+                delete globalThis.JSON;
+                Object.defineProperty(globalThis, "Math", { value: 3, enumerable: true });
+                Reflect = null;
+            "#,
+            )
+            .unwrap();
+
+            ctx.restore_globals(&snapshot).unwrap();
+            let res: Vec<StdString> = ctx
+                .eval(
+                    r#"
+                    [
+                        typeof added,
+                        typeof globalThis[Symbol.for("added")],
+                        String(kept),
+                        typeof JSON.stringify,
+                        typeof Math.max,
+                        String(Object.keys(globalThis).includes("Math")),
+                        typeof Reflect.ownKeys,
+                    ]
+                "#,
+                )
+                .unwrap();
+            assert_eq!(
+                res,
+                [
+                    "undefined",
+                    "undefined",
+                    "1",
+                    "function",
+                    "function",
+                    "false",
+                    "function"
+                ]
+            );
+
+            // A snapshot can be restored more than once.
+            ctx.globals().set("added", 1).unwrap();
+            ctx.restore_globals(&snapshot).unwrap();
+            assert!(!ctx.globals().contains_key("added").unwrap());
+        })
+    }
+}
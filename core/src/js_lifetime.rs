@@ -1,6 +1,6 @@
 use crate::{
-    atom, value::Constructor, Array, Atom, BigInt, CString, Exception, Function, Module, Object,
-    Promise, String, Symbol, Value,
+    atom, value::Constructor, Array, Atom, BigInt, CString, Exception, Function, Map, Module,
+    Object, Persistent, PersistentFunction, Promise, String, Symbol, Value,
 };
 
 /// The trait which signifies a type using the rquickjs `'js` lifetime trick for maintaining safety around Javascript values.
@@ -100,6 +100,7 @@ outlive_impls! {
     CString,
     Object,
     Array,
+    Map,
     BigInt,
     Function,
     Constructor,
@@ -176,10 +177,50 @@ impl_outlive!(
     atom::PredefinedAtom,
 );
 
+macro_rules! impl_outlive_tuple {
+    ($($($ty:ident)*,)*) => {
+        $(
+            unsafe impl<'js, $($ty,)*> JsLifetime<'js> for ($($ty,)*)
+            where
+                $($ty: JsLifetime<'js>,)*
+            {
+                type Changed<'to> = ($($ty::Changed<'to>,)*);
+            }
+        )*
+    };
+}
+
+impl_outlive_tuple! {
+    A,
+    A B,
+    A B C,
+    A B C D,
+    A B C D E,
+    A B C D E F,
+    A B C D E F G,
+    A B C D E F G H,
+    A B C D E F G H I,
+    A B C D E F G H I J,
+    A B C D E F G H I J K,
+    A B C D E F G H I J K L,
+    A B C D E F G H I J K L M,
+    A B C D E F G H I J K L M N,
+    A B C D E F G H I J K L M N O,
+    A B C D E F G H I J K L M N O P,
+}
+
 unsafe impl<'js, T: JsLifetime<'js>> JsLifetime<'js> for Module<'js, T> {
     type Changed<'to> = Module<'to, T::Changed<'to>>;
 }
 
+unsafe impl<'js, T: 'static> JsLifetime<'js> for Persistent<T> {
+    type Changed<'to> = Persistent<T>;
+}
+
+unsafe impl<'js> JsLifetime<'js> for PersistentFunction {
+    type Changed<'to> = PersistentFunction;
+}
+
 unsafe impl<'js> JsLifetime<'js> for () {
     type Changed<'to> = ();
 }
@@ -23,12 +23,13 @@ pub use context::{Context, Ctx};
 pub mod class;
 pub use class::Class;
 pub use js_lifetime::JsLifetime;
-pub use persistent::Persistent;
+pub use persistent::{Persistent, PersistentFunction, SharedValue};
 pub use result::{CatchResultExt, CaughtError, CaughtResult, Error, Result, ThrowResultExt};
 pub use value::{
-    array, atom, convert, function, module, object, promise, Array, Atom, BigInt, CString, Coerced,
-    Exception, Filter, FromAtom, FromIteratorJs, FromJs, Function, IntoAtom, IntoJs, IteratorJs,
-    Module, Null, Object, Promise, String, Symbol, Type, Undefined, Value,
+    array, atom, convert, function, map, module, object, promise, Array, Atom, BigInt, CString,
+    Coerced, Exception, Filter, FromAtom, FromIteratorJs, FromJs, Function, InspectOptions,
+    IntoAtom, IntoJs, IteratorJs, JsIterator, Map, Module, Null, Object, Promise, StackFrame,
+    String, Symbol, Type, Undefined, Value,
 };
 
 #[cfg(feature = "allocator")]
@@ -46,9 +47,12 @@ pub use context::MultiWith;
 #[cfg(feature = "futures")]
 #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "futures")))]
 pub use runtime::AsyncRuntime;
+#[cfg(feature = "futures")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "futures")))]
+pub use value::stream;
 #[cfg(feature = "array-buffer")]
 #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "array-buffer")))]
-pub use value::{ArrayBuffer, TypedArray};
+pub use value::{ArrayBuffer, ClampedU8, TypedArray};
 
 //#[doc(hidden)]
 pub mod qjs {
@@ -68,9 +72,12 @@ pub mod prelude {
     pub use crate::context::MultiWith;
     pub use crate::{
         context::Ctx,
-        convert::{Coerced, FromAtom, FromIteratorJs, FromJs, IntoAtom, IntoJs, IteratorJs, List},
+        convert::{
+            Coerced, FromAtom, FromIteratorJs, FromJs, IntoAtom, IntoJs, IteratorJs, List, Strict,
+        },
         function::{
-            Exhaustive, Flat, Func, FuncArg, IntoArg, IntoArgs, MutFn, OnceFn, Opt, Rest, This,
+            Exhaustive, Flat, Func, FuncArg, IntoArg, IntoArgs, MutFn, NewTarget, OnceFn, Opt,
+            Rest, This,
         },
         result::{CatchResultExt, ThrowResultExt},
         JsLifetime,
@@ -80,6 +87,7 @@ pub mod prelude {
     pub use crate::{
         function::Async,
         promise::{Promise, Promised},
+        stream::Streamed,
     };
 }
 
@@ -2,7 +2,7 @@
 
 use std::{ffi::CStr, ptr};
 
-use crate::{module::Declared, qjs, Ctx, Module, Result};
+use crate::{module::Declared, qjs, Ctx, Error, Module, Result};
 
 mod builtin_loader;
 mod builtin_resolver;
@@ -20,7 +20,7 @@ pub use builtin_loader::BuiltinLoader;
 pub use builtin_resolver::BuiltinResolver;
 pub use compile::Compile;
 pub use file_resolver::FileResolver;
-pub use module_loader::ModuleLoader;
+pub use module_loader::{DynModuleDef, ModuleLoader};
 pub use script_loader::ScriptLoader;
 
 #[cfg(feature = "dyn-load")]
@@ -70,6 +70,10 @@ pub trait Loader {
 struct LoaderOpaque {
     resolver: Box<dyn Resolver>,
     loader: Box<dyn Loader>,
+    /// The last resolved name and the module which imported it, used to add context to loading
+    /// errors. QuickJS loads a module right after resolving it if it isn't loaded yet, so this is
+    /// replaced on every resolve.
+    last_import: Option<(String, String)>,
 }
 
 #[derive(Debug)]
@@ -91,6 +95,7 @@ impl LoaderHolder {
         Self(Box::into_raw(Box::new(LoaderOpaque {
             resolver: Box::new(resolver),
             loader: Box::new(loader),
+            last_import: None,
         })))
     }
 
@@ -115,7 +120,11 @@ impl LoaderHolder {
         let base = base.to_str()?;
         let name = name.to_str()?;
 
+        opaque.last_import = None;
         let name = opaque.resolver.resolve(ctx, base, name)?;
+        if !base.is_empty() {
+            opaque.last_import = Some((name.clone(), base.to_owned()));
+        }
 
         // We should transfer ownership of this string to QuickJS
         Ok(
@@ -149,8 +158,32 @@ impl LoaderHolder {
         name: &CStr,
     ) -> Result<*mut qjs::JSModuleDef> {
         let name = name.to_str()?;
-
-        Ok(opaque.loader.load(ctx, name)?.as_ptr())
+        let importer = opaque
+            .last_import
+            .take()
+            .filter(|(resolved, _)| resolved == name)
+            .map(|(_, importer)| importer);
+
+        match opaque.loader.load(ctx, name) {
+            Ok(module) => Ok(module.as_ptr()),
+            // Loaders only know the name of the module, add which module imported it.
+            Err(Error::Loading { name, message }) => {
+                let Some(importer) = importer else {
+                    return Err(Error::Loading { name, message });
+                };
+                let message = match message {
+                    Some(message) if !message.is_empty() => {
+                        format!("{message} (imported from '{importer}')")
+                    }
+                    _ => format!("imported from '{importer}'"),
+                };
+                Err(Error::Loading {
+                    name,
+                    message: Some(message.into()),
+                })
+            }
+            Err(error) => Err(error),
+        }
     }
 
     unsafe extern "C" fn load_raw(
@@ -221,7 +254,7 @@ macro_rules! loader_impls {
                         match $t.load(_ctx, name) {
                             // Still could try the next loader
                             Err($crate::Error::Loading { message, .. }) => {
-                                message.map(|message| messages.push(message));
+                                message.map(|message| messages.push(message.into_owned()));
                             },
                             result => return result,
                         }
@@ -279,6 +312,47 @@ mod test {
         }
     }
 
+    #[test]
+    fn loading_error_names_importer() {
+        let rt = Runtime::new().unwrap();
+        let ctx = Context::full(&rt).unwrap();
+        rt.set_loader(
+            crate::loader::BuiltinResolver::default()
+                .with_module("test")
+                .with_module("missing"),
+            TestLoader,
+        );
+        ctx.with(|ctx| {
+            let err = Module::evaluate(
+                ctx.clone(),
+                "loader",
+                r#"
+                      import { n } from "missing";
+                    "#,
+            )
+            .catch(&ctx)
+            .unwrap_err();
+            let message = err.to_string();
+            assert!(message.contains("'missing'"), "{message}");
+            assert!(message.contains("imported from 'loader'"), "{message}");
+
+            // Resolving an already loaded module doesn't leave an importer behind.
+            Module::evaluate(ctx.clone(), "cached", "import { n } from 'test';")
+                .unwrap()
+                .finish::<()>()
+                .unwrap();
+            Module::evaluate(ctx.clone(), "again", "import { n } from 'test';")
+                .unwrap()
+                .finish::<()>()
+                .unwrap();
+            let err = Module::evaluate(ctx.clone(), "other", "import { n } from 'missing';")
+                .catch(&ctx)
+                .unwrap_err();
+            let message = err.to_string();
+            assert!(message.contains("imported from 'other'"), "{message}");
+        })
+    }
+
     #[test]
     fn custom_loader() {
         let rt = Runtime::new().unwrap();
@@ -1,16 +1,48 @@
 use crate::{module::ModuleDef, Ctx, Error, Module, Result};
-use std::{collections::HashMap, fmt::Debug};
+use std::{collections::HashMap, fmt};
 
 use super::Loader;
 
 type LoadFn = for<'js> fn(Ctx<'js>, Vec<u8>) -> Result<Module<'js>>;
 
+type LoaderFn = Box<dyn FnMut(&str) -> Option<DynModuleDef>>;
+
+/// A type erased [`ModuleDef`].
+///
+/// Returned by the functions given to [`ModuleLoader::with_loader_fn`] to pick the native module
+/// to load for a name.
+#[derive(Clone, Copy)]
+pub struct DynModuleDef(LoadFn);
+
+impl DynModuleDef {
+    /// Create a type erased module definition from a module definition.
+    pub fn new<M: ModuleDef>(_module: M) -> Self {
+        DynModuleDef(ModuleLoader::load_func::<M>)
+    }
+}
+
+impl fmt::Debug for DynModuleDef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynModuleDef").finish()
+    }
+}
+
 /// The builtin native module loader
 ///
 /// This loader can be used as the nested backing loader in user-defined loaders.
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub struct ModuleLoader {
     modules: HashMap<String, LoadFn>,
+    loader_fns: Vec<LoaderFn>,
+}
+
+impl fmt::Debug for ModuleLoader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ModuleLoader")
+            .field("modules", &self.modules)
+            .field("loader_fns", &self.loader_fns.len())
+            .finish()
+    }
 }
 
 impl ModuleLoader {
@@ -30,6 +62,38 @@ impl ModuleLoader {
         self.add_module(name, module);
         self
     }
+
+    /// Add a function which picks the module to load for a name when the module is loaded.
+    ///
+    /// The function is called with the resolved name of modules which were not added with
+    /// [`ModuleLoader::add_module`]. Functions are tried in the order they were added until one
+    /// returns a module.
+    pub fn add_loader_fn<F>(&mut self, loader_fn: F) -> &mut Self
+    where
+        F: FnMut(&str) -> Option<DynModuleDef> + 'static,
+    {
+        self.loader_fns.push(Box::new(loader_fn));
+        self
+    }
+
+    /// Add a function which picks the module to load for a name when the module is loaded.
+    ///
+    /// ```
+    /// # use rquickjs::{loader::{DynModuleDef, ModuleLoader}, module::ModuleDef};
+    /// struct Plugin;
+    /// impl ModuleDef for Plugin {}
+    ///
+    /// let loader = ModuleLoader::default()
+    ///     .with_loader_fn(|name| name.strip_prefix("plugin/").map(|_| DynModuleDef::new(Plugin)));
+    /// ```
+    #[must_use]
+    pub fn with_loader_fn<F>(mut self, loader_fn: F) -> Self
+    where
+        F: FnMut(&str) -> Option<DynModuleDef> + 'static,
+    {
+        self.add_loader_fn(loader_fn);
+        self
+    }
 }
 
 impl Loader for ModuleLoader {
@@ -37,8 +101,84 @@ impl Loader for ModuleLoader {
         let load = self
             .modules
             .remove(path)
+            .or_else(|| {
+                self.loader_fns
+                    .iter_mut()
+                    .find_map(|loader_fn| loader_fn(path))
+                    .map(|x| x.0)
+            })
             .ok_or_else(|| Error::new_loading(path))?;
 
         (load)(ctx.clone(), Vec::from(path))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        loader::{DynModuleDef, ModuleLoader, Resolver},
+        module::{Declarations, Exports, ModuleDef},
+        CatchResultExt, Context, Ctx, Error, Module, Result, Runtime,
+    };
+
+    struct PluginResolver;
+
+    impl Resolver for PluginResolver {
+        fn resolve<'js>(&mut self, _ctx: &Ctx<'js>, base: &str, name: &str) -> Result<String> {
+            if name.starts_with("plugin") {
+                Ok(name.into())
+            } else {
+                Err(Error::new_resolving(base, name))
+            }
+        }
+    }
+
+    struct Plugin;
+
+    impl ModuleDef for Plugin {
+        fn declare(decl: &Declarations) -> Result<()> {
+            decl.declare("value")?;
+            Ok(())
+        }
+
+        fn evaluate<'js>(ctx: &Ctx<'js>, exports: &Exports<'js>) -> Result<()> {
+            let loaded = ctx.globals().get::<_, Option<u32>>("loaded")?.unwrap_or(0);
+            ctx.globals().set("loaded", loaded + 1)?;
+            exports.export("value", loaded + 1)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn loader_fn() {
+        let rt = Runtime::new().unwrap();
+        let ctx = Context::full(&rt).unwrap();
+        let loader = ModuleLoader::default().with_loader_fn(|name| {
+            name.strip_prefix("plugin/")
+                .map(|_| DynModuleDef::new(Plugin))
+        });
+        rt.set_loader(PluginResolver, loader);
+        ctx.with(|ctx| {
+            Module::evaluate(
+                ctx.clone(),
+                "main",
+                r#"
+                import { value as a } from "plugin/a";
+                import { value as b } from "plugin/b";
+                globalThis.values = [a, b];
+                "#,
+            )
+            .catch(&ctx)
+            .unwrap()
+            .finish::<()>()
+            .catch(&ctx)
+            .unwrap();
+            let values: Vec<u32> = ctx.globals().get("values").unwrap();
+            assert_eq!(values, vec![1, 2]);
+
+            let err = Module::evaluate(ctx.clone(), "other", "import 'plugin_c';").unwrap_err();
+            assert!(matches!(err, Error::Exception));
+            ctx.catch();
+        })
+    }
+}
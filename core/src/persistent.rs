@@ -1,4 +1,6 @@
-use crate::{qjs, Ctx, Error, FromJs, IntoJs, JsLifetime, Result, Value};
+use crate::{
+    function::IntoArgs, qjs, Ctx, Error, FromJs, Function, IntoJs, JsLifetime, Result, Value,
+};
 
 use std::{
     fmt,
@@ -33,6 +35,9 @@ use std::{
 /// NOTE: Be careful and ensure that no persistent links outlives the runtime,
 /// otherwise Runtime will abort the process when dropped.
 ///
+/// A `Persistent` stored in a class should be traced, see the [`Trace`](crate::class::Trace)
+/// implementation, so that cycles running through the persisted value can be collected.
+///
 #[derive(Eq, PartialEq, Hash)]
 pub struct Persistent<T> {
     pub(crate) rt: *mut qjs::JSRuntime,
@@ -132,6 +137,117 @@ where
     }
 }
 
+/// A value which can be explicitly shared between contexts of the same runtime.
+///
+/// Unlike [`Persistent`], restoring a `SharedValue` binds the value to the context it is restored
+/// in, so the value returned from [`SharedValue::restore`] is used with the realm of that context.
+///
+/// ```
+/// # use rquickjs::{Runtime, Context, SharedValue, Object};
+/// # let rt = Runtime::new().unwrap();
+/// let ctx_a = Context::full(&rt).unwrap();
+/// let ctx_b = Context::full(&rt).unwrap();
+/// let shared = ctx_a.with(|ctx| {
+///     let obj: Object = ctx.eval("({ a: 1 })").unwrap();
+///     SharedValue::new(&ctx, obj).unwrap()
+/// });
+/// ctx_b.with(|ctx| {
+///     let obj: Object = shared.restore(&ctx).unwrap();
+///     assert_eq!(obj.get::<_, i32>("a").unwrap(), 1);
+/// });
+/// ```
+///
+/// It is an error (`Error::UnrelatedRuntime`) to restore the value in a context which isn't part
+/// of the runtime the value was created in.
+#[derive(Clone)]
+pub struct SharedValue(Persistent<Value<'static>>);
+
+impl fmt::Debug for SharedValue {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_tuple("SharedValue").field(&self.0).finish()
+    }
+}
+
+impl SharedValue {
+    /// Create a new shared value from anything which can be converted into a JavaScript value.
+    pub fn new<'js, V: IntoJs<'js>>(ctx: &Ctx<'js>, value: V) -> Result<Self> {
+        let value = value.into_js(ctx)?;
+        Ok(SharedValue(Persistent::save(ctx, value)))
+    }
+
+    /// Restore the value in the given context, converting it into the requested type.
+    pub fn restore<'js, T: FromJs<'js>>(&self, ctx: &Ctx<'js>) -> Result<T> {
+        let ctx_runtime_ptr = unsafe { qjs::JS_GetRuntime(ctx.as_ptr()) };
+        if self.0.rt != ctx_runtime_ptr {
+            return Err(Error::UnrelatedRuntime);
+        }
+        let value = unsafe { Value::from_js_value_const(ctx.clone(), self.0.value.as_js_value()) };
+        T::from_js(ctx, value)
+    }
+}
+
+/// A persisted JavaScript function which can be called later.
+///
+/// A convenience wrapper around a `Persistent<Function>` which restores and calls the function in
+/// one step.
+///
+/// ```
+/// # use rquickjs::{Runtime, Context, PersistentFunction, Function};
+/// # let rt = Runtime::new().unwrap();
+/// # let ctx = Context::full(&rt).unwrap();
+/// let func = ctx.with(|ctx| {
+///     PersistentFunction::new(&ctx, ctx.eval::<Function, _>("a => a + 1").unwrap())
+/// });
+/// let res: i32 = ctx.with(|ctx| func.call_in(&ctx, (2,)).unwrap());
+/// assert_eq!(res, 3);
+/// ```
+///
+/// It is an error (`Error::UnrelatedRuntime`) to call the function in a context which isn't part
+/// of the runtime the function was created in.
+#[derive(Clone)]
+pub struct PersistentFunction(pub(crate) Persistent<Function<'static>>);
+
+impl fmt::Debug for PersistentFunction {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_tuple("PersistentFunction")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+impl PersistentFunction {
+    /// Persist the given function.
+    pub fn new<'js>(ctx: &Ctx<'js>, func: Function<'js>) -> Self {
+        PersistentFunction(Persistent::save(ctx, func))
+    }
+
+    /// Restore the function in the given context.
+    pub fn restore<'js>(&self, ctx: &Ctx<'js>) -> Result<Function<'js>> {
+        self.0.clone().restore(ctx)
+    }
+
+    /// Restore the function in the given context and call it with the given arguments.
+    pub fn call_in<'js, A, R>(&self, ctx: &Ctx<'js>, args: A) -> Result<R>
+    where
+        A: IntoArgs<'js>,
+        R: FromJs<'js>,
+    {
+        self.restore(ctx)?.call(args)
+    }
+}
+
+impl From<Persistent<Function<'static>>> for PersistentFunction {
+    fn from(value: Persistent<Function<'static>>) -> Self {
+        PersistentFunction(value)
+    }
+}
+
+impl From<PersistentFunction> for Persistent<Function<'static>> {
+    fn from(value: PersistentFunction) -> Self {
+        value.0
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::*;
@@ -197,6 +313,41 @@ mod test {
         assert_eq!(res, 1);
     }
 
+    #[test]
+    fn shared_value_between_contexts() {
+        let rt = Runtime::new().unwrap();
+        let ctx_a = Context::full(&rt).unwrap();
+        let ctx_b = Context::full(&rt).unwrap();
+
+        let shared = ctx_a.with(|ctx| {
+            let obj: Object = ctx.eval("({ a: 1, b: 'foo' })").unwrap();
+            SharedValue::new(&ctx, obj).unwrap()
+        });
+
+        ctx_b.with(|ctx| {
+            let obj: Object = shared.restore(&ctx).unwrap();
+            assert_eq!(obj.get::<_, i32>("a").unwrap(), 1);
+            assert_eq!(obj.get::<_, StdString>("b").unwrap(), "foo");
+            ctx.globals().set("shared", obj).unwrap();
+            let b: StdString = ctx.eval("shared.b").unwrap();
+            assert_eq!(b, "foo");
+        });
+    }
+
+    #[test]
+    fn shared_value_different_runtime() {
+        let rt1 = Runtime::new().unwrap();
+        let ctx1 = Context::full(&rt1).unwrap();
+        let shared = ctx1.with(|ctx| SharedValue::new(&ctx, 1).unwrap());
+
+        let rt2 = Runtime::new().unwrap();
+        let ctx2 = Context::full(&rt2).unwrap();
+        ctx2.with(|ctx| {
+            let err = shared.restore::<i32>(&ctx).unwrap_err();
+            assert!(matches!(err, Error::UnrelatedRuntime));
+        });
+    }
+
     #[test]
     fn persistent_value() {
         let rt = Runtime::new().unwrap();
@@ -214,4 +365,31 @@ mod test {
             assert!(eq.as_bool().unwrap());
         });
     }
+
+    #[test]
+    fn persistent_function_call_in() {
+        let rt = Runtime::new().unwrap();
+        let ctx = Context::full(&rt).unwrap();
+
+        let func = ctx.with(|ctx| {
+            let func: Function = ctx
+                .eval("let count = 0; (a, b) => { count += 1; return a + b + count; }")
+                .unwrap();
+            PersistentFunction::new(&ctx, func)
+        });
+
+        ctx.with(|ctx| {
+            let res: i32 = func.call_in(&ctx, (1, 2)).unwrap();
+            assert_eq!(res, 4);
+            let res: i32 = func.call_in(&ctx, (1, 2)).unwrap();
+            assert_eq!(res, 5);
+        });
+
+        let rt2 = Runtime::new().unwrap();
+        let ctx2 = Context::full(&rt2).unwrap();
+        ctx2.with(|ctx| {
+            let err = func.call_in::<_, ()>(&ctx, ()).unwrap_err();
+            assert!(matches!(err, Error::UnrelatedRuntime));
+        });
+    }
 }
@@ -1,3 +1,5 @@
+#[cfg(feature = "loader")]
+use std::borrow::Cow;
 use std::{
     error::Error as StdError,
     ffi::{CString, FromBytesWithNulError, NulError},
@@ -115,8 +117,8 @@ pub enum Error {
     #[cfg(feature = "loader")]
     /// Error when loading js module
     Loading {
-        name: StdString,
-        message: Option<StdString>,
+        name: Cow<'static, str>,
+        message: Option<Cow<'static, str>>,
     },
     #[cfg(feature = "array-buffer")]
     AsSlice(AsSliceError),
@@ -125,6 +127,12 @@ pub enum Error {
     /// An error returned by a blocked on promise if block on the promise would result in a dead
     /// lock.
     WouldBlock,
+    /// Tried to retrieve the pending exception while no exception was pending.
+    NoException,
+    /// Tried to evaluate a module which was already evaluated.
+    ModuleAlreadyEvaluated {
+        name: StdString,
+    },
     /// An error related to userdata
     UserData(UserDataError<()>),
     /// An error from QuickJS from which the specifics are unknown.
@@ -172,7 +180,7 @@ impl Error {
         StdString: From<N>,
     {
         Error::Loading {
-            name: name.into(),
+            name: Cow::Owned(name.into()),
             message: None,
         }
     }
@@ -184,8 +192,17 @@ impl Error {
         StdString: From<N> + From<M>,
     {
         Error::Loading {
-            name: name.into(),
-            message: Some(msg.into()),
+            name: Cow::Owned(name.into()),
+            message: Some(Cow::Owned(msg.into())),
+        }
+    }
+
+    #[cfg(feature = "loader")]
+    /// Create loading error from static strings, without allocating
+    pub const fn new_loading_static(name: &'static str, msg: &'static str) -> Self {
+        Error::Loading {
+            name: Cow::Borrowed(name),
+            message: Some(Cow::Borrowed(msg)),
         }
     }
 
@@ -460,6 +477,12 @@ impl Display for Error {
                 x.fmt(f)?;
             }
             Error::WouldBlock => "Error blocking on a promise resulted in a dead lock".fmt(f)?,
+            Error::NoException => "No exception is pending".fmt(f)?,
+            Error::ModuleAlreadyEvaluated { name } => {
+                "Module '".fmt(f)?;
+                name.fmt(f)?;
+                "' was already evaluated".fmt(f)?;
+            }
             Error::UserData(x) => x.fmt(f)?,
             #[cfg(feature = "array-buffer")]
             Error::AsSlice(x) => {
@@ -578,6 +601,18 @@ impl<'js> CaughtError<'js> {
     pub fn is_js_error(&self) -> bool {
         matches!(self, CaughtError::Exception(_) | CaughtError::Value(_))
     }
+
+    /// Returns the thrown JavaScript value, `None` if the error wasn't an exception.
+    ///
+    /// Works for any thrown value, including values which aren't objects like in
+    /// `throw "boom"`.
+    pub fn into_value(self) -> Option<Value<'js>> {
+        match self {
+            CaughtError::Error(_) => None,
+            CaughtError::Exception(ex) => Some(ex.into_value()),
+            CaughtError::Value(value) => Some(value),
+        }
+    }
 }
 
 /// Extension trait to easily turn results with [`Error`] into results with [`CaughtError`]
@@ -713,3 +748,54 @@ impl<'js> Ctx<'js> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{test_with, CatchResultExt, Value};
+
+    #[test]
+    fn caught_into_value() {
+        test_with(|ctx| {
+            let value = ctx
+                .eval::<(), _>("throw 'boom'")
+                .catch(&ctx)
+                .unwrap_err()
+                .into_value()
+                .unwrap();
+            assert_eq!(value.as_string().unwrap().to_string().unwrap(), "boom");
+
+            let value = ctx
+                .eval::<(), _>("throw new TypeError('boom')")
+                .catch(&ctx)
+                .unwrap_err()
+                .into_value()
+                .unwrap();
+            assert!(value.is_error());
+
+            let error = ctx
+                .globals()
+                .get::<_, Value>("missing")
+                .and_then(|x| x.get::<i32>())
+                .catch(&ctx)
+                .unwrap_err();
+            assert!(error.into_value().is_none());
+        })
+    }
+
+    #[cfg(feature = "loader")]
+    #[test]
+    fn static_loading_error() {
+        use super::Error;
+
+        const ERROR: Error = Error::new_loading_static("foo.js", "not found");
+        assert!(ERROR.is_loading());
+        assert_eq!(
+            ERROR.to_string(),
+            Error::new_loading_message("foo.js", "not found").to_string()
+        );
+        assert_eq!(
+            ERROR.to_string(),
+            "Error loading module 'foo.js': not found"
+        );
+    }
+}
@@ -1,6 +1,7 @@
 //! QuickJS runtime related types.
 
 mod base;
+mod builder;
 pub(crate) mod opaque;
 pub(crate) mod raw;
 mod userdata;
@@ -13,6 +14,8 @@ pub(crate) mod schedular;
 mod spawner;
 
 pub use base::{Runtime, WeakRuntime};
+pub use builder::RuntimeBuilder;
+pub(crate) use userdata::ContextUserData;
 pub use userdata::{UserDataError, UserDataGuard};
 
 #[cfg(feature = "futures")]
@@ -27,5 +30,19 @@ pub type InterruptHandler = Box<dyn FnMut() -> bool + 'static>;
 #[cfg(feature = "parallel")]
 pub type InterruptHandler = Box<dyn FnMut() -> bool + Send + 'static>;
 
+/// The type of the promise rejection tracker.
+///
+/// Called with the promise, the rejection reason and whether the rejection is now handled.
+#[cfg(not(feature = "parallel"))]
+pub type RejectionTracker =
+    Box<dyn for<'js> Fn(crate::Ctx<'js>, crate::Value<'js>, crate::Value<'js>, bool) + 'static>;
+/// The type of the promise rejection tracker.
+///
+/// Called with the promise, the rejection reason and whether the rejection is now handled.
+#[cfg(feature = "parallel")]
+pub type RejectionTracker = Box<
+    dyn for<'js> Fn(crate::Ctx<'js>, crate::Value<'js>, crate::Value<'js>, bool) + Send + 'static,
+>;
+
 /// A struct with information about the runtimes memory usage.
 pub type MemoryUsage = crate::qjs::JSMemoryUsage;
@@ -1,5 +1,7 @@
 use std::{
     ffi::CString,
+    future::Future,
+    mem,
     ptr::NonNull,
     result::Result as StdResult,
     sync::{Arc, Weak},
@@ -12,15 +14,19 @@ use std::sync::mpsc::{self, Receiver, Sender};
 use async_lock::Mutex;
 
 use super::{
-    opaque::Opaque, raw::RawRuntime, schedular::SchedularPoll, spawner::DriveFuture,
-    InterruptHandler, MemoryUsage,
+    opaque::Opaque,
+    raw::RawRuntime,
+    schedular::SchedularPoll,
+    spawner::{DriveFuture, TaskSet},
+    InterruptHandler, MemoryUsage, RejectionTracker, RuntimeBuilder,
 };
 #[cfg(feature = "allocator")]
 use crate::allocator::Allocator;
 #[cfg(feature = "loader")]
 use crate::loader::{Loader, Resolver};
 use crate::{
-    context::AsyncContext, result::AsyncJobException, util::ManualPoll, Ctx, Exception, Result,
+    context::AsyncContext, markers::ParallelSend, result::AsyncJobException, util::ManualPoll, Ctx,
+    Exception, Result,
 };
 #[cfg(feature = "parallel")]
 use crate::{
@@ -39,7 +45,10 @@ impl InnerRuntime {
     pub fn drop_pending(&self) {
         #[cfg(feature = "parallel")]
         while let Ok(x) = self.drop_recv.try_recv() {
-            unsafe { qjs::JS_FreeContext(x.as_ptr()) }
+            unsafe {
+                super::ContextUserData::release(x.as_ptr(), 0);
+                qjs::JS_FreeContext(x.as_ptr())
+            }
         }
     }
 }
@@ -60,14 +69,18 @@ unsafe impl Send for InnerRuntime {}
 #[derive(Clone)]
 pub struct AsyncWeakRuntime {
     inner: Weak<Mutex<InnerRuntime>>,
+    pub(crate) tasks: Weak<TaskSet>,
     #[cfg(feature = "parallel")]
     drop_send: Sender<NonNull<qjs::JSContext>>,
 }
 
 impl AsyncWeakRuntime {
     pub fn try_ref(&self) -> Option<AsyncRuntime> {
-        self.inner.upgrade().map(|inner| AsyncRuntime {
+        let inner = self.inner.upgrade()?;
+        Some(AsyncRuntime {
             inner,
+            tasks: self.tasks.clone(),
+            _owned_tasks: self.tasks.upgrade(),
             #[cfg(feature = "parallel")]
             drop_send: self.drop_send.clone(),
         })
@@ -80,6 +93,11 @@ impl AsyncWeakRuntime {
 pub struct AsyncRuntime {
     // use Arc instead of Ref so we can use OwnedLock
     pub(crate) inner: Arc<Mutex<InnerRuntime>>,
+    /// Futures spawned with [`AsyncRuntime::spawn`], kept outside of the lock.
+    tasks: Weak<TaskSet>,
+    /// Keeps the spawned futures alive, `None` for the handles held by contexts so that a spawned
+    /// future holding a context doesn't keep itself alive.
+    _owned_tasks: Option<Arc<TaskSet>>,
     #[cfg(feature = "parallel")]
     pub(crate) drop_send: Sender<NonNull<qjs::JSContext>>,
 }
@@ -111,19 +129,7 @@ impl AsyncRuntime {
     pub fn new() -> Result<Self> {
         let opaque = Opaque::with_spawner();
         let runtime = unsafe { RawRuntime::new(opaque) }?;
-
-        #[cfg(feature = "parallel")]
-        let (drop_send, drop_recv) = mpsc::channel();
-
-        Ok(Self {
-            inner: Arc::new(Mutex::new(InnerRuntime {
-                runtime,
-                #[cfg(feature = "parallel")]
-                drop_recv,
-            })),
-            #[cfg(feature = "parallel")]
-            drop_send,
-        })
+        Ok(Self::from_raw(runtime))
     }
 
     /// Create a new runtime using specified allocator
@@ -139,25 +145,51 @@ impl AsyncRuntime {
     {
         let opaque = Opaque::with_spawner();
         let runtime = unsafe { RawRuntime::new_with_allocator(opaque, allocator) }?;
+        Ok(Self::from_raw(runtime))
+    }
 
+    // Annoying false positive clippy lint
+    #[allow(clippy::arc_with_non_send_sync)]
+    pub(crate) fn from_raw(runtime: RawRuntime) -> Self {
         #[cfg(feature = "parallel")]
         let (drop_send, drop_recv) = mpsc::channel();
+        let tasks = Arc::new(TaskSet::new());
 
-        Ok(Self {
+        Self {
             inner: Arc::new(Mutex::new(InnerRuntime {
                 runtime,
                 #[cfg(feature = "parallel")]
                 drop_recv,
             })),
+            tasks: Arc::downgrade(&tasks),
+            _owned_tasks: Some(tasks),
             #[cfg(feature = "parallel")]
             drop_send,
-        })
+        }
+    }
+
+    /// Returns a handle to the runtime for a context, which doesn't keep the spawned futures
+    /// alive.
+    pub(crate) fn context_handle(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            tasks: self.tasks.clone(),
+            _owned_tasks: None,
+            #[cfg(feature = "parallel")]
+            drop_send: self.drop_send.clone(),
+        }
+    }
+
+    /// Create a runtime builder for creating a runtime with a specific configuration.
+    pub fn builder() -> RuntimeBuilder {
+        RuntimeBuilder::default()
     }
 
     /// Get weak ref to runtime
     pub fn weak(&self) -> AsyncWeakRuntime {
         AsyncWeakRuntime {
             inner: Arc::downgrade(&self.inner),
+            tasks: self.tasks.clone(),
             #[cfg(feature = "parallel")]
             drop_send: self.drop_send.clone(),
         }
@@ -177,6 +209,22 @@ impl AsyncRuntime {
         }
     }
 
+    /// Set a closure which is called when a promise is rejected without a handler or a handler is
+    /// added to a promise which was rejected before.
+    ///
+    /// The closure receives the promise, the rejection reason and whether the rejection is now
+    /// handled.
+    #[inline]
+    pub async fn set_host_promise_rejection_tracker(&self, tracker: Option<RejectionTracker>) {
+        unsafe {
+            self.inner
+                .lock()
+                .await
+                .runtime
+                .set_host_promise_rejection_tracker(tracker);
+        }
+    }
+
     /// Set the module loader
     #[cfg(feature = "loader")]
     #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "loader")))]
@@ -213,7 +261,14 @@ impl AsyncRuntime {
 
     /// Set a limit on the max size of stack the runtime will use.
     ///
-    /// The default values is 256x1024 bytes.
+    /// The default value is 1024x1024 bytes. The stack is measured from the point where the
+    /// runtime is entered, exceeding the limit throws a `RangeError` which can be caught from
+    /// JavaScript. The limit should be comfortably smaller than the stack of the thread the runtime
+    /// is used on, otherwise deep recursion might overflow the thread stack before the limit is
+    /// reached.
+    ///
+    /// QuickJS has no per-context stack limit, the limit applies to all the contexts of the
+    /// runtime including contexts which are created later.
     pub async fn set_max_stack_size(&self, limit: usize) {
         unsafe {
             self.inner.lock().await.runtime.set_max_stack_size(limit);
@@ -253,7 +308,9 @@ impl AsyncRuntime {
     pub async fn is_job_pending(&self) -> bool {
         let lock = self.inner.lock().await;
 
-        lock.runtime.is_job_pending() || !lock.runtime.get_opaque().spawner_is_empty()
+        lock.runtime.is_job_pending()
+            || !lock.runtime.get_opaque().spawner_is_empty()
+            || matches!(self.tasks.upgrade(), Some(tasks) if !tasks.is_empty())
     }
 
     /// Execute first pending job
@@ -291,8 +348,45 @@ impl AsyncRuntime {
     }
 
     /// Run all futures and jobs in the runtime until all are finished.
+    ///
+    /// This includes the futures spawned with [`AsyncRuntime::spawn`].
     #[inline]
     pub async fn idle(&self) {
+        loop {
+            let runtime_empty = self.run_until_stalled().await;
+
+            // Spawned tasks are polled without the lock so they can lock the runtime themselves.
+            let tasks = ManualPoll::new(|cx| {
+                Poll::Ready(match self.tasks.upgrade() {
+                    Some(tasks) => tasks.poll(cx),
+                    None => SchedularPoll::Empty,
+                })
+            });
+            match tasks.await {
+                SchedularPoll::Empty if runtime_empty => return,
+                // Tasks might have queued jobs, run the runtime again.
+                SchedularPoll::PendingProgress => continue,
+                SchedularPoll::Empty | SchedularPoll::Pending | SchedularPoll::ShouldYield => {}
+            }
+
+            // Nothing can make progress right now, wait until either the runtime or a task is
+            // woken.
+            let mut woken = false;
+            ManualPoll::new(|_| {
+                if mem::replace(&mut woken, true) {
+                    Poll::Ready(())
+                } else {
+                    Poll::Pending
+                }
+            })
+            .await;
+        }
+    }
+
+    /// Run the jobs and futures inside the runtime until no more progress can be made.
+    ///
+    /// Returns whether all futures inside the runtime are finished.
+    async fn run_until_stalled(&self) -> bool {
         let mut lock = self.inner.lock().await;
         lock.runtime.update_stack_top();
         lock.drop_pending();
@@ -322,9 +416,10 @@ impl AsyncRuntime {
                 }
 
                 match lock.runtime.get_opaque().poll(cx) {
-                    SchedularPoll::ShouldYield => return Poll::Pending,
-                    SchedularPoll::Empty => return Poll::Ready(()),
-                    SchedularPoll::Pending => return Poll::Pending,
+                    SchedularPoll::Empty => return Poll::Ready(true),
+                    SchedularPoll::ShouldYield | SchedularPoll::Pending => {
+                        return Poll::Ready(false)
+                    }
                     SchedularPoll::PendingProgress => {}
                 }
             }
@@ -336,6 +431,25 @@ impl AsyncRuntime {
         f.await
     }
 
+    /// Spawn a future on the runtime.
+    ///
+    /// The future is driven by [`AsyncRuntime::drive`] and [`AsyncRuntime::idle`], which also
+    /// waits for spawned futures to complete. Spawned futures are polled without holding the
+    /// runtime lock, so they can use a context with for example [`async_with!`](crate::async_with).
+    ///
+    /// Futures which haven't completed are dropped together with the last handle to the runtime
+    /// which isn't held by a context, so a future can hold on to an [`AsyncContext`] without
+    /// keeping the runtime alive. The handle returned by [`AsyncContext::runtime`] is held by the
+    /// context, futures spawned on it after the other handles are dropped are dropped immediately.
+    pub fn spawn<F>(&self, future: F)
+    where
+        F: Future<Output = ()> + ParallelSend + 'static,
+    {
+        if let Some(tasks) = self.tasks.upgrade() {
+            tasks.push(Box::pin(future));
+        }
+    }
+
     /// Returns a future that completes when the runtime is dropped.
     /// If the future is polled it will drive futures spawned inside the runtime completing them
     /// even if runtime is currently not in use.
@@ -397,6 +511,32 @@ mod test {
         }).await;
     });
 
+    async_test_case!(stack_overflow_is_catchable => (_rt,ctx){
+        use crate::prelude::{Async, Func};
+
+        let res = async_with!(&ctx => |ctx|{
+            ctx.eval::<bool,_>(r#"
+                function recurse(){ return recurse() + 1 }
+                try { recurse(); false } catch(e) { e instanceof RangeError }
+            "#).unwrap()
+        }).await;
+        assert!(res);
+
+        let res = async_with!(&ctx => |ctx|{
+            ctx.globals().set("sleep", Func::from(Async(|| async {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }))).unwrap();
+            let promise: Promise = ctx.eval(r#"
+                (async () => {
+                    await sleep();
+                    try { recurse(); return false } catch(e) { return e instanceof RangeError }
+                })()
+            "#).unwrap();
+            promise.into_future::<bool>().await.catch(&ctx).unwrap()
+        }).await;
+        assert!(res);
+    });
+
     async_test_case!(sleep_closure => (_rt,ctx){
 
         let mut a = 1;
@@ -411,6 +551,79 @@ mod test {
         assert_eq!(a,2);
     });
 
+    async_test_case!(spawn_resolves_promise => (rt,ctx){
+        async_with!(ctx => |ctx|{
+            let (promise, resolve, _) = ctx.promise().unwrap();
+            ctx.globals().set("resolve", resolve).unwrap();
+            ctx.globals().set("promise", promise).unwrap();
+            ctx.eval::<(), _>("globalThis.result = 0; promise.then((x) => { result = x + 1 })").unwrap();
+        }).await;
+
+        let task_ctx = ctx.clone();
+        rt.spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            async_with!(task_ctx => |ctx|{
+                let resolve: Function = ctx.globals().get("resolve").unwrap();
+                resolve.call::<_, ()>((42,)).unwrap();
+            }).await;
+        });
+        assert!(rt.is_job_pending().await);
+
+        rt.idle().await;
+        assert!(!rt.is_job_pending().await);
+
+        let res = async_with!(ctx => |ctx|{
+            ctx.globals().get::<_, i32>("result").unwrap()
+        }).await;
+        assert_eq!(res, 43);
+    });
+
+    async_test_case!(spawn_dropped_with_runtime => (rt,ctx){
+        use std::sync::{Arc, atomic::{AtomicBool, Ordering}};
+
+        struct Guard(Arc<AtomicBool>);
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        let dropped = Arc::new(AtomicBool::new(false));
+        let guard = Guard(dropped.clone());
+        rt.spawn(async move {
+            let _guard = guard;
+            std::future::pending::<()>().await;
+        });
+        async_with!(ctx => |ctx|{
+            ctx.eval::<(), _>("1 + 1").unwrap();
+        }).await;
+        assert!(!dropped.load(Ordering::SeqCst));
+
+        drop(ctx);
+        drop(rt);
+        assert!(dropped.load(Ordering::SeqCst));
+    });
+
+    async_test_case!(spawn_holding_context_dropped_with_runtime => (rt,ctx){
+        let weak = rt.weak();
+        let task_ctx = ctx.clone();
+        rt.spawn(async move {
+            loop {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                async_with!(task_ctx => |ctx|{
+                    ctx.eval::<(), _>("1 + 1").unwrap();
+                }).await;
+            }
+        });
+        // The task never completes, so idle only returns by timing out.
+        assert!(tokio::time::timeout(Duration::from_millis(10), rt.idle()).await.is_err());
+        assert!(rt.is_job_pending().await);
+
+        drop(ctx);
+        drop(rt);
+        assert!(weak.try_ref().is_none());
+    });
+
     async_test_case!(drive => (rt,ctx){
         use std::sync::{Arc, atomic::{Ordering,AtomicUsize}};
 
@@ -1,6 +1,9 @@
 //! QuickJS runtime related types.
 
-use super::{opaque::Opaque, raw::RawRuntime, InterruptHandler, MemoryUsage};
+use super::{
+    opaque::Opaque, raw::RawRuntime, InterruptHandler, MemoryUsage, RejectionTracker,
+    RuntimeBuilder,
+};
 #[cfg(feature = "allocator")]
 use crate::allocator::Allocator;
 #[cfg(feature = "loader")]
@@ -38,9 +41,7 @@ impl Runtime {
     pub fn new() -> Result<Self> {
         let opaque = Opaque::new();
         let rt = unsafe { RawRuntime::new(opaque)? };
-        Ok(Self {
-            inner: Ref::new(Mut::new(rt)),
-        })
+        Ok(Self::from_raw(rt))
     }
 
     /// Create a new runtime using specified allocator
@@ -54,9 +55,18 @@ impl Runtime {
     {
         let opaque = Opaque::new();
         let rt = unsafe { RawRuntime::new_with_allocator(opaque, allocator)? };
-        Ok(Self {
+        Ok(Self::from_raw(rt))
+    }
+
+    pub(crate) fn from_raw(rt: RawRuntime) -> Self {
+        Self {
             inner: Ref::new(Mut::new(rt)),
-        })
+        }
+    }
+
+    /// Create a runtime builder for creating a runtime with a specific configuration.
+    pub fn builder() -> RuntimeBuilder {
+        RuntimeBuilder::default()
     }
 
     /// Get weak ref to runtime
@@ -74,6 +84,20 @@ impl Runtime {
         }
     }
 
+    /// Set a closure which is called when a promise is rejected without a handler or a handler is
+    /// added to a promise which was rejected before.
+    ///
+    /// The closure receives the promise, the rejection reason and whether the rejection is now
+    /// handled.
+    #[inline]
+    pub fn set_host_promise_rejection_tracker(&self, tracker: Option<RejectionTracker>) {
+        unsafe {
+            self.inner
+                .lock()
+                .set_host_promise_rejection_tracker(tracker);
+        }
+    }
+
     /// Set the module loader
     #[cfg(feature = "loader")]
     #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "loader")))]
@@ -110,13 +134,33 @@ impl Runtime {
 
     /// Set a limit on the max size of stack the runtime will use.
     ///
-    /// The default values is 256x1024 bytes.
+    /// The default value is 1024x1024 bytes. The stack is measured from the point where the
+    /// runtime is entered, exceeding the limit throws a `RangeError` which can be caught from
+    /// JavaScript. The limit should be comfortably smaller than the stack of the thread the runtime
+    /// is used on, otherwise deep recursion might overflow the thread stack before the limit is
+    /// reached.
+    ///
+    /// QuickJS has no per-context stack limit, the limit applies to all the contexts of the
+    /// runtime including contexts which are created later.
     pub fn set_max_stack_size(&self, limit: usize) {
         unsafe {
             self.inner.lock().set_max_stack_size(limit);
         }
     }
 
+    /// Re-base the stack the runtime measures its stack size against to the current stack
+    /// position.
+    ///
+    /// QuickJS measures the [stack size](Runtime::set_max_stack_size) from a recorded stack top.
+    /// When a runtime is moved to another thread, the recorded top points into the stack of the
+    /// original thread and stack checks will fail spuriously. Entering the runtime through
+    /// [`Context::with`] or [`Runtime::execute_pending_job`] already updates the stack top, so
+    /// this is only needed when the runtime is used in another way on a new thread, like when
+    /// calling QuickJS functions directly.
+    pub fn update_stack_top(&self) {
+        self.inner.lock().update_stack_top();
+    }
+
     /// Set a memory threshold for garbage collection.
     pub fn set_gc_threshold(&self, threshold: usize) {
         unsafe {
@@ -200,4 +244,98 @@ mod test {
         rt.set_gc_threshold(0xFF);
         rt.run_gc();
     }
+
+    #[test]
+    fn stack_overflow_is_catchable() {
+        let rt = Runtime::new().unwrap();
+        let ctx = crate::Context::full(&rt).unwrap();
+        ctx.with(|ctx| {
+            let res: bool = ctx
+                .eval(
+                    r#"
+                    function recurse(){ return recurse() + 1 }
+                    try { recurse(); false } catch(e) { e instanceof RangeError }
+                "#,
+                )
+                .unwrap();
+            assert!(res);
+        });
+        // Entering from a different thread with its own stack should also be caught.
+        #[cfg(feature = "parallel")]
+        std::thread::spawn(move || {
+            ctx.with(|ctx| {
+                let res: bool = ctx
+                    .eval("try { recurse(); false } catch(e) { e instanceof RangeError }")
+                    .unwrap();
+                assert!(res);
+            })
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[test]
+    fn max_stack_size_applies_to_all_contexts() {
+        let rt = Runtime::new().unwrap();
+        let before = crate::Context::full(&rt).unwrap();
+        rt.set_max_stack_size(16 * 1024);
+        let after = crate::Context::full(&rt).unwrap();
+        let source = r#"
+            function depth(n){ return n == 0 ? 0 : depth(n - 1) + 1 }
+            try { depth(200) } catch(e) { e instanceof RangeError ? "overflow" : e }
+        "#;
+        for ctx in [&before, &after] {
+            ctx.with(|ctx| {
+                let res: std::string::String = ctx.eval(source).unwrap();
+                assert_eq!(res, "overflow");
+            });
+        }
+
+        rt.set_max_stack_size(1024 * 1024);
+        for ctx in [&before, &after] {
+            ctx.with(|ctx| {
+                let res: i32 = ctx.eval(source).unwrap();
+                assert_eq!(res, 200);
+            });
+        }
+    }
+
+    #[test]
+    fn update_stack_top_on_new_thread() {
+        use crate::qjs;
+
+        struct SendRuntime(Runtime, crate::Context);
+        // The runtime and all handles to it are moved to the new thread together.
+        unsafe impl Send for SendRuntime {}
+
+        let rt = Runtime::new().unwrap();
+        rt.set_max_stack_size(1024 * 1024);
+        let ctx = crate::Context::full(&rt).unwrap();
+        let moved = SendRuntime(rt, ctx);
+        std::thread::spawn(move || {
+            // Bind the wrapper as a whole, destructuring it directly would only capture its fields.
+            let moved = moved;
+            let SendRuntime(rt, ctx) = moved;
+            // Call into QuickJS directly, which doesn't update the stack top like
+            // `Context::with` does.
+            rt.update_stack_top();
+            let _lock = rt.inner.lock();
+            const SOURCE: &[u8] =
+                b"function depth(n){ return n == 0 ? 0 : depth(n - 1) + 1 }; depth(200)\0";
+            unsafe {
+                let ctx = ctx.as_raw().as_ptr();
+                let res = qjs::JS_Eval(
+                    ctx,
+                    SOURCE.as_ptr().cast(),
+                    (SOURCE.len() - 1) as _,
+                    b"test\0".as_ptr().cast(),
+                    qjs::JS_EVAL_TYPE_GLOBAL as _,
+                );
+                assert!(!qjs::JS_IsException(res), "spurious stack overflow");
+                assert_eq!(qjs::JS_VALUE_GET_INT(res), 200);
+            }
+        })
+        .join()
+        .unwrap();
+    }
 }
@@ -0,0 +1,144 @@
+#[cfg(feature = "allocator")]
+use crate::allocator::Allocator;
+#[cfg(feature = "futures")]
+use crate::runtime::AsyncRuntime;
+use crate::{Result, Runtime};
+
+use super::{opaque::Opaque, raw::RawRuntime};
+
+#[cfg(feature = "allocator")]
+type CreateFn = Box<dyn FnOnce(Opaque<'static>) -> Result<RawRuntime>>;
+
+/// Used for building a [`Runtime`] with a specific configuration.
+///
+/// ```
+/// # use rquickjs::Runtime;
+/// let rt = Runtime::builder()
+///     .max_stack_size(512 * 1024)
+///     .gc_threshold(4 * 1024 * 1024)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Default)]
+pub struct RuntimeBuilder {
+    memory_limit: Option<usize>,
+    max_stack_size: Option<usize>,
+    gc_threshold: Option<usize>,
+    #[cfg(feature = "allocator")]
+    allocator: Option<CreateFn>,
+}
+
+impl RuntimeBuilder {
+    /// Set a limit on the max amount of memory the runtime will use.
+    ///
+    /// See [`Runtime::set_memory_limit`].
+    #[must_use]
+    pub fn memory_limit(mut self, limit: usize) -> Self {
+        self.memory_limit = Some(limit);
+        self
+    }
+
+    /// Set a limit on the max size of stack the runtime will use.
+    ///
+    /// See [`Runtime::set_max_stack_size`].
+    #[must_use]
+    pub fn max_stack_size(mut self, limit: usize) -> Self {
+        self.max_stack_size = Some(limit);
+        self
+    }
+
+    /// Set a memory threshold for garbage collection.
+    ///
+    /// See [`Runtime::set_gc_threshold`].
+    #[must_use]
+    pub fn gc_threshold(mut self, threshold: usize) -> Self {
+        self.gc_threshold = Some(threshold);
+        self
+    }
+
+    /// Use the specified allocator for the runtime.
+    ///
+    /// See [`Runtime::new_with_alloc`].
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "allocator")))]
+    #[cfg(feature = "allocator")]
+    #[must_use]
+    pub fn allocator<A>(mut self, allocator: A) -> Self
+    where
+        A: Allocator + 'static,
+    {
+        self.allocator = Some(Box::new(move |opaque| unsafe {
+            RawRuntime::new_with_allocator(opaque, allocator)
+        }));
+        self
+    }
+
+    /// Build the runtime.
+    pub fn build(self) -> Result<Runtime> {
+        let rt = self.create(Opaque::new())?;
+        Ok(Runtime::from_raw(rt))
+    }
+
+    /// Build an async runtime.
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "futures")))]
+    #[cfg(feature = "futures")]
+    pub fn build_async(self) -> Result<AsyncRuntime> {
+        let rt = self.create(Opaque::with_spawner())?;
+        Ok(AsyncRuntime::from_raw(rt))
+    }
+
+    fn create(self, opaque: Opaque<'static>) -> Result<RawRuntime> {
+        #[cfg(feature = "allocator")]
+        let mut rt = match self.allocator {
+            Some(create) => create(opaque)?,
+            None => unsafe { RawRuntime::new(opaque)? },
+        };
+        #[cfg(not(feature = "allocator"))]
+        let mut rt = unsafe { RawRuntime::new(opaque)? };
+
+        unsafe {
+            if let Some(limit) = self.memory_limit {
+                rt.set_memory_limit(limit);
+            }
+            if let Some(limit) = self.max_stack_size {
+                rt.set_max_stack_size(limit);
+            }
+            if let Some(threshold) = self.gc_threshold {
+                rt.set_gc_threshold(threshold);
+            }
+        }
+        Ok(rt)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Runtime;
+
+    #[test]
+    fn memory_limit() {
+        let rt = Runtime::builder()
+            .memory_limit(1024 * 1024)
+            .build()
+            .unwrap();
+        assert_eq!(rt.memory_usage().malloc_limit, 1024 * 1024);
+    }
+
+    // The limit is not enforced by custom allocators.
+    #[cfg(not(feature = "rust-alloc"))]
+    #[test]
+    fn memory_limit_exceeded() {
+        use crate::{CatchResultExt, Context};
+
+        let rt = Runtime::builder()
+            .memory_limit(1024 * 1024)
+            .build()
+            .unwrap();
+        let ctx = Context::full(&rt).unwrap();
+        ctx.with(|ctx| {
+            ctx.eval::<(), _>("new Array(1024 * 1024).fill(0)")
+                .catch(&ctx)
+                .unwrap_err();
+            ctx.eval::<(), _>("[1, 2, 3].fill(0)").catch(&ctx).unwrap();
+        });
+    }
+}
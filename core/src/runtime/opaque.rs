@@ -1,17 +1,20 @@
 use crate::{
     class::{self, ffi::VTable, JsClass},
-    qjs, Ctx, Error, JsLifetime, Object,
+    qjs,
+    value::object::OpaqueMap,
+    Ctx, Error, JsLifetime, Object, Value,
 };
 
 use super::{
     userdata::{UserDataGuard, UserDataMap},
-    InterruptHandler, UserDataError,
+    InterruptHandler, RejectionTracker, UserDataError,
 };
 use std::{
     any::{Any, TypeId},
     cell::{Cell, UnsafeCell},
     collections::{hash_map::Entry, HashMap},
     marker::PhantomData,
+    panic::{self, AssertUnwindSafe},
     ptr,
 };
 
@@ -24,6 +27,103 @@ use std::{
     task::{Context, Waker},
 };
 
+/// The class ids of builtin classes which QuickJS doesn't expose a check for.
+///
+/// The ids of builtin classes change between versions of QuickJS and are not part of its API, so
+/// they are read from objects created in a context which only has the required intrinsics.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BuiltinClassIds {
+    pub map: qjs::JSClassID,
+    pub set: qjs::JSClassID,
+    pub date: qjs::JSClassID,
+    pub async_function: qjs::JSClassID,
+    pub generator_function: qjs::JSClassID,
+    pub async_generator_function: qjs::JSClassID,
+    /// The class id of every typed array type, [`qjs::JS_INVALID_CLASS_ID`] for types which the
+    /// QuickJS version doesn't support.
+    pub typed_arrays: [qjs::JSClassID; TYPED_ARRAYS.len()],
+}
+
+const TYPED_ARRAYS: [&str; 12] = [
+    "Uint8ClampedArray",
+    "Int8Array",
+    "Uint8Array",
+    "Int16Array",
+    "Uint16Array",
+    "Int32Array",
+    "Uint32Array",
+    "BigInt64Array",
+    "BigUint64Array",
+    "Float16Array",
+    "Float32Array",
+    "Float64Array",
+];
+
+impl BuiltinClassIds {
+    unsafe fn load(rt: *mut qjs::JSRuntime) -> Option<Self> {
+        const FILE_NAME: &[u8] = b"<builtin>\0";
+
+        let mut source = "[new Map, new Set, new Date, async function() {}, function*() {}, \
+             async function*() {}"
+            .to_string();
+        for name in TYPED_ARRAYS {
+            source.push_str(&format!(", globalThis.{name} && new {name}"));
+        }
+        source.push_str("]\0");
+
+        let ctx = qjs::JS_NewContextRaw(rt);
+        if ctx.is_null() {
+            return None;
+        }
+        qjs::JS_AddIntrinsicBaseObjects(ctx);
+        qjs::JS_AddIntrinsicEval(ctx);
+        qjs::JS_AddIntrinsicDate(ctx);
+        qjs::JS_AddIntrinsicMapSet(ctx);
+        qjs::JS_AddIntrinsicTypedArrays(ctx);
+        qjs::JS_AddIntrinsicPromise(ctx);
+
+        let array = qjs::JS_Eval(
+            ctx,
+            source.as_ptr().cast(),
+            (source.len() - 1) as _,
+            FILE_NAME.as_ptr().cast(),
+            qjs::JS_EVAL_TYPE_GLOBAL as _,
+        );
+        let res = if qjs::JS_IsException(array) {
+            qjs::JS_FreeValue(ctx, qjs::JS_GetException(ctx));
+            None
+        } else {
+            // Values which are not objects, like `undefined` for missing types, have an invalid
+            // class id.
+            let mut ids = [qjs::JS_INVALID_CLASS_ID; 6 + TYPED_ARRAYS.len()];
+            for (idx, id) in ids.iter_mut().enumerate() {
+                let value = qjs::JS_GetPropertyUint32(ctx, array, idx as u32);
+                *id = qjs::JS_GetClassID(value);
+                qjs::JS_FreeValue(ctx, value);
+            }
+            qjs::JS_FreeValue(ctx, array);
+            let mut typed_arrays = [qjs::JS_INVALID_CLASS_ID; TYPED_ARRAYS.len()];
+            typed_arrays.copy_from_slice(&ids[6..]);
+            Some(BuiltinClassIds {
+                map: ids[0],
+                set: ids[1],
+                date: ids[2],
+                async_function: ids[3],
+                generator_function: ids[4],
+                async_generator_function: ids[5],
+                typed_arrays,
+            })
+        };
+        qjs::JS_FreeContext(ctx);
+        res
+    }
+
+    /// Returns whether the class id is the id of a typed array.
+    pub fn is_typed_array(&self, id: qjs::JSClassID) -> bool {
+        id != qjs::JS_INVALID_CLASS_ID && self.typed_arrays.contains(&id)
+    }
+}
+
 /// Opaque book keeping data for Rust.
 pub(crate) struct Opaque<'js> {
     /// Used to carry a panic if a callback triggered one.
@@ -39,6 +139,19 @@ pub(crate) struct Opaque<'js> {
 
     prototypes: UnsafeCell<HashMap<TypeId, Option<Object<'js>>>>,
 
+    builtin_class_ids: Cell<Option<BuiltinClassIds>>,
+
+    /// The weak map holding the data attached with [`Object::set_opaque`].
+    opaque_map: UnsafeCell<Option<OpaqueMap<'js>>>,
+
+    /// The user provided promise rejection tracker, if any.
+    host_rejection_tracker: UnsafeCell<Option<RejectionTracker>>,
+    /// The number of active rejection tracking scopes, see [`Opaque::track_rejections`].
+    rejection_tracking: Cell<usize>,
+    /// Promises which were rejected without a handler together with their rejection reason and
+    /// the depth of the tracking scope they were rejected in.
+    unhandled_rejections: UnsafeCell<Vec<(usize, Value<'js>, Value<'js>)>>,
+
     userdata: UserDataMap,
 
     #[cfg(feature = "futures")]
@@ -59,6 +172,14 @@ impl<'js> Opaque<'js> {
 
             prototypes: UnsafeCell::new(HashMap::new()),
 
+            builtin_class_ids: Cell::new(None),
+
+            opaque_map: UnsafeCell::new(None),
+
+            host_rejection_tracker: UnsafeCell::new(None),
+            rejection_tracking: Cell::new(0),
+            unhandled_rejections: UnsafeCell::new(Vec::new()),
+
             userdata: UserDataMap::default(),
 
             _marker: PhantomData,
@@ -188,6 +309,17 @@ impl<'js> Opaque<'js> {
         self.callable_class_id
     }
 
+    /// Returns the class ids of builtin classes, `None` if they couldn't be loaded because the
+    /// runtime is out of memory.
+    pub fn get_builtin_class_ids(&self, rt: *mut qjs::JSRuntime) -> Option<BuiltinClassIds> {
+        if let Some(ids) = self.builtin_class_ids.get() {
+            return Some(ids);
+        }
+        let ids = unsafe { BuiltinClassIds::load(rt)? };
+        self.builtin_class_ids.set(Some(ids));
+        Some(ids)
+    }
+
     pub fn get_or_insert_prototype<C: JsClass<'js>>(
         &self,
         ctx: &Ctx<'js>,
@@ -205,6 +337,111 @@ impl<'js> Opaque<'js> {
         }
     }
 
+    pub fn get_or_insert_opaque_map(&self, ctx: &Ctx<'js>) -> Result<OpaqueMap<'js>, Error> {
+        unsafe {
+            if let Some(map) = (*self.opaque_map.get()).as_ref() {
+                return Ok(map.clone());
+            }
+            let map = OpaqueMap::new(ctx)?;
+            *self.opaque_map.get() = Some(map.clone());
+            Ok(map)
+        }
+    }
+
+    /// Sets the user provided promise rejection tracker.
+    pub unsafe fn set_rejection_tracker(
+        &self,
+        rt: *mut qjs::JSRuntime,
+        tracker: Option<RejectionTracker>,
+    ) {
+        *self.host_rejection_tracker.get() = tracker;
+        self.install_rejection_tracker(rt);
+    }
+
+    /// Starts a scope recording promises which are rejected without a handler.
+    ///
+    /// Scopes nest, every call must be followed by a call to [`Opaque::untrack_rejections`]. The
+    /// user provided tracker keeps being called while a scope is active.
+    pub unsafe fn track_rejections(&self, rt: *mut qjs::JSRuntime) {
+        let depth = self.rejection_tracking.get();
+        self.rejection_tracking.set(depth + 1);
+        if depth == 0 {
+            self.install_rejection_tracker(rt);
+        }
+    }
+
+    /// Ends the innermost scope started with [`Opaque::track_rejections`].
+    ///
+    /// Returns the reason of the first promise rejected within the scope which still has no
+    /// handler, all rejections recorded within the scope are forgotten. Ending the outermost
+    /// scope restores the tracker which was installed before it started.
+    pub unsafe fn untrack_rejections(&self, rt: *mut qjs::JSRuntime) -> Option<Value<'js>> {
+        let depth = self.rejection_tracking.get();
+        self.rejection_tracking.set(depth - 1);
+        if depth == 1 {
+            self.install_rejection_tracker(rt);
+        }
+        let rejections = &mut *self.unhandled_rejections.get();
+        let start = rejections
+            .iter()
+            .position(|x| x.0 >= depth)
+            .unwrap_or(rejections.len());
+        rejections.drain(start..).next().map(|x| x.2)
+    }
+
+    /// Installs the host promise rejection tracker if there is a user provided tracker or an
+    /// active tracking scope, removes it otherwise.
+    unsafe fn install_rejection_tracker(&self, rt: *mut qjs::JSRuntime) {
+        let active =
+            self.rejection_tracking.get() > 0 || (*self.host_rejection_tracker.get()).is_some();
+        qjs::JS_SetHostPromiseRejectionTracker(
+            rt,
+            if active {
+                Some(Self::rejection_tracker)
+            } else {
+                None
+            },
+            self as *const Self as *mut qjs::c_void,
+        );
+    }
+
+    unsafe extern "C" fn rejection_tracker(
+        ctx: *mut qjs::JSContext,
+        promise: qjs::JSValue,
+        reason: qjs::JSValue,
+        is_handled: qjs::c_int,
+        opaque: *mut qjs::c_void,
+    ) {
+        let this = &*(opaque as *const Self);
+        let ctx = Ctx::from_ptr(ctx);
+        if this.rejection_tracking.get() > 0 {
+            let rejections = &mut *this.unhandled_rejections.get();
+            if is_handled == 0 {
+                rejections.push((
+                    this.rejection_tracking.get(),
+                    Value::from_js_value_const(ctx.clone(), promise),
+                    Value::from_js_value_const(ctx.clone(), reason),
+                ));
+            } else {
+                let promise = qjs::JS_VALUE_GET_PTR(promise);
+                rejections.retain(|x| qjs::JS_VALUE_GET_PTR(x.1.as_js_value()) != promise);
+            }
+        }
+
+        if let Some(tracker) = (*this.host_rejection_tracker.get()).as_ref() {
+            let promise = Value::from_js_value_const(ctx.clone(), promise);
+            let reason = Value::from_js_value_const(ctx.clone(), reason);
+            let res = panic::catch_unwind(AssertUnwindSafe(|| {
+                tracker(ctx, promise, reason, is_handled != 0)
+            }));
+            if let Err(panic) = res {
+                // The panic is resumed by the Rust code running the interpreter once it returns
+                // with an exception.
+                this.set_panic(panic);
+            }
+        }
+    }
+
     /// Cleans up all the internal state.
     ///
     /// Called before dropping the runtime to ensure that we drop everything before freeing the
@@ -213,6 +450,9 @@ impl<'js> Opaque<'js> {
         self.interrupt_handler.get_mut().take();
         self.panic.take();
         self.prototypes.get_mut().clear();
+        self.unhandled_rejections.get_mut().clear();
+        self.host_rejection_tracker.get_mut().take();
+        self.opaque_map.get_mut().take();
         #[cfg(feature = "futures")]
         self.spawner.take();
         self.userdata.clear()
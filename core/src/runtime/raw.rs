@@ -16,7 +16,7 @@ use crate::{
     Error, Result,
 };
 
-use super::{opaque::Opaque, InterruptHandler};
+use super::{opaque::Opaque, InterruptHandler, RejectionTracker};
 
 const DUMP_BYTECODE_FINAL: u64 = 0x01;
 const DUMP_BYTECODE_PASS2: u64 = 0x02;
@@ -198,8 +198,11 @@ impl RawRuntime {
         })
     }
 
+    /// Re-bases the stack limit on the current stack pointer.
+    ///
+    /// Called whenever the runtime is entered as the runtime might be used from a different thread
+    /// or from a different stack depth than where it was created.
     pub fn update_stack_top(&self) {
-        #[cfg(feature = "parallel")]
         unsafe {
             qjs::JS_UpdateStackTop(self.rt.as_ptr());
         }
@@ -257,7 +260,11 @@ impl RawRuntime {
 
     /// Set a limit on the max size of stack the runtime will use.
     ///
-    /// The default values is 256x1024 bytes.
+    /// The default value is 1024x1024 bytes. The stack is measured from the point where the
+    /// runtime is entered, exceeding the limit throws a `RangeError` which can be caught from
+    /// JavaScript. The limit should be comfortably smaller than the stack of the thread the runtime
+    /// is used on, otherwise deep recursion might overflow the thread stack before the limit is
+    /// reached.
     pub unsafe fn set_max_stack_size(&mut self, limit: usize) {
         let limit: size_t = limit.try_into().unwrap_or(size_t::MAX);
         qjs::JS_SetMaxStackSize(self.rt.as_ptr(), limit);
@@ -325,6 +332,13 @@ impl RawRuntime {
         self.get_opaque().set_interrupt_handler(handler);
     }
 
+    /// Set a closure which is called when a promise is rejected without a handler or a handler is
+    /// added to a promise which was rejected before.
+    pub unsafe fn set_host_promise_rejection_tracker(&mut self, tracker: Option<RejectionTracker>) {
+        self.get_opaque()
+            .set_rejection_tracker(self.rt.as_ptr(), tracker);
+    }
+
     fn add_dump_flags(rt: *mut rquickjs_sys::JSRuntime) {
         unsafe {
             qjs::JS_SetDumpFlags(rt, build_dump_flags());
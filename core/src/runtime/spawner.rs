@@ -5,7 +5,9 @@ use super::{
 use crate::AsyncRuntime;
 use std::{
     future::Future,
+    mem,
     pin::Pin,
+    sync::{Mutex, TryLockError},
     task::{ready, Context, Poll, Waker},
 };
 
@@ -46,6 +48,84 @@ impl Spawner {
     }
 }
 
+#[cfg(not(feature = "parallel"))]
+pub(crate) type BoxTask = Pin<Box<dyn Future<Output = ()>>>;
+#[cfg(feature = "parallel")]
+pub(crate) type BoxTask = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// A structure to hold futures spawned from outside the runtime with [`AsyncRuntime::spawn`].
+///
+/// Unlike the futures in [`Spawner`] these futures are polled without holding the runtime lock,
+/// so they can lock the runtime themselves.
+pub(crate) struct TaskSet {
+    schedular: Mutex<Schedular>,
+    incoming: Mutex<Incoming>,
+}
+
+#[derive(Default)]
+struct Incoming {
+    tasks: Vec<BoxTask>,
+    waker: Option<Waker>,
+}
+
+// The schedular is only accessed behind the mutex and all tasks are send.
+#[cfg(feature = "parallel")]
+unsafe impl Send for TaskSet {}
+#[cfg(feature = "parallel")]
+unsafe impl Sync for TaskSet {}
+
+impl TaskSet {
+    pub fn new() -> Self {
+        TaskSet {
+            schedular: Mutex::new(Schedular::new()),
+            incoming: Mutex::new(Incoming::default()),
+        }
+    }
+
+    pub fn push(&self, task: BoxTask) {
+        let mut incoming = self.incoming.lock().unwrap();
+        incoming.tasks.push(task);
+        if let Some(waker) = incoming.waker.take() {
+            waker.wake();
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        if !self.incoming.lock().unwrap().tasks.is_empty() {
+            return false;
+        }
+        match self.schedular.try_lock() {
+            Ok(x) => x.is_empty(),
+            // Currently being polled so there are tasks left.
+            Err(_) => false,
+        }
+    }
+
+    pub fn poll(&self, cx: &mut Context) -> SchedularPoll {
+        let schedular = match self.schedular.try_lock() {
+            Ok(x) => x,
+            Err(TryLockError::Poisoned(x)) => x.into_inner(),
+            Err(TryLockError::WouldBlock) => {
+                // Polled from somewhere else at the same time, try again later.
+                cx.waker().wake_by_ref();
+                return SchedularPoll::ShouldYield;
+            }
+        };
+
+        let tasks = {
+            let mut incoming = self.incoming.lock().unwrap();
+            incoming.waker = Some(cx.waker().clone());
+            mem::take(&mut incoming.tasks)
+        };
+        for task in tasks {
+            // SAFETY: Tasks are 'static.
+            unsafe { schedular.push(task) };
+        }
+
+        unsafe { schedular.poll(cx) }
+    }
+}
+
 enum DriveFutureState {
     Initial,
     Lock {
@@ -80,6 +160,13 @@ impl Future for DriveFuture {
     fn poll(self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
         // Safety: We manually ensure that pinned values remained properly pinned.
         let this = unsafe { self.get_unchecked_mut() };
+
+        // Futures spawned with `AsyncRuntime::spawn` are polled outside of the lock, on every
+        // poll, as they might have woken this future while waiting for the lock.
+        if let Some(tasks) = this.rt.tasks.upgrade() {
+            tasks.poll(cx);
+        }
+
         loop {
             let mut lock = match this.state {
                 DriveFutureState::Initial => {
@@ -4,11 +4,13 @@ use std::{
     cell::{Cell, UnsafeCell},
     collections::HashMap,
     hash::{BuildHasherDefault, Hasher},
-    mem::ManuallyDrop,
+    mem::{self, ManuallyDrop},
     ops::Deref,
+    ptr,
+    sync::Arc,
 };
 
-use crate::JsLifetime;
+use crate::{qjs, JsLifetime};
 
 unsafe fn to_static<'js, T: JsLifetime<'js>>(this: T) -> T::Changed<'static>
 where
@@ -166,6 +168,85 @@ impl UserDataMap {
     }
 }
 
+/// The userdata of a single context, see [`Ctx::store_context_userdata`](crate::Ctx::store_context_userdata).
+///
+/// The map is shared by all the handles to a context and by the context itself through its opaque
+/// pointer, so it can be found from a [`Ctx`](crate::Ctx). The data is dropped when the last handle
+/// is dropped.
+pub(crate) struct ContextUserData(Arc<UserDataMap>);
+
+impl ContextUserData {
+    /// Returns the userdata of the context, attaching new userdata if it doesn't have any yet.
+    ///
+    /// # Safety
+    /// The runtime of the context must be locked or the context must not be used by another
+    /// thread.
+    // The map is only accessed while the runtime is locked.
+    #[allow(clippy::arc_with_non_send_sync)]
+    pub unsafe fn attach(ctx: *mut qjs::JSContext) -> Self {
+        let ptr = qjs::JS_GetContextOpaque(ctx) as *const UserDataMap;
+        if ptr.is_null() {
+            let map = Arc::new(UserDataMap::default());
+            qjs::JS_SetContextOpaque(ctx, Arc::into_raw(map.clone()) as *mut _);
+            ContextUserData(map)
+        } else {
+            Arc::increment_strong_count(ptr);
+            ContextUserData(Arc::from_raw(ptr))
+        }
+    }
+
+    /// Returns the userdata attached to the context, if any.
+    ///
+    /// # Safety
+    /// The runtime of the context must be locked.
+    pub unsafe fn get<'a>(ctx: *mut qjs::JSContext) -> Option<&'a UserDataMap> {
+        (qjs::JS_GetContextOpaque(ctx) as *const UserDataMap).as_ref()
+    }
+
+    /// Detaches the userdata from the context and drops the data if this is the last handle to
+    /// the context.
+    ///
+    /// # Safety
+    /// The runtime of the context must be locked and the context must still be alive.
+    pub unsafe fn detach(&self, ctx: *mut qjs::JSContext) {
+        // One reference is owned by the context.
+        if Arc::strong_count(&self.0) == 2 {
+            Self::release(ctx, 1)
+        }
+    }
+
+    /// Detaches the userdata from the context and drops the data if only the given number of
+    /// handles to the context are left.
+    ///
+    /// Userdata which is currently borrowed stays attached, it is leaked if the context is freed.
+    ///
+    /// # Safety
+    /// The runtime of the context must be locked and the context must still be alive.
+    pub unsafe fn release(ctx: *mut qjs::JSContext, handles: usize) {
+        let ptr = qjs::JS_GetContextOpaque(ctx) as *const UserDataMap;
+        if ptr.is_null() {
+            return;
+        }
+        let map = Arc::from_raw(ptr);
+        // One reference is owned by the context.
+        if Arc::strong_count(&map) != handles + 1 || map.count.get() > 0 {
+            mem::forget(map);
+            return;
+        }
+        qjs::JS_SetContextOpaque(ctx, ptr::null_mut());
+        // Take the data out first, dropping it might access the userdata of the context again.
+        let data = mem::take(&mut *map.map.get());
+        drop(map);
+        drop(data);
+    }
+}
+
+impl Clone for ContextUserData {
+    fn clone(&self) -> Self {
+        ContextUserData(self.0.clone())
+    }
+}
+
 /// Guard for user data to avoid inserting new userdata while exisiting userdata is being
 /// referenced.
 pub struct UserDataGuard<'a, U> {
@@ -1,4 +1,4 @@
-use crate::{qjs, Ctx, Error, Result};
+use crate::{qjs, runtime::opaque::BuiltinClassIds, Ctx, Error, Result};
 use std::{fmt, hash::Hash, mem, ops::Deref, result::Result as StdResult, str};
 
 pub mod array;
@@ -7,6 +7,9 @@ mod bigint;
 pub mod convert;
 pub(crate) mod exception;
 pub mod function;
+mod inspect;
+mod iterator;
+pub mod map;
 pub mod module;
 pub mod object;
 pub mod promise;
@@ -17,14 +20,21 @@ pub use array::Array;
 pub use atom::Atom;
 pub use bigint::BigInt;
 pub use convert::{Coerced, FromAtom, FromIteratorJs, FromJs, IntoAtom, IntoJs, IteratorJs};
-pub use exception::Exception;
+pub use exception::{Exception, StackFrame};
 pub use function::{Constructor, Function};
+pub use inspect::InspectOptions;
+pub use iterator::JsIterator;
+pub use map::Map;
 pub use module::Module;
 pub use object::{Filter, Object};
 pub use promise::Promise;
 pub use string::{CString, String};
 pub use symbol::Symbol;
 
+#[cfg(feature = "futures")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "futures")))]
+pub mod stream;
+
 #[cfg(feature = "array-buffer")]
 pub mod array_buffer;
 #[cfg(feature = "array-buffer")]
@@ -33,7 +43,7 @@ pub mod typed_array;
 #[cfg(feature = "array-buffer")]
 pub use array_buffer::ArrayBuffer;
 #[cfg(feature = "array-buffer")]
-pub use typed_array::TypedArray;
+pub use typed_array::{ClampedU8, TypedArray};
 
 /// Any JavaScript value
 pub struct Value<'js> {
@@ -361,6 +371,15 @@ impl<'js> Value<'js> {
         0 != unsafe { qjs::JS_IsConstructor(self.ctx.as_ptr(), self.value) }
     }
 
+    /// Check if the value can be called, like `typeof value === "function"` in JavaScript.
+    ///
+    /// This includes all functions, constructors and classes, as well as callable proxies. Note
+    /// that calling a class without `new` still throws a `TypeError`.
+    #[inline]
+    pub fn is_callable(&self) -> bool {
+        self.is_function()
+    }
+
     /// Check if the value is a promise.
     #[inline]
     pub fn is_promise(&self) -> bool {
@@ -374,11 +393,61 @@ impl<'js> Value<'js> {
     }
 
     /// Check if the value is an error
+    ///
+    /// Returns true for any object created by an `Error` constructor, including the builtin
+    /// errors like `TypeError` and instances of classes which extend `Error`. Objects which merely
+    /// have `Error.prototype` in their prototype chain, like `Object.create(Error.prototype)`, are
+    /// not errors.
     #[inline]
     pub fn is_error(&self) -> bool {
         0 != unsafe { qjs::JS_IsError(self.ctx.as_ptr(), self.value) }
     }
 
+    /// Check if the value is a `Map`.
+    ///
+    /// This checks the class of the object, so objects which merely have `Map.prototype` in their
+    /// prototype chain are not maps, while maps created in other contexts are.
+    pub fn is_map(&self) -> bool {
+        matches!(self.builtin_class_ids(), Some((ids, id)) if id == ids.map)
+    }
+
+    /// Check if the value is a `Set`.
+    ///
+    /// Like [`Value::is_map`] this checks the class of the object, not its prototype chain.
+    pub fn is_set(&self) -> bool {
+        matches!(self.builtin_class_ids(), Some((ids, id)) if id == ids.set)
+    }
+
+    /// Returns whether the value is a `Date`, checking the class of the object.
+    pub(crate) fn is_date(&self) -> bool {
+        matches!(self.builtin_class_ids(), Some((ids, id)) if id == ids.date)
+    }
+
+    /// Check if the value is a typed array of any element type, like `Uint8Array` or
+    /// `Float64Array`.
+    ///
+    /// `DataView`s and `ArrayBuffer`s are not typed arrays. Use
+    /// `Object::is_typed_array` to check for a specific element type.
+    pub fn is_typed_array(&self) -> bool {
+        matches!(
+            self.builtin_class_ids(),
+            Some((ids, id)) if ids.is_typed_array(id)
+        )
+    }
+
+    /// Returns the builtin class ids together with the class id of this value if it is an object.
+    pub(crate) fn builtin_class_ids(&self) -> Option<(BuiltinClassIds, qjs::JSClassID)> {
+        if !self.is_object() {
+            return None;
+        }
+        let ids = unsafe {
+            self.ctx
+                .get_opaque()
+                .get_builtin_class_ids(qjs::JS_GetRuntime(self.ctx.as_ptr()))?
+        };
+        Some((ids, unsafe { qjs::JS_GetClassID(self.value) }))
+    }
+
     /// Reference as value
     #[inline]
     pub fn as_value(&self) -> &Self {
@@ -786,4 +855,115 @@ mod test {
 
         assert!(!Type::Bool.interpretable_as(Type::Int));
     }
+
+    #[test]
+    fn is_callable() {
+        test_with(|ctx| {
+            let values: Array = ctx
+                .eval("[() => 1, class A {}, function () {}, new Proxy(() => 1, {}), Math.max]")
+                .unwrap();
+            for value in values.iter::<Value>() {
+                assert!(value.unwrap().is_callable());
+            }
+            let values: Array = ctx
+                .eval("[{}, [], 1, 'function', null, new Proxy({}, {})]")
+                .unwrap();
+            for value in values.iter::<Value>() {
+                assert!(!value.unwrap().is_callable());
+            }
+        })
+    }
+
+    #[test]
+    fn is_predicates() {
+        test_with(|ctx| {
+            let check = |source: &str| -> [bool; 5] {
+                let value: Value = ctx.eval(source).unwrap();
+                [
+                    value.is_array(),
+                    value.is_typed_array(),
+                    value.is_map(),
+                    value.is_set(),
+                    value.is_promise(),
+                ]
+            };
+            assert_eq!(check("[1, 2]"), [true, false, false, false, false]);
+            assert_eq!(
+                check("new Uint8Array(2)"),
+                [false, true, false, false, false]
+            );
+            assert_eq!(
+                check("new Float64Array(2)"),
+                [false, true, false, false, false]
+            );
+            assert_eq!(check("new Map()"), [false, false, true, false, false]);
+            assert_eq!(check("new Set()"), [false, false, false, true, false]);
+            assert_eq!(
+                check("Promise.resolve(1)"),
+                [false, false, false, false, true]
+            );
+            assert_eq!(check("({})"), [false; 5]);
+            assert_eq!(check("new DataView(new ArrayBuffer(2))"), [false; 5]);
+            assert_eq!(check("1"), [false; 5]);
+
+            for name in [
+                "Uint8ClampedArray",
+                "Int8Array",
+                "Int16Array",
+                "Uint16Array",
+                "Int32Array",
+                "Uint32Array",
+                "BigInt64Array",
+                "BigUint64Array",
+                "Float32Array",
+            ] {
+                assert_eq!(
+                    check(&format!("new {name}(2)")),
+                    [false, true, false, false, false]
+                );
+            }
+
+            // Only the class of the object matters, not the prototype chain or the globals.
+            assert_eq!(check("Object.create(Map.prototype)"), [false; 5]);
+            assert_eq!(check("Object.create(Uint8Array.prototype)"), [false; 5]);
+            let map: Value = ctx.eval("new Map()").unwrap();
+            let set: Value = ctx.eval("new Set()").unwrap();
+            let array: Value = ctx.eval("new Int32Array(1)").unwrap();
+            ctx.eval::<(), _>("globalThis.Map = 5; delete globalThis.Set; Uint8Array = null;")
+                .unwrap();
+            assert!(map.is_map());
+            assert!(set.is_set());
+            assert!(array.is_typed_array());
+            assert!(!ctx.eval::<Value, _>("({})").unwrap().is_map());
+            assert_eq!(unsafe { qjs::JS_HasException(ctx.as_ptr()) }, 0);
+
+            let object: Object = ctx.eval("new WeakMap()").unwrap();
+            assert!(!object.is_map());
+            assert!(!object.is_array());
+        })
+    }
+
+    #[test]
+    fn is_error() {
+        test_with(|ctx| {
+            let errors: Array = ctx
+                .eval(
+                    r#"
+                    class E extends Error {}
+                    [new Error(), new TypeError(), new E()]
+                "#,
+                )
+                .unwrap();
+            for error in errors.iter::<Object>() {
+                assert!(error.unwrap().is_error());
+            }
+
+            let not_errors: Array = ctx
+                .eval("[{}, Object.create(Error.prototype), 'Error', Error]")
+                .unwrap();
+            for value in not_errors.iter::<Value>() {
+                assert!(!value.unwrap().is_error());
+            }
+        })
+    }
 }
@@ -1,6 +1,8 @@
 //! JavaScript array types.
 
-use crate::{atom::PredefinedAtom, qjs, Ctx, FromJs, IntoJs, Object, Result, Value};
+use crate::{
+    atom::PredefinedAtom, function::Rest, qjs, Ctx, FromJs, IntoJs, Object, Result, Value,
+};
 use std::{iter::FusedIterator, marker::PhantomData};
 
 use super::convert::FromIteratorJs;
@@ -78,6 +80,70 @@ impl<'js> Array<'js> {
         }
     }
 
+    /// Returns a new array with the elements of this array followed by the elements of `other`,
+    /// like `Array.prototype.concat`.
+    pub fn concat(&self, other: &Array<'js>) -> Result<Array<'js>> {
+        self.0.call_method(PredefinedAtom::Concat, (other.clone(),))
+    }
+
+    /// Returns a new array with the elements of nested arrays up to `depth` levels deep moved
+    /// into the array, like `Array.prototype.flat`.
+    pub fn flat(&self, depth: u32) -> Result<Array<'js>> {
+        self.0.call_method("flat", (depth,))
+    }
+
+    /// Removes `delete_count` elements starting at `start` and inserts `items` in their place,
+    /// like `Array.prototype.splice`.
+    ///
+    /// Returns an array of the removed elements.
+    pub fn splice(
+        &self,
+        start: usize,
+        delete_count: usize,
+        items: &[Value<'js>],
+    ) -> Result<Array<'js>> {
+        self.0
+            .call_method("splice", (start, delete_count, Rest(items.to_vec())))
+    }
+
+    /// Returns the index of the first element for which `pred` returns true.
+    ///
+    /// Like `Array.prototype.findIndex` but the predicate runs in Rust, holes are visited as
+    /// `undefined`. Stops at the first error returned by the predicate.
+    pub fn position<F>(&self, mut pred: F) -> Result<Option<usize>>
+    where
+        F: FnMut(&Value<'js>) -> Result<bool>,
+    {
+        let mut idx = 0;
+        while idx < self.len() {
+            let value: Value = self.get(idx)?;
+            if pred(&value)? {
+                return Ok(Some(idx));
+            }
+            idx += 1;
+        }
+        Ok(None)
+    }
+
+    /// Returns the first element for which `pred` returns true, converted into `T`.
+    ///
+    /// Like `Array.prototype.find` but the predicate runs in Rust.
+    pub fn find<T, F>(&self, mut pred: F) -> Result<Option<T>>
+    where
+        T: FromJs<'js>,
+        F: FnMut(&Value<'js>) -> Result<bool>,
+    {
+        let mut idx = 0;
+        while idx < self.len() {
+            let value: Value = self.get(idx)?;
+            if pred(&value)? {
+                return T::from_js(self.ctx(), value).map(Some);
+            }
+            idx += 1;
+        }
+        Ok(None)
+    }
+
     pub fn into_object(self) -> Object<'js> {
         self.0
     }
@@ -201,6 +267,50 @@ mod test {
         });
     }
 
+    #[test]
+    fn find_position() {
+        test_with(|ctx| {
+            let array: Array = ctx.eval("[1, 2, 3, 4]").unwrap();
+            let greater = |x: &Value| Ok(matches!(x.as_int(), Some(x) if x > 2));
+            assert_eq!(array.position(greater).unwrap(), Some(2));
+            assert_eq!(array.find::<i32, _>(greater).unwrap(), Some(3));
+
+            assert_eq!(array.position(|x| Ok(x.is_string())).unwrap(), None);
+            assert_eq!(array.find::<i32, _>(|x| Ok(x.is_string())).unwrap(), None);
+
+            let err = array.position(|_| Err(Error::Unknown)).unwrap_err();
+            assert!(matches!(err, Error::Unknown));
+        });
+    }
+
+    #[test]
+    fn concat_flat_splice() {
+        test_with(|ctx| {
+            let a: Array = ctx.eval("[1, 2]").unwrap();
+            let b: Array = ctx.eval("[3, [4, [5]]]").unwrap();
+
+            let res = a.concat(&b).unwrap();
+            assert_eq!(res.len(), 4);
+            assert_eq!(a.len(), 2);
+
+            let flat = res.flat(1).unwrap();
+            assert_eq!(flat.len(), 5);
+            let flat = res.flat(2).unwrap();
+            let flat: Vec<i32> = flat.iter().collect::<Result<_>>().unwrap();
+            assert_eq!(flat, vec![1, 2, 3, 4, 5]);
+
+            let items = [
+                Value::new_int(ctx.clone(), 8),
+                Value::new_int(ctx.clone(), 9),
+            ];
+            let removed = a.splice(1, 1, &items).unwrap();
+            let removed: Vec<i32> = removed.iter().collect::<Result<_>>().unwrap();
+            assert_eq!(removed, vec![2]);
+            let a: Vec<i32> = a.iter().collect::<Result<_>>().unwrap();
+            assert_eq!(a, vec![1, 8, 9]);
+        })
+    }
+
     #[test]
     fn into_object() {
         test_with(|ctx| {
@@ -20,6 +20,12 @@ pub struct RawArrayBuffer {
 pub enum AsSliceError {
     BufferUsed,
     InvalidAlignment,
+    /// A range was outside of the length of the buffer.
+    OutOfBounds {
+        start: usize,
+        end: usize,
+        len: usize,
+    },
 }
 
 impl fmt::Display for AsSliceError {
@@ -29,6 +35,9 @@ impl fmt::Display for AsSliceError {
             AsSliceError::InvalidAlignment => {
                 write!(f, "Buffer had a different alignment than was requested")
             }
+            AsSliceError::OutOfBounds { start, end, len } => {
+                write!(f, "Range {start}..{end} is out of bounds for length {len}")
+            }
         }
     }
 }
@@ -53,6 +62,10 @@ impl<'js> ArrayBuffer<'js> {
         let size = src.len() * size_of::<T>();
 
         extern "C" fn drop_raw<T>(_rt: *mut qjs::JSRuntime, opaque: *mut c_void, ptr: *mut c_void) {
+            // detaching the buffer already freed the data
+            if ptr.is_null() {
+                return;
+            }
             let ptr = ptr as *mut T;
             let capacity = opaque as usize;
             // reconstruct vector in order to free data
@@ -92,8 +105,10 @@ impl<'js> ArrayBuffer<'js> {
     }
 
     /// Get the length of the array buffer in bytes.
+    ///
+    /// Returns 0 if the array is detached.
     pub fn len(&self) -> usize {
-        Self::get_raw(&self.0).expect("Not an ArrayBuffer").len
+        Self::get_raw(&self.0).map_or(0, |raw| raw.len)
     }
 
     /// Returns whether an array buffer is empty.
@@ -125,6 +140,23 @@ impl<'js> ArrayBuffer<'js> {
         unsafe { qjs::JS_DetachArrayBuffer(self.0.ctx.as_ptr(), self.0.as_js_value()) }
     }
 
+    /// Detach array buffer, returning the bytes it contained.
+    ///
+    /// After detaching the buffer has a length of 0 and any access to it, or to typed arrays
+    /// viewing it, from JavaScript throws a `TypeError`.
+    ///
+    /// Returns `None` if the array was already detached.
+    pub fn take(&mut self) -> Option<Vec<u8>> {
+        let bytes = self.as_bytes()?.to_vec();
+        self.detach();
+        Some(bytes)
+    }
+
+    /// Returns whether the array buffer is detached.
+    pub fn is_detached(&self) -> bool {
+        Self::get_raw(&self.0).is_none()
+    }
+
     /// Reference to value
     #[inline]
     pub fn as_value(&self) -> &Value<'js> {
@@ -174,16 +206,24 @@ impl<'js> ArrayBuffer<'js> {
         let ctx = val.ctx();
         let val = val.as_js_value();
         let mut size = MaybeUninit::<qjs::size_t>::uninit();
+        // QuickJS throws a TypeError for detached buffers and other objects, the `None` already
+        // signals that. Take an exception which was already pending so it isn't replaced.
+        let pending = unsafe { qjs::JS_GetException(ctx.as_ptr()) };
         let ptr = unsafe { qjs::JS_GetArrayBuffer(ctx.as_ptr(), size.as_mut_ptr(), val) };
 
-        if let Some(ptr) = NonNull::new(ptr) {
+        let res = if let Some(ptr) = NonNull::new(ptr) {
             let len = unsafe { size.assume_init() }
                 .try_into()
                 .expect(qjs::SIZE_T_ERROR);
             Some(RawArrayBuffer { len, ptr })
         } else {
+            unsafe { qjs::JS_FreeValue(ctx.as_ptr(), qjs::JS_GetException(ctx.as_ptr())) };
             None
+        };
+        if unsafe { !qjs::JS_IsUninitialized(pending) } {
+            unsafe { qjs::JS_Throw(ctx.as_ptr(), pending) };
         }
+        res
     }
 }
 
@@ -348,4 +388,61 @@ mod test {
             assert_eq!(val.as_bytes().unwrap(), &res)
         });
     }
+
+    #[test]
+    fn take() {
+        test_with(|ctx| {
+            let mut val: ArrayBuffer = ctx
+                .eval(
+                    r#"
+                        globalThis.array = new Uint8Array([1, 2, 3]);
+                        array.buffer
+                    "#,
+                )
+                .unwrap();
+            let view: TypedArray<u8> = ctx.globals().get("array").unwrap();
+
+            assert!(!val.is_detached());
+            assert_eq!(val.take().unwrap(), vec![1, 2, 3]);
+            assert!(val.is_detached());
+            assert_eq!(val.len(), 0);
+            assert!(val.as_bytes().is_none());
+            assert!(val.take().is_none());
+            assert!(view.as_bytes().is_none());
+
+            ctx.globals().set("buffer", val).unwrap();
+            assert_eq!(ctx.eval::<usize, _>("buffer.byteLength").unwrap(), 0);
+            let is_type_error: bool = ctx
+                .eval(
+                    r#"
+                        try {
+                            new Uint8Array(buffer);
+                            false
+                        } catch (e) {
+                            e instanceof TypeError
+                        }
+                    "#,
+                )
+                .unwrap();
+            assert!(is_type_error);
+        });
+    }
+
+    #[test]
+    fn detached_keeps_pending_exception() {
+        test_with(|ctx| {
+            let mut val: ArrayBuffer = ctx.eval("new ArrayBuffer(4)").unwrap();
+            val.detach();
+
+            let err = ctx.eval::<(), _>("throw 'pending'").unwrap_err();
+            assert!(err.is_exception());
+            assert!(val.is_detached());
+            assert!(ArrayBuffer::from_value(ctx.eval("({})").unwrap()).is_none());
+            let thrown: StdString = ctx.catch().get().unwrap();
+            assert_eq!(thrown, "pending");
+
+            assert!(val.is_detached());
+            assert_eq!(ctx.catch().type_of(), Type::Uninitialized);
+        });
+    }
 }
@@ -109,6 +109,22 @@ impl<'js> Atom<'js> {
         unsafe { Atom::from_atom_val(ctx, predefined as qjs::JSAtom) }
     }
 
+    /// Returns whether the atom is the given predefined atom.
+    ///
+    /// This compares the atoms directly and is therefore much cheaper than converting the atom
+    /// to a string first.
+    pub fn is(&self, predefined: PredefinedAtom) -> bool {
+        self.atom == predefined as qjs::JSAtom
+    }
+
+    /// Returns the predefined atom this atom is equal to, if any.
+    ///
+    /// Note that an atom created from a string like `"Symbol.iterator"` is not the same atom as
+    /// the predefined symbol.
+    pub fn as_predefined(&self) -> Option<PredefinedAtom> {
+        PredefinedAtom::from_raw(self.atom)
+    }
+
     /// Convert the atom to a JavaScript string.
     pub fn to_string(&self) -> Result<StdString> {
         unsafe {
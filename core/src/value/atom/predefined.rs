@@ -640,6 +640,216 @@ impl PredefinedAtom {
             PredefinedAtom::SymbolUnscopables => "Symbol.unscopables",
         }
     }
+
+    /// Returns the predefined atom with the given raw atom value, if there is one.
+    pub(crate) const fn from_raw(atom: qjs::JSAtom) -> Option<Self> {
+        let atom = match atom {
+            qjs::JS_ATOM_null => PredefinedAtom::Null,
+            qjs::JS_ATOM_false => PredefinedAtom::False,
+            qjs::JS_ATOM_true => PredefinedAtom::True,
+            qjs::JS_ATOM_if => PredefinedAtom::If,
+            qjs::JS_ATOM_else => PredefinedAtom::Else,
+            qjs::JS_ATOM_return => PredefinedAtom::Return,
+            qjs::JS_ATOM_var => PredefinedAtom::Var,
+            qjs::JS_ATOM_this => PredefinedAtom::This,
+            qjs::JS_ATOM_delete => PredefinedAtom::Delete,
+            qjs::JS_ATOM_void => PredefinedAtom::Void,
+            qjs::JS_ATOM_typeof => PredefinedAtom::Typeof,
+            qjs::JS_ATOM_new => PredefinedAtom::New,
+            qjs::JS_ATOM_in => PredefinedAtom::In,
+            qjs::JS_ATOM_instanceof => PredefinedAtom::Instanceof,
+            qjs::JS_ATOM_do => PredefinedAtom::Do,
+            qjs::JS_ATOM_while => PredefinedAtom::While,
+            qjs::JS_ATOM_for => PredefinedAtom::For,
+            qjs::JS_ATOM_break => PredefinedAtom::Break,
+            qjs::JS_ATOM_continue => PredefinedAtom::Continue,
+            qjs::JS_ATOM_switch => PredefinedAtom::Switch,
+            qjs::JS_ATOM_case => PredefinedAtom::Case,
+            qjs::JS_ATOM_default => PredefinedAtom::Default,
+            qjs::JS_ATOM_throw => PredefinedAtom::Throw,
+            qjs::JS_ATOM_try => PredefinedAtom::Try,
+            qjs::JS_ATOM_catch => PredefinedAtom::Catch,
+            qjs::JS_ATOM_finally => PredefinedAtom::Finally,
+            qjs::JS_ATOM_function => PredefinedAtom::FunctionKw,
+            qjs::JS_ATOM_debugger => PredefinedAtom::Debugger,
+            qjs::JS_ATOM_with => PredefinedAtom::With,
+            qjs::JS_ATOM_class => PredefinedAtom::Class,
+            qjs::JS_ATOM_const => PredefinedAtom::Const,
+            qjs::JS_ATOM_enum => PredefinedAtom::Enum,
+            qjs::JS_ATOM_export => PredefinedAtom::Export,
+            qjs::JS_ATOM_extends => PredefinedAtom::Extends,
+            qjs::JS_ATOM_import => PredefinedAtom::Import,
+            qjs::JS_ATOM_super => PredefinedAtom::Super,
+            qjs::JS_ATOM_implements => PredefinedAtom::Implements,
+            qjs::JS_ATOM_interface => PredefinedAtom::Interface,
+            qjs::JS_ATOM_let => PredefinedAtom::Let,
+            qjs::JS_ATOM_package => PredefinedAtom::Package,
+            qjs::JS_ATOM_private => PredefinedAtom::Private,
+            qjs::JS_ATOM_protected => PredefinedAtom::Protected,
+            qjs::JS_ATOM_public => PredefinedAtom::Public,
+            qjs::JS_ATOM_static => PredefinedAtom::Static,
+            qjs::JS_ATOM_yield => PredefinedAtom::Yield,
+            qjs::JS_ATOM_await => PredefinedAtom::Await,
+            qjs::JS_ATOM_empty_string => PredefinedAtom::Empty,
+            qjs::JS_ATOM_length => PredefinedAtom::Length,
+            qjs::JS_ATOM_message => PredefinedAtom::Message,
+            qjs::JS_ATOM_errors => PredefinedAtom::Errors,
+            qjs::JS_ATOM_stack => PredefinedAtom::Stack,
+            qjs::JS_ATOM_name => PredefinedAtom::Name,
+            qjs::JS_ATOM_toString => PredefinedAtom::ToString,
+            qjs::JS_ATOM_toLocaleString => PredefinedAtom::ToLocaleString,
+            qjs::JS_ATOM_valueOf => PredefinedAtom::ValueOf,
+            qjs::JS_ATOM_eval => PredefinedAtom::Eval,
+            qjs::JS_ATOM_prototype => PredefinedAtom::Prototype,
+            qjs::JS_ATOM_constructor => PredefinedAtom::Constructor,
+            qjs::JS_ATOM_configurable => PredefinedAtom::Configurable,
+            qjs::JS_ATOM_writable => PredefinedAtom::Writable,
+            qjs::JS_ATOM_enumerable => PredefinedAtom::Enumerable,
+            qjs::JS_ATOM_value => PredefinedAtom::Value,
+            qjs::JS_ATOM_get => PredefinedAtom::Getter,
+            qjs::JS_ATOM_set => PredefinedAtom::Setter,
+            qjs::JS_ATOM_of => PredefinedAtom::Of,
+            qjs::JS_ATOM___proto__ => PredefinedAtom::UnderscoreProto,
+            qjs::JS_ATOM_undefined => PredefinedAtom::Undefined,
+            qjs::JS_ATOM_number => PredefinedAtom::NumberLower,
+            qjs::JS_ATOM_boolean => PredefinedAtom::BooleanLower,
+            qjs::JS_ATOM_string => PredefinedAtom::StringLower,
+            qjs::JS_ATOM_object => PredefinedAtom::ObjectLower,
+            qjs::JS_ATOM_symbol => PredefinedAtom::SymbolLower,
+            qjs::JS_ATOM_integer => PredefinedAtom::Integer,
+            qjs::JS_ATOM_unknown => PredefinedAtom::Unknown,
+            qjs::JS_ATOM_arguments => PredefinedAtom::ArgumentsLower,
+            qjs::JS_ATOM_callee => PredefinedAtom::Callee,
+            qjs::JS_ATOM_caller => PredefinedAtom::Caller,
+            qjs::JS_ATOM_lastIndex => PredefinedAtom::LastIndex,
+            qjs::JS_ATOM_target => PredefinedAtom::Target,
+            qjs::JS_ATOM_index => PredefinedAtom::Index,
+            qjs::JS_ATOM_input => PredefinedAtom::Input,
+            qjs::JS_ATOM_defineProperties => PredefinedAtom::DefineProperties,
+            qjs::JS_ATOM_apply => PredefinedAtom::Apply,
+            qjs::JS_ATOM_join => PredefinedAtom::Join,
+            qjs::JS_ATOM_concat => PredefinedAtom::Concat,
+            qjs::JS_ATOM_split => PredefinedAtom::Split,
+            qjs::JS_ATOM_construct => PredefinedAtom::Construct,
+            qjs::JS_ATOM_getPrototypeOf => PredefinedAtom::GetPrototypeOf,
+            qjs::JS_ATOM_setPrototypeOf => PredefinedAtom::SetPrototypeOf,
+            qjs::JS_ATOM_isExtensible => PredefinedAtom::IsExtensible,
+            qjs::JS_ATOM_preventExtensions => PredefinedAtom::PreventExtensions,
+            qjs::JS_ATOM_has => PredefinedAtom::Has,
+            qjs::JS_ATOM_deleteProperty => PredefinedAtom::DeleteProperty,
+            qjs::JS_ATOM_defineProperty => PredefinedAtom::DefineProperty,
+            qjs::JS_ATOM_getOwnPropertyDescriptor => PredefinedAtom::GetOwnPropertyDescriptor,
+            qjs::JS_ATOM_ownKeys => PredefinedAtom::OwnKeys,
+            qjs::JS_ATOM_add => PredefinedAtom::Add,
+            qjs::JS_ATOM_done => PredefinedAtom::Done,
+            qjs::JS_ATOM_next => PredefinedAtom::Next,
+            qjs::JS_ATOM_values => PredefinedAtom::Values,
+            qjs::JS_ATOM_source => PredefinedAtom::Source,
+            qjs::JS_ATOM_flags => PredefinedAtom::Flags,
+            qjs::JS_ATOM_global => PredefinedAtom::Global,
+            qjs::JS_ATOM_unicode => PredefinedAtom::Unicode,
+            qjs::JS_ATOM_raw => PredefinedAtom::Raw,
+            qjs::JS_ATOM_new_target => PredefinedAtom::NewTarget,
+            qjs::JS_ATOM_this_active_func => PredefinedAtom::ThisActiveFunc,
+            qjs::JS_ATOM_home_object => PredefinedAtom::HomeObject,
+            qjs::JS_ATOM_computed_field => PredefinedAtom::ComputedField,
+            qjs::JS_ATOM_static_computed_field => PredefinedAtom::StaticComputedField,
+            qjs::JS_ATOM_class_fields_init => PredefinedAtom::ClassFieldsInit,
+            qjs::JS_ATOM_brand => PredefinedAtom::Brand,
+            qjs::JS_ATOM_hash_constructor => PredefinedAtom::HashConstructor,
+            qjs::JS_ATOM_as => PredefinedAtom::As,
+            qjs::JS_ATOM_from => PredefinedAtom::From,
+            qjs::JS_ATOM_meta => PredefinedAtom::Meta,
+            qjs::JS_ATOM__default_ => PredefinedAtom::StarDefault,
+            qjs::JS_ATOM__star_ => PredefinedAtom::Star,
+            qjs::JS_ATOM_Module => PredefinedAtom::Module,
+            qjs::JS_ATOM_then => PredefinedAtom::Then,
+            qjs::JS_ATOM_resolve => PredefinedAtom::Resolve,
+            qjs::JS_ATOM_reject => PredefinedAtom::Reject,
+            qjs::JS_ATOM_promise => PredefinedAtom::PromiseLower,
+            qjs::JS_ATOM_proxy => PredefinedAtom::ProxyLower,
+            qjs::JS_ATOM_revoke => PredefinedAtom::Revoke,
+            qjs::JS_ATOM_async => PredefinedAtom::Async,
+            qjs::JS_ATOM_exec => PredefinedAtom::Exec,
+            qjs::JS_ATOM_groups => PredefinedAtom::Groups,
+            qjs::JS_ATOM_status => PredefinedAtom::Status,
+            qjs::JS_ATOM_reason => PredefinedAtom::Reason,
+            qjs::JS_ATOM_globalThis => PredefinedAtom::GlobalThis,
+            qjs::JS_ATOM_bigint => PredefinedAtom::Bigint,
+            qjs::JS_ATOM_toJSON => PredefinedAtom::ToJSON,
+            qjs::JS_ATOM_maxByteLength => PredefinedAtom::MaxByteLength,
+            qjs::JS_ATOM_Object => PredefinedAtom::Object,
+            qjs::JS_ATOM_Array => PredefinedAtom::Array,
+            qjs::JS_ATOM_Error => PredefinedAtom::Error,
+            qjs::JS_ATOM_Number => PredefinedAtom::Number,
+            qjs::JS_ATOM_String => PredefinedAtom::String,
+            qjs::JS_ATOM_Boolean => PredefinedAtom::Boolean,
+            qjs::JS_ATOM_Symbol => PredefinedAtom::Symbol,
+            qjs::JS_ATOM_Arguments => PredefinedAtom::Arguments,
+            qjs::JS_ATOM_Math => PredefinedAtom::Math,
+            qjs::JS_ATOM_JSON => PredefinedAtom::JSON,
+            qjs::JS_ATOM_Date => PredefinedAtom::Date,
+            qjs::JS_ATOM_Function => PredefinedAtom::Function,
+            qjs::JS_ATOM_GeneratorFunction => PredefinedAtom::GeneratorFunction,
+            qjs::JS_ATOM_ForInIterator => PredefinedAtom::ForInIterator,
+            qjs::JS_ATOM_RegExp => PredefinedAtom::RegExp,
+            qjs::JS_ATOM_ArrayBuffer => PredefinedAtom::ArrayBuffer,
+            qjs::JS_ATOM_SharedArrayBuffer => PredefinedAtom::SharedArrayBuffer,
+            qjs::JS_ATOM_Uint8ClampedArray => PredefinedAtom::Uint8ClampedArray,
+            qjs::JS_ATOM_Int8Array => PredefinedAtom::Int8Array,
+            qjs::JS_ATOM_Uint8Array => PredefinedAtom::Uint8Array,
+            qjs::JS_ATOM_Int16Array => PredefinedAtom::Int16Array,
+            qjs::JS_ATOM_Uint16Array => PredefinedAtom::Uint16Array,
+            qjs::JS_ATOM_Int32Array => PredefinedAtom::Int32Array,
+            qjs::JS_ATOM_Uint32Array => PredefinedAtom::Uint32Array,
+            qjs::JS_ATOM_BigInt64Array => PredefinedAtom::BigInt64Array,
+            qjs::JS_ATOM_BigUint64Array => PredefinedAtom::BigUint64Array,
+            qjs::JS_ATOM_Float32Array => PredefinedAtom::Float32Array,
+            qjs::JS_ATOM_Float64Array => PredefinedAtom::Float64Array,
+            qjs::JS_ATOM_DataView => PredefinedAtom::DataView,
+            qjs::JS_ATOM_BigInt => PredefinedAtom::BigInt,
+            qjs::JS_ATOM_Map => PredefinedAtom::Map,
+            qjs::JS_ATOM_Set => PredefinedAtom::Set,
+            qjs::JS_ATOM_WeakMap => PredefinedAtom::WeakMap,
+            qjs::JS_ATOM_WeakSet => PredefinedAtom::WeakSet,
+            qjs::JS_ATOM_Map_Iterator => PredefinedAtom::MapIterator,
+            qjs::JS_ATOM_Set_Iterator => PredefinedAtom::SetIterator,
+            qjs::JS_ATOM_Array_Iterator => PredefinedAtom::ArrayIterator,
+            qjs::JS_ATOM_String_Iterator => PredefinedAtom::StringIterator,
+            qjs::JS_ATOM_RegExp_String_Iterator => PredefinedAtom::RegExpStringIterator,
+            qjs::JS_ATOM_Generator => PredefinedAtom::Generator,
+            qjs::JS_ATOM_Proxy => PredefinedAtom::Proxy,
+            qjs::JS_ATOM_Promise => PredefinedAtom::Promise,
+            qjs::JS_ATOM_PromiseResolveFunction => PredefinedAtom::PromiseResolveFunction,
+            qjs::JS_ATOM_PromiseRejectFunction => PredefinedAtom::PromiseRejectFunction,
+            qjs::JS_ATOM_AsyncFunction => PredefinedAtom::AsyncFunction,
+            qjs::JS_ATOM_AsyncFunctionResolve => PredefinedAtom::AsyncFunctionResolve,
+            qjs::JS_ATOM_AsyncFunctionReject => PredefinedAtom::AsyncFunctionReject,
+            qjs::JS_ATOM_AsyncGeneratorFunction => PredefinedAtom::AsyncGeneratorFunction,
+            qjs::JS_ATOM_AsyncGenerator => PredefinedAtom::AsyncGenerator,
+            qjs::JS_ATOM_EvalError => PredefinedAtom::EvalError,
+            qjs::JS_ATOM_RangeError => PredefinedAtom::RangeError,
+            qjs::JS_ATOM_ReferenceError => PredefinedAtom::ReferenceError,
+            qjs::JS_ATOM_SyntaxError => PredefinedAtom::SyntaxError,
+            qjs::JS_ATOM_TypeError => PredefinedAtom::TypeError,
+            qjs::JS_ATOM_URIError => PredefinedAtom::URIError,
+            qjs::JS_ATOM_InternalError => PredefinedAtom::InternalError,
+            qjs::JS_ATOM_Symbol_asyncIterator => PredefinedAtom::SymbolAsyncIterator,
+            qjs::JS_ATOM_Symbol_iterator => PredefinedAtom::SymbolIterator,
+            qjs::JS_ATOM_Symbol_match => PredefinedAtom::SymbolMatch,
+            qjs::JS_ATOM_Symbol_matchAll => PredefinedAtom::SymbolMatchAll,
+            qjs::JS_ATOM_Symbol_replace => PredefinedAtom::SymbolReplace,
+            qjs::JS_ATOM_Symbol_search => PredefinedAtom::SymbolSearch,
+            qjs::JS_ATOM_Symbol_split => PredefinedAtom::SymbolSplit,
+            qjs::JS_ATOM_Symbol_toStringTag => PredefinedAtom::SymbolToStringTag,
+            qjs::JS_ATOM_Symbol_isConcatSpreadable => PredefinedAtom::SymbolIsConcatSpreadable,
+            qjs::JS_ATOM_Symbol_hasInstance => PredefinedAtom::SymbolHasInstance,
+            qjs::JS_ATOM_Symbol_species => PredefinedAtom::SymbolSpecies,
+            qjs::JS_ATOM_Symbol_unscopables => PredefinedAtom::SymbolUnscopables,
+            _ => return None,
+        };
+        Some(atom)
+    }
 }
 
 #[cfg(test)]
@@ -859,6 +1069,7 @@ mod test {
             for predef in ALL_PREDEFS {
                 let atom = predef.into_atom(&ctx).unwrap();
                 assert_eq!(atom.to_string().unwrap().as_str(), predef.to_str());
+                assert_eq!(atom.as_predefined(), Some(*predef));
 
                 // the string of a symbol doesn't convert to the same atom.
                 if predef.is_symbol() {
@@ -875,4 +1086,25 @@ mod test {
             }
         })
     }
+
+    #[test]
+    fn compare_predefined() {
+        let rt = Runtime::new().unwrap();
+        let context = Context::full(&rt).unwrap();
+        context.with(|ctx| {
+            let length = Atom::from_str(ctx.clone(), "length").unwrap();
+            assert!(length.is(PredefinedAtom::Length));
+            assert!(!length.is(PredefinedAtom::Name));
+            assert_eq!(length.as_predefined(), Some(PredefinedAtom::Length));
+
+            let custom = Atom::from_str(ctx.clone(), "not_predefined").unwrap();
+            assert_eq!(custom.as_predefined(), None);
+
+            let index = Atom::from_u32(ctx.clone(), 0).unwrap();
+            assert_eq!(index.as_predefined(), None);
+
+            let iterator = Atom::from_str(ctx.clone(), "Symbol.iterator").unwrap();
+            assert!(!iterator.is(PredefinedAtom::SymbolIterator));
+        })
+    }
 }
@@ -1,4 +1,5 @@
-use crate::{qjs, Ctx, Error, Result, Value};
+use crate::{qjs, Ctx, Error, Exception, Result, String, Value};
+use std::ffi::CString;
 
 /// Rust representation of a JavaScript big int.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -20,6 +21,48 @@ impl<'js> BigInt<'js> {
         }
     }
 
+    /// Parse a big int from a string of digits in the given radix.
+    ///
+    /// The digits may be preceded by a `+` or `-` sign, digits above 9 are the letters `a` to `z`
+    /// in either case. Like `BigInt.prototype.toString` the radix must be between 2 and 36,
+    /// otherwise a `RangeError` is thrown. A `SyntaxError` is thrown if the string isn't a valid
+    /// number in the radix.
+    pub fn from_str_radix(ctx: Ctx<'js>, s: &str, radix: u32) -> Result<Self> {
+        check_radix(&ctx, radix)?;
+        let Some(decimal) = to_decimal(s, radix) else {
+            return Err(Exception::throw_syntax(
+                &ctx,
+                &format!("invalid digits for a big int in radix {radix}"),
+            ));
+        };
+        if let Ok(v) = decimal.parse() {
+            return Self::from_i64(ctx, v);
+        }
+        // Larger values are created from a big int literal, which is parsed by the engine and
+        // can't be changed by scripts, unlike the global `BigInt` function.
+        let file_name = CString::new("<bigint>").unwrap();
+        unsafe {
+            let v = ctx.eval_raw(
+                format!("{decimal}n"),
+                &file_name,
+                qjs::JS_EVAL_TYPE_GLOBAL as _,
+            )?;
+            Ok(BigInt(Value::from_js_value(ctx, v)))
+        }
+    }
+
+    /// Returns the digits of the big int in the given radix, see
+    /// [`BigInt::from_str_radix`].
+    pub fn to_string_radix(&self, radix: u32) -> Result<String<'js>> {
+        let ctx = self.0.ctx();
+        check_radix(ctx, radix)?;
+        let decimal = unsafe {
+            let v = ctx.handle_exception(qjs::JS_ToString(ctx.as_ptr(), self.0.value))?;
+            String::from_js_value(ctx.clone(), v).to_string()?
+        };
+        String::from_str(ctx.clone(), &from_decimal(&decimal, radix))
+    }
+
     pub fn to_i64(self) -> Result<i64> {
         unsafe {
             let mut res: i64 = 0;
@@ -31,6 +74,82 @@ impl<'js> BigInt<'js> {
     }
 }
 
+fn check_radix(ctx: &Ctx<'_>, radix: u32) -> Result<()> {
+    if !(2..=36).contains(&radix) {
+        return Err(Exception::throw_range(
+            ctx,
+            "radix must be between 2 and 36",
+        ));
+    }
+    Ok(())
+}
+
+/// Converts a number in the given radix to a decimal string which `BigInt()` is able to parse.
+fn to_decimal(s: &str, radix: u32) -> Option<std::string::String> {
+    const BASE: u64 = 1_000_000_000;
+
+    let (sign, digits) = match s.as_bytes().first() {
+        Some(b'-') => ("-", &s[1..]),
+        Some(b'+') => ("", &s[1..]),
+        _ => ("", s),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+
+    // Little endian limbs in base 10^9.
+    let mut limbs = vec![0u32];
+    for c in digits.chars() {
+        let mut carry = c.to_digit(radix)? as u64;
+        for limb in limbs.iter_mut() {
+            let v = *limb as u64 * radix as u64 + carry;
+            *limb = (v % BASE) as u32;
+            carry = v / BASE;
+        }
+        if carry > 0 {
+            limbs.push(carry as u32);
+        }
+    }
+
+    let mut res = format!("{sign}{}", limbs.last().unwrap());
+    for limb in limbs.iter().rev().skip(1) {
+        res.push_str(&format!("{limb:09}"));
+    }
+    Some(res)
+}
+
+/// Converts a decimal string, as returned by `JS_ToString`, to the digits in the given radix.
+fn from_decimal(s: &str, radix: u32) -> std::string::String {
+    const BASE: u64 = 1_000_000_000;
+
+    let (sign, digits) = match s.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", s),
+    };
+
+    // Little endian limbs in base 10^9, the string only contains ASCII digits.
+    let mut limbs: Vec<u32> = digits
+        .as_bytes()
+        .rchunks(9)
+        .map(|x| std::str::from_utf8(x).unwrap().parse().unwrap())
+        .collect();
+
+    let mut res = Vec::new();
+    while limbs.iter().any(|x| *x != 0) {
+        let mut rem = 0u64;
+        for limb in limbs.iter_mut().rev() {
+            let v = rem * BASE + *limb as u64;
+            *limb = (v / radix as u64) as u32;
+            rem = v % radix as u64;
+        }
+        res.push(std::char::from_digit(rem as u32, radix).unwrap());
+    }
+    if res.is_empty() {
+        res.push('0');
+    }
+    sign.chars().chain(res.into_iter().rev()).collect()
+}
+
 #[cfg(test)]
 mod test {
     use crate::*;
@@ -59,4 +178,51 @@ mod test {
             func.call::<_, ()>((bigint,)).unwrap();
         })
     }
+
+    #[test]
+    fn radix() {
+        test_with(|ctx| {
+            let v = BigInt::from_str_radix(ctx.clone(), "ff", 16).unwrap();
+            assert_eq!(v.clone().to_i64().unwrap(), 255);
+            let s = BigInt::from_i64(ctx.clone(), 255)
+                .unwrap()
+                .to_string_radix(2)
+                .unwrap();
+            assert_eq!(s.to_string().unwrap(), "11111111");
+
+            let big = "-1z0000000000000000000000000000000000000000000zz";
+            let v = BigInt::from_str_radix(ctx.clone(), big, 36).unwrap();
+            let s = v.to_string_radix(36).unwrap().to_string().unwrap();
+            assert_eq!(s, big);
+
+            let err = BigInt::from_str_radix(ctx.clone(), "12", 37).unwrap_err();
+            assert!(matches!(err, Error::Exception));
+            let err: Exception = ctx.catch().into_exception().unwrap();
+            assert!(err.message().unwrap().contains("radix"));
+            assert!(BigInt::from_str_radix(ctx.clone(), "12", 2).is_err());
+            ctx.catch();
+            assert!(BigInt::from_str_radix(ctx.clone(), "-", 10).is_err());
+            ctx.catch();
+            assert!(v.to_string_radix(1).is_err());
+            ctx.catch();
+
+            let zero = BigInt::from_str_radix(ctx.clone(), "-0", 7).unwrap();
+            assert_eq!(zero.to_string_radix(7).unwrap().to_string().unwrap(), "0");
+            let v = BigInt::from_str_radix(ctx.clone(), "1000000000000000000000", 10).unwrap();
+            let s = v.to_string_radix(16).unwrap().to_string().unwrap();
+            assert_eq!(s, "3635c9adc5dea00000");
+        })
+    }
+
+    #[test]
+    fn radix_shadowed_global() {
+        test_with(|ctx| {
+            ctx.eval::<(), _>("BigInt = () => 0n; BigInt.prototype = {};")
+                .unwrap();
+            let v = BigInt::from_str_radix(ctx.clone(), "-ff", 16).unwrap();
+            assert_eq!(v.clone().to_i64().unwrap(), -255);
+            let s = v.to_string_radix(16).unwrap().to_string().unwrap();
+            assert_eq!(s, "-ff");
+        })
+    }
 }
@@ -6,6 +6,10 @@ mod atom;
 mod coerce;
 mod from;
 mod into;
+#[cfg(feature = "serde-json")]
+mod json;
+#[cfg(feature = "uuid")]
+mod uuid;
 
 /// The wrapper for values to force coercion
 ///
@@ -31,6 +35,11 @@ mod into;
 ///
 /// assert!(ctx.eval::<f64, _>("`123.5`").is_err());
 /// assert_eq!(ctx.eval::<Coerced<f64>, _>("`123.5`")?.0, 123.5);
+///
+/// // Coercion to boolean
+/// assert!(ctx.eval::<bool, _>("0").is_err());
+/// assert!(!ctx.eval::<Coerced<bool>, _>("0")?.0);
+/// assert!(ctx.eval::<Coerced<bool>, _>("({})")?.0);
 /// #
 /// # Ok(())
 /// # }).unwrap();
@@ -39,6 +48,28 @@ mod into;
 #[repr(transparent)]
 pub struct Coerced<T>(pub T);
 
+/// The wrapper for values to be converted without any conversion leniency.
+///
+/// Converting to `()` accepts any value and discards it. `Strict<()>` instead only accepts
+/// `undefined`, which is useful to assert that a script doesn't produce a value.
+///
+/// ```
+/// # use rquickjs::{Runtime, Context, Result, convert::Strict};
+/// # let rt = Runtime::new().unwrap();
+/// # let ctx = Context::full(&rt).unwrap();
+/// # ctx.with(|ctx| -> Result<()> {
+/// #
+/// ctx.eval::<(), _>("42")?;
+/// assert!(ctx.eval::<Strict<()>, _>("42").is_err());
+/// ctx.eval::<Strict<()>, _>("let a = 42;")?;
+/// #
+/// # Ok(())
+/// # }).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Strict<T>(pub T);
+
 /// For converting JavaScript values to Rust values
 ///
 /// This trait automatically converts any value which can be
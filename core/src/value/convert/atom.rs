@@ -1,7 +1,11 @@
 use crate::{
-    atom::PredefinedAtom, qjs, Atom, Ctx, FromAtom, IntoAtom, Result, StdString, String, Value,
+    atom::PredefinedAtom, qjs, Atom, Ctx, Error, FromAtom, IntoAtom, Result, StdString, String,
+    Value,
 };
 
+/// The tag QuickJS uses for atoms which directly store an integer index.
+const JS_ATOM_TAG_INT: qjs::JSAtom = 1 << 31;
+
 impl<'js> FromAtom<'js> for Atom<'js> {
     fn from_atom(atom: Atom<'js>) -> Result<Self> {
         Ok(atom)
@@ -26,6 +30,27 @@ impl<'js> FromAtom<'js> for StdString {
     }
 }
 
+/// Convert from an atom which is an array index, like the key of `object[5]`.
+///
+/// Small indices are stored directly in the atom by QuickJS and don't require a lookup. Keys
+/// which aren't the canonical string form of a `u32`, like `"05"` or `"foo"`, result in an error.
+impl<'js> FromAtom<'js> for u32 {
+    fn from_atom(atom: Atom<'js>) -> Result<Self> {
+        if atom.atom & JS_ATOM_TAG_INT != 0 {
+            return Ok(atom.atom & !JS_ATOM_TAG_INT);
+        }
+        let name = atom.to_string()?;
+        match name.parse::<u32>() {
+            Ok(idx) if idx.to_string() == name => Ok(idx),
+            _ => Err(Error::new_from_js_message(
+                "atom",
+                "u32",
+                format!("property key `{name}` is not an index"),
+            )),
+        }
+    }
+}
+
 impl<'js> IntoAtom<'js> for PredefinedAtom {
     fn into_atom(self, ctx: &Ctx<'js>) -> Result<Atom<'js>> {
         Ok(unsafe { Atom::from_atom_val_dup(ctx.clone(), self as qjs::JSAtom) })
@@ -82,3 +107,40 @@ into_atom_impls! {
     from_i32: i8 i16 i32,
     from_f64: f32 f64,
 }
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn u32_atom() {
+        test_with(|ctx| {
+            let from_int = 5u32.into_atom(&ctx).unwrap();
+            let from_str = "5".into_atom(&ctx).unwrap();
+            assert_eq!(from_int, from_str);
+            assert_eq!(u32::from_atom(from_str).unwrap(), 5);
+
+            let large = u32::MAX - 1;
+            let atom = large.into_atom(&ctx).unwrap();
+            assert_eq!(atom.to_string().unwrap(), large.to_string());
+            assert_eq!(u32::from_atom(atom).unwrap(), large);
+
+            for key in ["05", "-1", "foo", "1.5"] {
+                let atom = key.into_atom(&ctx).unwrap();
+                assert!(u32::from_atom(atom).is_err());
+            }
+        });
+    }
+
+    #[test]
+    fn u32_keys() {
+        test_with(|ctx| {
+            let array: Array = ctx.eval("[1, 2, 3]").unwrap();
+            assert_eq!(array.as_object().get::<_, i32>(2u32).unwrap(), 3);
+
+            let obj: Object = ctx.eval("({ 1: 'a', 20: 'b', 3000000000: 'c' })").unwrap();
+            let keys = obj.keys::<u32>().collect::<Result<Vec<_>>>().unwrap();
+            assert_eq!(keys, [1, 20, 3000000000]);
+        });
+    }
+}
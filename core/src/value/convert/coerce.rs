@@ -95,3 +95,48 @@ impl<'js> FromJs<'js> for Coerced<bool> {
         }))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{convert::Coerced, test_with, FromJs, Object, StdString, Value};
+
+    #[test]
+    fn coerce_bool() {
+        test_with(|ctx| {
+            let truthy = |src: &str| {
+                let value: Value = ctx.eval(src).unwrap();
+                Coerced::<bool>::from_js(&ctx, value).unwrap().0
+            };
+            for falsy in ["0", "''", "null", "undefined", "NaN", "-0", "0n", "false"] {
+                assert!(!truthy(falsy), "{} should be falsy", falsy);
+            }
+            for src in [
+                "({})", "[]", "'0'", "' '", "-1", "Infinity", "Symbol()", "true",
+            ] {
+                assert!(truthy(src), "{} should be truthy", src);
+            }
+
+            let object = Object::new(ctx.clone()).unwrap().into_value();
+            assert!(Coerced::<bool>::from_js(&ctx, object).unwrap().0);
+        })
+    }
+
+    #[test]
+    fn coerce_string() {
+        test_with(|ctx| {
+            let string = |src: &str| {
+                let value: Value = ctx.eval(src).unwrap();
+                Coerced::<StdString>::from_js(&ctx, value).unwrap().0
+            };
+            assert_eq!(string("[1,2]"), "1,2");
+            assert_eq!(string("null"), "null");
+            assert_eq!(string("undefined"), "undefined");
+            assert_eq!(string("10n"), "10");
+            assert_eq!(string("({ toString() { return 'custom' } })"), "custom");
+
+            // `ToString` throws on symbols, unlike `String(symbol)`.
+            let symbol: Value = ctx.eval("Symbol('a')").unwrap();
+            assert!(Coerced::<StdString>::from_js(&ctx, symbol).is_err());
+        })
+    }
+}
@@ -1,11 +1,16 @@
 use crate::{
-    convert::List, Array, CString, Ctx, Error, FromAtom, FromJs, Object, Result, StdString, String,
-    Type, Value,
+    convert::{List, Strict},
+    Array, CString, Ctx, Error, FromAtom, FromJs, Object, Result, StdString, String, Type, Value,
 };
 use std::{
+    borrow::Cow,
     cell::{Cell, RefCell},
     collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque},
     hash::{BuildHasher, Hash},
+    num::{
+        NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+        NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
     rc::Rc,
     sync::{Arc, Mutex, RwLock},
     time::{Duration, SystemTime},
@@ -29,6 +34,33 @@ impl<'js> FromJs<'js> for StdString {
     }
 }
 
+/// Convert from a JS string
+///
+/// The string data returned by QuickJS has to be freed again, and a `Cow` has no place to keep
+/// the buffer alive after the conversion, so this always returns [`Cow::Owned`]. To borrow the
+/// string data convert to a [`CString`] instead, it owns the buffer and
+/// [`CString::to_str_lossy`] borrows from it for as long as the `CString` is alive:
+///
+/// ```
+/// # use rquickjs::{Runtime, Context, Function, CString};
+/// # use std::borrow::Cow;
+/// # let rt = Runtime::new().unwrap();
+/// # let ctx = Context::full(&rt).unwrap();
+/// # ctx.with(|ctx| {
+/// let len = Function::new(ctx.clone(), |s: CString| {
+///     let s: Cow<str> = s.to_str_lossy();
+///     s.len()
+/// })
+/// .unwrap();
+/// assert_eq!(len.call::<_, usize>(("foo",)).unwrap(), 3);
+/// # });
+/// ```
+impl<'js, 'a> FromJs<'js> for Cow<'a, str> {
+    fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> Result<Self> {
+        StdString::from_js(ctx, value).map(Cow::Owned)
+    }
+}
+
 impl<'js> FromJs<'js> for char {
     fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> Result<Self> {
         let type_name = value.type_name();
@@ -61,6 +93,17 @@ impl<'js> FromJs<'js> for () {
     }
 }
 
+/// Convert from JS as `undefined`, failing on any other value
+impl<'js> FromJs<'js> for Strict<()> {
+    fn from_js(_: &Ctx<'js>, value: Value<'js>) -> Result<Self> {
+        if value.is_undefined() {
+            Ok(Strict(()))
+        } else {
+            Err(Error::new_from_js(value.type_name(), "undefined"))
+        }
+    }
+}
+
 /// Convert from JS as optional
 impl<'js, T> FromJs<'js> for Option<T>
 where
@@ -127,16 +170,20 @@ fn tuple_match_size(actual: usize, expected: usize) -> Result<()> {
     }
 }
 
+/// Checks that `min <= val < end`.
+///
+/// The upper bound is exclusive because the maximum of 64 bit integers is not representable as a
+/// float, `u64::MAX as f64` rounds up to `2^64`.
 fn number_match_range<T: PartialOrd>(
     val: T,
     min: T,
-    max: T,
+    end: T,
     from: &'static str,
     to: &'static str,
 ) -> Result<()> {
     if val < min {
         Err(Error::new_from_js_message(from, to, "Underflow"))
-    } else if val > max {
+    } else if val >= end {
         Err(Error::new_from_js_message(from, to, "Overflow"))
     } else {
         Ok(())
@@ -239,7 +286,7 @@ macro_rules! from_js_impls {
                 impl<'js> FromJs<'js> for $type {
                     fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> Result<Self> {
                         let num = <$base>::from_js(ctx, value)?;
-                        number_match_range(num, $type::MIN as $base, $type::MAX as $base, stringify!($base), stringify!($type))?;
+                        number_match_range(num, $type::MIN as $base, $type::MAX as $base + 1 as $base, stringify!($base), stringify!($type))?;
                         Ok(num as $type)
                     }
                 }
@@ -247,6 +294,20 @@ macro_rules! from_js_impls {
         )*
     };
 
+    // for non-zero integer types
+    (nonzero: $($type:ident => $inner:ident,)*) => {
+        $(
+            impl<'js> FromJs<'js> for $type {
+                fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> Result<Self> {
+                    let num = <$inner>::from_js(ctx, value)?;
+                    $type::new(num).ok_or_else(|| {
+                        Error::new_from_js_message(stringify!($inner), stringify!($type), "Zero")
+                    })
+                }
+            }
+        )*
+    };
+
     (@one $($t:tt)*) => { 1 };
 
     (@idx A) => { 0 };
@@ -273,6 +334,20 @@ from_js_impls! {
     f64: u32 u64 i64 usize isize,
 }
 
+from_js_impls! {
+    nonzero:
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroIsize => isize,
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroUsize => usize,
+}
+
 from_js_impls! {
     val:
     bool => Bool get_bool,
@@ -329,6 +404,35 @@ from_js_impls! {
     IndexSet {S: Default + BuildHasher} (Eq + Hash),
 }
 
+/// Convert from JS array to Rust fixed size array
+///
+/// Only the first `N` elements are read, so longer arrays are accepted.
+impl<'js, T, const N: usize> FromJs<'js> for [T; N]
+where
+    T: FromJs<'js>,
+{
+    fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> Result<Self> {
+        let array = Array::from_value(value)?;
+        let array_len = array.len();
+        if array_len < N {
+            return Err(Error::new_from_js_message(
+                "array",
+                "fixed size array",
+                format!("Expected at least {N} values, got {array_len}"),
+            ));
+        }
+
+        let mut values = Vec::with_capacity(N);
+        for idx in 0..N {
+            values.push(array.get::<T>(idx)?);
+        }
+        match values.try_into() {
+            Ok(values) => Ok(values),
+            Err(_) => unreachable!("array was created with the correct length"),
+        }
+    }
+}
+
 from_js_impls! {
     map:
     /// Convert from JS object to Rust hash map
@@ -409,8 +513,141 @@ chrono_from_js_impls! {
     Local;
 }
 
+macro_rules! net_from_js_impls {
+    ($($type:ident,)+) => {
+        $(
+            /// Convert from a JS string containing the address
+            #[cfg(feature = "net")]
+            #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net")))]
+            impl<'js> FromJs<'js> for std::net::$type {
+                fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> Result<Self> {
+                    let type_name = value.type_name();
+                    StdString::from_js(ctx, value)?
+                        .parse()
+                        .map_err(|error: std::net::AddrParseError| {
+                            Error::new_from_js_message(type_name, stringify!($type), error.to_string())
+                        })
+                }
+            }
+        )+
+    };
+}
+
+net_from_js_impls! {
+    IpAddr,
+    Ipv4Addr,
+    Ipv6Addr,
+    SocketAddr,
+    SocketAddrV4,
+    SocketAddrV6,
+}
+
+/// Convert from a JS string containing the path
+///
+/// JS strings which contain lone surrogates are not valid unicode and result in an error instead
+/// of a path with replaced characters.
+#[cfg(feature = "path")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "path")))]
+impl<'js> FromJs<'js> for std::path::PathBuf {
+    fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> Result<Self> {
+        StdString::from_js(ctx, value).map(Into::into)
+    }
+}
+
 #[cfg(test)]
 mod test {
+    #[test]
+    fn strict_unit() {
+        use crate::{convert::Strict, test_with, Error, FromJs, Value};
+
+        test_with(|ctx| {
+            let value = ctx.eval::<Value, _>("42").unwrap();
+            <()>::from_js(&ctx, value.clone()).unwrap();
+            let err = Strict::<()>::from_js(&ctx, value).unwrap_err();
+            assert!(matches!(err, Error::FromJs { from: "int", .. }));
+
+            Strict::<()>::from_js(&ctx, Value::new_undefined(ctx.clone())).unwrap();
+            assert!(ctx.eval::<Strict<()>, _>("null").is_err());
+            ctx.eval::<Strict<()>, _>("let a = 1;").unwrap();
+        })
+    }
+
+    #[test]
+    fn js_to_integer_range() {
+        use crate::{Context, Error, Runtime};
+
+        let runtime = Runtime::new().unwrap();
+        let ctx = Context::full(&runtime).unwrap();
+
+        ctx.with(|ctx| {
+            assert_eq!(ctx.eval::<u32, _>("2 ** 32 - 1").unwrap(), u32::MAX);
+            // The conversion into a 32 bit `usize` uses the same range check.
+            let err = ctx.eval::<u32, _>("2 ** 32").unwrap_err();
+            assert!(matches!(err, Error::FromJs { to: "u32", .. }));
+            assert!(err.to_string().contains("Overflow"));
+            let err = ctx.eval::<u32, _>("-1").unwrap_err();
+            assert!(err.to_string().contains("Underflow"));
+
+            // `u64::MAX as f64` rounds up to `2 ** 64`.
+            let err = ctx.eval::<u64, _>("2 ** 64").unwrap_err();
+            assert!(err.to_string().contains("Overflow"));
+            let err = ctx.eval::<usize, _>("2 ** 64").unwrap_err();
+            assert!(err.to_string().contains("Overflow"));
+            let err = ctx.eval::<i64, _>("2 ** 63").unwrap_err();
+            assert!(err.to_string().contains("Overflow"));
+            assert_eq!(ctx.eval::<i64, _>("-(2 ** 63)").unwrap(), i64::MIN);
+            assert_eq!(ctx.eval::<i16, _>("32767").unwrap(), i16::MAX);
+            assert!(ctx.eval::<i16, _>("32768").is_err());
+        });
+    }
+
+    #[test]
+    fn js_to_nonzero() {
+        use crate::{Context, Error, IntoJs, Runtime, Value};
+        use std::num::{NonZeroI64, NonZeroU32, NonZeroU8};
+
+        let runtime = Runtime::new().unwrap();
+        let ctx = Context::full(&runtime).unwrap();
+
+        ctx.with(|ctx| {
+            let res: NonZeroU32 = ctx.eval("5").unwrap();
+            assert_eq!(res.get(), 5);
+            let res: NonZeroI64 = ctx.eval("-5").unwrap();
+            assert_eq!(res.get(), -5);
+
+            let err = ctx.eval::<NonZeroU32, _>("0").unwrap_err();
+            assert!(matches!(err, Error::FromJs { .. }));
+            assert!(err.to_string().contains("Zero"));
+            let err = ctx.eval::<NonZeroU8, _>("256").unwrap_err();
+            assert!(err.to_string().contains("Overflow"));
+
+            let value: Value = NonZeroU32::new(7).unwrap().into_js(&ctx).unwrap();
+            assert_eq!(value.as_int(), Some(7));
+        });
+    }
+
+    #[test]
+    fn js_to_fixed_size_array() {
+        use crate::{Context, Error, IntoJs, Runtime, Value};
+
+        let runtime = Runtime::new().unwrap();
+        let ctx = Context::full(&runtime).unwrap();
+
+        ctx.with(|ctx| {
+            let value: Value = [1.0f32, 2.0, 3.0].into_js(&ctx).unwrap();
+            assert!(value.is_array());
+            let res: [f32; 3] = value.get().unwrap();
+            assert_eq!(res, [1.0, 2.0, 3.0]);
+
+            let res: [i32; 2] = ctx.eval("[1, 2, 3]").unwrap();
+            assert_eq!(res, [1, 2]);
+
+            let err = ctx.eval::<[f32; 3], _>("[1, 2]").unwrap_err();
+            assert!(matches!(err, Error::FromJs { .. }));
+            assert!(err.to_string().contains("got 2"));
+        });
+    }
+
     #[test]
     fn js_to_system_time() {
         use crate::{Context, Runtime};
@@ -7,7 +7,12 @@ use crate::{
 use std::{
     cell::{Cell, RefCell},
     collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque},
-    sync::{Mutex, RwLock},
+    num::{
+        NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+        NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+    rc::Rc,
+    sync::{Arc, Mutex, RwLock},
     time::SystemTime,
 };
 
@@ -76,6 +81,26 @@ where
     }
 }
 
+impl<'js, T, const N: usize> IntoJs<'js> for [T; N]
+where
+    T: IntoJs<'js>,
+{
+    fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+        self.into_iter()
+            .collect_js(ctx)
+            .map(|Array(value)| value.into_value())
+    }
+}
+
+impl<'js, T, const N: usize> IntoJs<'js> for &[T; N]
+where
+    for<'a> &'a T: IntoJs<'js>,
+{
+    fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+        self.as_slice().into_js(ctx)
+    }
+}
+
 impl<'js> IntoJs<'js> for () {
     fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
         Ok(Value::new_undefined(ctx.clone()))
@@ -185,6 +210,54 @@ where
     }
 }
 
+/// Convert the shared value, cloning it if other references to it exist
+///
+/// The JavaScript value doesn't share ownership with the Rust side. To share state between a
+/// class instance and Rust, store the shared state, like a `Rc<RefCell<T>>`, in a field of the
+/// class instead.
+impl<'js, T> IntoJs<'js> for Rc<T>
+where
+    T: IntoJs<'js> + Clone,
+{
+    fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+        Rc::try_unwrap(self)
+            .unwrap_or_else(|x| (*x).clone())
+            .into_js(ctx)
+    }
+}
+
+impl<'js, T> IntoJs<'js> for &Rc<T>
+where
+    for<'r> &'r T: IntoJs<'js>,
+{
+    fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+        self.as_ref().into_js(ctx)
+    }
+}
+
+/// Convert the shared value, cloning it if other references to it exist
+///
+/// See the implementation for [`Rc`] on how the value is shared.
+impl<'js, T> IntoJs<'js> for Arc<T>
+where
+    T: IntoJs<'js> + Clone,
+{
+    fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+        Arc::try_unwrap(self)
+            .unwrap_or_else(|x| (*x).clone())
+            .into_js(ctx)
+    }
+}
+
+impl<'js, T> IntoJs<'js> for &Arc<T>
+where
+    for<'r> &'r T: IntoJs<'js>,
+{
+    fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+        self.as_ref().into_js(ctx)
+    }
+}
+
 impl<'js, T> IntoJs<'js> for &Cell<T>
 where
     T: IntoJs<'js> + Copy,
@@ -380,6 +453,49 @@ macro_rules! into_js_impls {
         )*
     };
 
+    // for non-zero integer types
+    (nonzero: $($type:ident,)*) => {
+        $(
+            impl<'js> IntoJs<'js> for $type {
+                fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+                    self.get().into_js(ctx)
+                }
+            }
+
+            impl<'js> IntoJs<'js> for &$type {
+                fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+                    self.get().into_js(ctx)
+                }
+            }
+        )*
+    };
+
+    // for pointer sized integers which are only converted if a number can represent them exactly
+    (size: $($type:ident)*) => {
+        $(
+            impl<'js> IntoJs<'js> for $type {
+                fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+                    // The largest range of integers which a float can represent exactly.
+                    const MAX_SAFE: i128 = (1 << 53) - 1;
+                    if !(-MAX_SAFE..=MAX_SAFE).contains(&(self as i128)) {
+                        return Err(Error::new_into_js_message(
+                            stringify!($type),
+                            "number",
+                            "Overflow",
+                        ));
+                    }
+                    (self as i64).into_js(ctx)
+                }
+            }
+
+            impl<'js> IntoJs<'js> for &$type {
+                fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+                    (*self).into_js(ctx)
+                }
+            }
+        )*
+    };
+
     (@idx A) => { 0 };
     (@idx B) => { 1 };
     (@idx C) => { 2 };
@@ -463,7 +579,25 @@ into_js_impls! {
 
 into_js_impls! {
     val:
-    i32 f64 => i64 u32 u64 usize isize,
+    i32 f64 => i64 u32 u64,
+}
+
+into_js_impls! {
+    size: usize isize
+}
+
+into_js_impls! {
+    nonzero:
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroIsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroUsize,
 }
 
 fn millis_to_date<'js>(ctx: &Ctx<'js>, millis: i64) -> Result<Value<'js>> {
@@ -516,9 +650,114 @@ impl<'js, Tz: chrono::TimeZone> IntoJs<'js> for chrono::DateTime<Tz> {
     }
 }
 
+macro_rules! net_into_js_impls {
+    ($($type:ident,)+) => {
+        $(
+            /// Convert into a JS string in the canonical format of the address
+            #[cfg(feature = "net")]
+            #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net")))]
+            impl<'js> IntoJs<'js> for std::net::$type {
+                fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+                    self.to_string().into_js(ctx)
+                }
+            }
+        )+
+    };
+}
+
+net_into_js_impls! {
+    IpAddr,
+    Ipv4Addr,
+    Ipv6Addr,
+    SocketAddr,
+    SocketAddrV4,
+    SocketAddrV6,
+}
+
+#[cfg(feature = "path")]
+fn path_into_js<'js>(
+    ctx: &Ctx<'js>,
+    path: &std::path::Path,
+    from: &'static str,
+) -> Result<Value<'js>> {
+    path.to_str()
+        .ok_or_else(|| Error::new_into_js_message(from, "string", "path is not valid unicode"))?
+        .into_js(ctx)
+}
+
+/// Convert into a JS string containing the path
+///
+/// Paths which are not valid unicode, i.e. non UTF-8 paths on Unix and paths with unpaired
+/// surrogates on Windows, can not be represented and result in an error instead of being
+/// converted lossily.
+#[cfg(feature = "path")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "path")))]
+impl<'js> IntoJs<'js> for std::path::PathBuf {
+    fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+        path_into_js(ctx, &self, "PathBuf")
+    }
+}
+
+/// Convert into a JS string containing the path
+///
+/// See the implementation for [`PathBuf`](std::path::PathBuf) for how non unicode paths are
+/// handled.
+#[cfg(feature = "path")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "path")))]
+impl<'js> IntoJs<'js> for &std::path::Path {
+    fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+        path_into_js(ctx, self, "Path")
+    }
+}
+
 #[cfg(test)]
 mod test {
 
+    #[test]
+    fn shared_to_js() {
+        use crate::{Context, IntoJs, Runtime};
+        use std::{rc::Rc, sync::Arc};
+
+        let runtime = Runtime::new().unwrap();
+        let ctx = Context::full(&runtime).unwrap();
+
+        ctx.with(|ctx| {
+            let shared = Rc::new(vec![1, 2]);
+            let value = shared.clone().into_js(&ctx).unwrap();
+            assert_eq!(value.get::<Vec<i32>>().unwrap(), vec![1, 2]);
+            let value = Rc::try_unwrap(shared).unwrap().into_js(&ctx).unwrap();
+            assert!(value.is_array());
+
+            let shared = Arc::new("text".to_string());
+            let value = (&shared).into_js(&ctx).unwrap();
+            assert_eq!(value.get::<String>().unwrap(), "text");
+            let value = shared.into_js(&ctx).unwrap();
+            assert_eq!(value.get::<String>().unwrap(), "text");
+        });
+    }
+
+    #[test]
+    fn size_to_js() {
+        use crate::{Context, Error, IntoJs, Runtime};
+
+        let runtime = Runtime::new().unwrap();
+        let ctx = Context::full(&runtime).unwrap();
+
+        ctx.with(|ctx| {
+            let max_safe = (1usize << 53) - 1;
+            let value = max_safe.into_js(&ctx).unwrap();
+            assert_eq!(value.as_float(), Some(max_safe as f64));
+            let value = (-(max_safe as isize)).into_js(&ctx).unwrap();
+            assert_eq!(value.as_float(), Some(-(max_safe as f64)));
+            assert_eq!(5usize.into_js(&ctx).unwrap().as_int(), Some(5));
+
+            let err = (max_safe + 1).into_js(&ctx).unwrap_err();
+            assert!(matches!(err, Error::IntoJs { from: "usize", .. }));
+            let err = isize::MIN.into_js(&ctx).unwrap_err();
+            assert!(matches!(err, Error::IntoJs { from: "isize", .. }));
+        });
+    }
+
     #[test]
     fn char_to_js() {
         use crate::{Context, IntoJs, Runtime};
@@ -560,7 +799,7 @@ mod test {
             let globs = ctx.globals();
             globs.set("ts", ts.into_js(&ctx).unwrap()).unwrap();
             let res: i64 = ctx.eval("ts.getTime()").unwrap();
-            assert_eq!(millis, res as _);
+            assert_eq!(millis, res as u128);
         });
 
         let ts = SystemTime::UNIX_EPOCH - Duration::from_millis(123456);
@@ -573,7 +812,7 @@ mod test {
             let globs = ctx.globals();
             globs.set("ts", ts.into_js(&ctx).unwrap()).unwrap();
             let res: i64 = ctx.eval("ts.getTime()").unwrap();
-            assert_eq!(-(millis as i64), res as _);
+            assert_eq!(-(millis as i64), res);
         });
     }
 
@@ -596,4 +835,80 @@ mod test {
             assert_eq!(millis, res);
         });
     }
+
+    #[cfg(feature = "net")]
+    #[test]
+    fn net_addr_round_trip() {
+        use crate::{test_with, Error};
+        use std::net::{IpAddr, Ipv6Addr, SocketAddr, SocketAddrV6};
+
+        test_with(|ctx| {
+            let v4: IpAddr = "127.0.0.1".parse().unwrap();
+            ctx.globals().set("v4", v4).unwrap();
+            let s: String = ctx.eval("v4").unwrap();
+            assert_eq!(s, "127.0.0.1");
+            assert_eq!(ctx.eval::<IpAddr, _>("v4").unwrap(), v4);
+
+            let v6: IpAddr = "::1".parse().unwrap();
+            ctx.globals().set("v6", v6).unwrap();
+            assert_eq!(ctx.eval::<IpAddr, _>("v6").unwrap(), v6);
+
+            let sock: SocketAddr = ctx.eval("'[::1]:8080'").unwrap();
+            assert_eq!(sock, SocketAddr::new(v6, 8080));
+            ctx.globals().set("sock", sock).unwrap();
+            let s: String = ctx.eval("sock").unwrap();
+            assert_eq!(s, "[::1]:8080");
+
+            let scoped = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 80, 0, 2);
+            ctx.globals().set("scoped", scoped).unwrap();
+            let s: String = ctx.eval("scoped").unwrap();
+            assert_eq!(s, "[::1%2]:80");
+            assert_eq!(ctx.eval::<SocketAddrV6, _>("scoped").unwrap(), scoped);
+
+            let err = ctx.eval::<IpAddr, _>("'fe80::1%eth0'").unwrap_err();
+            assert!(matches!(err, Error::FromJs { to: "IpAddr", .. }));
+            let err = ctx.eval::<SocketAddr, _>("'::1:8080'").unwrap_err();
+            assert!(matches!(
+                err,
+                Error::FromJs {
+                    to: "SocketAddr",
+                    ..
+                }
+            ));
+        })
+    }
+
+    #[cfg(feature = "path")]
+    #[test]
+    fn path_round_trip() {
+        use crate::test_with;
+        use std::path::{Path, PathBuf};
+
+        test_with(|ctx| {
+            let path = Path::new("some/relative/file.js");
+            ctx.globals().set("path", path).unwrap();
+            let s: String = ctx.eval("path").unwrap();
+            assert_eq!(s, "some/relative/file.js");
+            assert_eq!(ctx.eval::<PathBuf, _>("path").unwrap(), path);
+
+            ctx.globals().set("owned", path.join("..")).unwrap();
+            let res: PathBuf = ctx.eval("owned").unwrap();
+            assert_eq!(res, PathBuf::from("some/relative/file.js/.."));
+
+            assert!(ctx.eval::<PathBuf, _>("'\\uD800'").is_err());
+        })
+    }
+
+    #[cfg(all(feature = "path", unix))]
+    #[test]
+    fn non_unicode_path() {
+        use crate::{test_with, Error, IntoJs};
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt, path::Path};
+
+        test_with(|ctx| {
+            let path = Path::new(OsStr::from_bytes(b"invalid\xff"));
+            let err = path.into_js(&ctx).unwrap_err();
+            assert!(matches!(err, Error::IntoJs { from: "Path", .. }));
+        })
+    }
 }
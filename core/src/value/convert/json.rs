@@ -0,0 +1,212 @@
+use crate::{
+    convert::Coerced, Array, BigInt, Ctx, Error, FromJs, IntoJs, Object, Result, StdString, Type,
+    Value,
+};
+use serde_json::{Map, Number, Value as JsonValue};
+
+/// The largest integer which a JavaScript number can represent exactly, `Number.MAX_SAFE_INTEGER`.
+const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+/// Convert a JSON value to a JavaScript value.
+///
+/// Integers within [`Number.MAX_SAFE_INTEGER`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/MAX_SAFE_INTEGER)
+/// become numbers, larger integers become `BigInt`s so they don't lose precision.
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "serde-json")))]
+impl<'js> IntoJs<'js> for &JsonValue {
+    fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+        Ok(match self {
+            JsonValue::Null => Value::new_null(ctx.clone()),
+            JsonValue::Bool(x) => Value::new_bool(ctx.clone(), *x),
+            JsonValue::Number(x) => number_into_js(ctx, x)?,
+            JsonValue::String(x) => x.as_str().into_js(ctx)?,
+            JsonValue::Array(x) => x.as_slice().into_js(ctx)?,
+            JsonValue::Object(x) => {
+                let object = Object::new(ctx.clone())?;
+                for (key, value) in x {
+                    object.set(key.as_str(), value)?;
+                }
+                object.into_value()
+            }
+        })
+    }
+}
+
+/// Convert a JSON value to a JavaScript value.
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "serde-json")))]
+impl<'js> IntoJs<'js> for JsonValue {
+    fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+        (&self).into_js(ctx)
+    }
+}
+
+fn number_into_js<'js>(ctx: &Ctx<'js>, number: &Number) -> Result<Value<'js>> {
+    if let Some(x) = number.as_i64() {
+        if (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&x) {
+            Ok(Value::new_number(ctx.clone(), x as f64))
+        } else {
+            BigInt::from_i64(ctx.clone(), x).map(|BigInt(x)| x)
+        }
+    } else if let Some(x) = number.as_u64() {
+        BigInt::from_u64(ctx.clone(), x).map(|BigInt(x)| x)
+    } else {
+        // Not an integer so it must be representable as a float.
+        Ok(Value::new_float(ctx.clone(), number.as_f64().unwrap()))
+    }
+}
+
+/// Convert a JavaScript value to a JSON value.
+///
+/// The conversion follows `JSON.stringify` where JSON has no equivalent: `undefined` and
+/// non-finite numbers become `null`. `BigInt`s which fit into a 64 bit integer become numbers.
+/// Functions, symbols and cyclic objects result in an error.
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "serde-json")))]
+impl<'js> FromJs<'js> for JsonValue {
+    fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> Result<Self> {
+        value_from_js(ctx, value, &mut Vec::new())
+    }
+}
+
+fn value_from_js<'js>(
+    ctx: &Ctx<'js>,
+    value: Value<'js>,
+    parents: &mut Vec<Object<'js>>,
+) -> Result<JsonValue> {
+    let type_ = value.type_of();
+    Ok(match type_ {
+        Type::Uninitialized | Type::Undefined | Type::Null => JsonValue::Null,
+        Type::Bool => JsonValue::Bool(value.as_bool().unwrap()),
+        Type::Int => JsonValue::Number(value.as_int().unwrap().into()),
+        Type::Float => float_from_js(value.as_float().unwrap()),
+        Type::String => JsonValue::String(StdString::from_js(ctx, value)?),
+        Type::BigInt => {
+            let Coerced(string) = Coerced::<StdString>::from_js(ctx, value)?;
+            if let Ok(x) = string.parse::<i64>() {
+                JsonValue::Number(x.into())
+            } else if let Ok(x) = string.parse::<u64>() {
+                JsonValue::Number(x.into())
+            } else {
+                return Err(Error::new_from_js_message(
+                    "bigint",
+                    "JSON number",
+                    "Value doesn't fit into a 64 bit integer",
+                ));
+            }
+        }
+        Type::Array | Type::Object | Type::Exception | Type::Promise => {
+            let object = value.into_object().unwrap();
+            if parents.contains(&object) {
+                return Err(Error::new_from_js_message(
+                    type_.as_str(),
+                    "JSON value",
+                    "Cyclic object value",
+                ));
+            }
+            parents.push(object.clone());
+            let res = if let Some(array) = object.as_array() {
+                array_from_js(ctx, array, parents)
+            } else {
+                object_from_js(ctx, &object, parents)
+            };
+            parents.pop();
+            res?
+        }
+        _ => return Err(Error::new_from_js(type_.as_str(), "JSON value")),
+    })
+}
+
+fn float_from_js(x: f64) -> JsonValue {
+    #[allow(clippy::float_cmp)]
+    if x.trunc() == x && x.abs() <= MAX_SAFE_INTEGER as f64 {
+        JsonValue::Number((x as i64).into())
+    } else {
+        Number::from_f64(x).map_or(JsonValue::Null, JsonValue::Number)
+    }
+}
+
+fn array_from_js<'js>(
+    ctx: &Ctx<'js>,
+    array: &Array<'js>,
+    parents: &mut Vec<Object<'js>>,
+) -> Result<JsonValue> {
+    let mut res = Vec::with_capacity(array.len());
+    for value in array.iter::<Value>() {
+        res.push(value_from_js(ctx, value?, parents)?);
+    }
+    Ok(JsonValue::Array(res))
+}
+
+fn object_from_js<'js>(
+    ctx: &Ctx<'js>,
+    object: &Object<'js>,
+    parents: &mut Vec<Object<'js>>,
+) -> Result<JsonValue> {
+    let mut res = Map::new();
+    for key in object.keys::<StdString>() {
+        let key = key?;
+        let value = object.get(key.as_str())?;
+        res.insert(key, value_from_js(ctx, value, parents)?);
+    }
+    Ok(JsonValue::Object(res))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use serde_json::{json, Value as JsonValue};
+
+    #[test]
+    fn round_trip() {
+        test_with(|ctx| {
+            let json = json!({
+                "a": [1, true, null],
+                "b": { "c": "text", "d": 1.5, "e": [] },
+                "f": -12,
+            });
+            let value = json.clone().into_js(&ctx).unwrap();
+            ctx.globals().set("value", value.clone()).unwrap();
+            let res: bool = ctx
+                .eval(r#"Array.isArray(value.a) && value.a[1] === true && value.b.c === "text""#)
+                .unwrap();
+            assert!(res);
+
+            let res: JsonValue = value.get().unwrap();
+            assert_eq!(res, json);
+        })
+    }
+
+    #[test]
+    fn numbers() {
+        test_with(|ctx| {
+            let value = json!(9007199254740993u64).into_js(&ctx).unwrap();
+            assert_eq!(value.type_of(), Type::BigInt);
+            assert_eq!(
+                value.get::<JsonValue>().unwrap(),
+                json!(9007199254740993u64)
+            );
+
+            let value = json!(u64::MAX).into_js(&ctx).unwrap();
+            assert_eq!(value.type_of(), Type::BigInt);
+            assert_eq!(value.get::<JsonValue>().unwrap(), json!(u64::MAX));
+
+            let value = json!(9007199254740991i64).into_js(&ctx).unwrap();
+            assert!(value.is_number());
+
+            let res: JsonValue = ctx.eval("[2 ** 40, 0.5, NaN, undefined, 5n]").unwrap();
+            assert_eq!(res, json!([1099511627776i64, 0.5, null, null, 5]));
+
+            assert!(ctx.eval::<JsonValue, _>("2n ** 64n").is_err());
+        })
+    }
+
+    #[test]
+    fn unsupported() {
+        test_with(|ctx| {
+            assert!(ctx.eval::<JsonValue, _>("({ f() {} }).f").is_err());
+            assert!(ctx.eval::<JsonValue, _>("Symbol()").is_err());
+            let err = ctx
+                .eval::<JsonValue, _>("const a = { b: {} }; a.b.a = a; a")
+                .unwrap_err();
+            assert!(err.to_string().contains("Cyclic"));
+        })
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{Ctx, Error, FromJs, IntoJs, Result, Value};
+use ::uuid::Uuid;
+
+/// Convert a UUID to a JavaScript string in the hyphenated form, like
+/// `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "uuid")))]
+impl<'js> IntoJs<'js> for Uuid {
+    fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+        self.hyphenated()
+            .encode_lower(&mut Uuid::encode_buffer())
+            .into_js(ctx)
+    }
+}
+
+/// Convert a JavaScript value to a UUID.
+///
+/// Strings are parsed in any of the formats accepted by [`Uuid::parse_str`]. With the
+/// `array-buffer` feature a `Uint8Array` of 16 bytes is also accepted.
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "uuid")))]
+impl<'js> FromJs<'js> for Uuid {
+    fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> Result<Self> {
+        if let Some(string) = value.as_string() {
+            let string = string.to_string()?;
+            return Uuid::parse_str(&string)
+                .map_err(|e| Error::new_from_js_message("string", "Uuid", e.to_string()));
+        }
+
+        #[cfg(feature = "array-buffer")]
+        if let Some(array) = value.as_object().and_then(|x| x.as_typed_array::<u8>()) {
+            let bytes = array
+                .as_bytes()
+                .ok_or_else(|| Error::new_from_js_message("Uint8Array", "Uuid", "detached"))?;
+            return Uuid::from_slice(bytes)
+                .map_err(|e| Error::new_from_js_message("Uint8Array", "Uuid", e.to_string()));
+        }
+
+        Err(Error::new_from_js(value.type_name(), "Uuid"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_with, Error};
+    use ::uuid::Uuid;
+
+    #[test]
+    fn uuid_round_trip() {
+        test_with(|ctx| {
+            let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+            ctx.globals().set("uuid", uuid).unwrap();
+            let string: String = ctx.eval("uuid").unwrap();
+            assert_eq!(string, "67e55044-10b1-426f-9247-bb680e5fe0c8");
+
+            let res: Uuid = ctx.eval("uuid.toUpperCase()").unwrap();
+            assert_eq!(res, uuid);
+
+            let err = ctx.eval::<Uuid, _>("'not-a-uuid'").unwrap_err();
+            assert!(matches!(err, Error::FromJs { .. }));
+            let err = ctx.eval::<Uuid, _>("42").unwrap_err();
+            assert!(matches!(err, Error::FromJs { .. }));
+        })
+    }
+
+    #[cfg(feature = "array-buffer")]
+    #[test]
+    fn uuid_from_bytes() {
+        test_with(|ctx| {
+            let res: Uuid = ctx
+                .eval("new Uint8Array([0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0, 0xc8])")
+                .unwrap();
+            assert_eq!(
+                res,
+                Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap()
+            );
+            assert!(ctx.eval::<Uuid, _>("new Uint8Array(4)").is_err());
+        })
+    }
+}
@@ -1,6 +1,9 @@
 use std::{error::Error as ErrorTrait, ffi::CStr, fmt};
 
-use crate::{atom::PredefinedAtom, convert::Coerced, qjs, Ctx, Error, Object, Result, Value};
+use crate::{
+    atom::PredefinedAtom, convert::Coerced, qjs, value::Constructor, Ctx, Error, IntoAtom, IntoJs,
+    Object, Result, Value,
+};
 
 /// A JavaScript instance of Error
 ///
@@ -66,6 +69,49 @@ impl<'js> Exception<'js> {
         Ok(Exception(obj))
     }
 
+    /// Creates a new exception with a given message which inherits from the given prototype.
+    ///
+    /// This allows creating instances of custom error classes, the resulting object will still be
+    /// a native error and will thus have a stack trace.
+    pub fn from_prototype(proto: &Object<'js>, message: &str) -> Result<Self> {
+        let exception = Self::from_message(proto.ctx().clone(), message)?;
+        exception.set_prototype(Some(proto))?;
+        Ok(exception)
+    }
+
+    /// Creates a new exception with a given message which is an instance of the given error
+    /// class.
+    ///
+    /// The constructor itself is not called, only its `prototype` property is used.
+    /// ```
+    /// # use rquickjs::{Runtime, Context, Exception, function::Constructor};
+    /// # let rt = Runtime::new().unwrap();
+    /// # let ctx = Context::full(&rt).unwrap();
+    /// # ctx.with(|ctx| {
+    /// let http_error: Constructor = ctx.eval("class HttpError extends Error {}; HttpError").unwrap();
+    /// let exception = Exception::from_constructor(&http_error, "not found")
+    ///     .unwrap()
+    ///     .with_property("status", 404)
+    ///     .unwrap();
+    /// ctx.globals().set("e", exception).unwrap();
+    /// assert!(ctx.eval::<bool,_>("e instanceof HttpError && e.status === 404").unwrap());
+    /// # })
+    /// ```
+    pub fn from_constructor(constructor: &Constructor<'js>, message: &str) -> Result<Self> {
+        let proto: Object = constructor.get(PredefinedAtom::Prototype)?;
+        Self::from_prototype(&proto, message)
+    }
+
+    /// Sets a property on the exception, returning the exception for chaining.
+    pub fn with_property<K, V>(self, key: K, value: V) -> Result<Self>
+    where
+        K: IntoAtom<'js>,
+        V: IntoJs<'js>,
+    {
+        self.set(key, value)?;
+        Ok(self)
+    }
+
     /// Returns the message of the error.
     ///
     /// Same as retrieving `error.message` in JavaScript.
@@ -86,6 +132,15 @@ impl<'js> Exception<'js> {
             .map(|x| x.0)
     }
 
+    /// Returns the error stack parsed into separate frames, innermost frame first.
+    ///
+    /// Returns an empty list if the error has no stack.
+    pub fn stack_frames(&self) -> Vec<StackFrame> {
+        self.stack()
+            .map(|stack| stack.lines().filter_map(StackFrame::parse).collect())
+            .unwrap_or_default()
+    }
+
     /// Throws a new generic error.
     ///
     /// Equivalent to:
@@ -213,6 +268,70 @@ impl<'js> Exception<'js> {
     }
 }
 
+/// A single frame of an error stack, see [`Exception::stack_frames`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StackFrame {
+    /// The name of the function, `<anonymous>` for anonymous functions.
+    pub function: Option<String>,
+    /// The file name, `None` for native functions.
+    pub file: Option<String>,
+    /// The 1-based line number if known.
+    pub line: Option<u32>,
+    /// The 1-based column number if known.
+    pub column: Option<u32>,
+}
+
+impl StackFrame {
+    /// Parse a single line of a stack, like `    at foo (file.js:2:13)`.
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        let line = line.strip_prefix("at ").unwrap_or(line);
+        if line.is_empty() {
+            return None;
+        }
+
+        let (function, location) = match line.strip_suffix(')').and_then(|x| x.rsplit_once(" (")) {
+            Some((function, location)) => (Some(function.to_string()), location),
+            None => (None, line),
+        };
+
+        let mut frame = StackFrame {
+            function,
+            file: None,
+            line: None,
+            column: None,
+        };
+        if location == "native" {
+            return Some(frame);
+        }
+
+        let mut file = location;
+        let mut numbers = Vec::new();
+        while numbers.len() < 2 {
+            match file.rsplit_once(':') {
+                Some((rest, number)) => match number.parse::<u32>() {
+                    Ok(number) => {
+                        numbers.push(number);
+                        file = rest;
+                    }
+                    Err(_) => break,
+                },
+                None => break,
+            }
+        }
+        match numbers[..] {
+            [column, line] => {
+                frame.line = Some(line);
+                frame.column = Some(column);
+            }
+            [line] => frame.line = Some(line),
+            _ => {}
+        }
+        frame.file = Some(file.to_string());
+        Some(frame)
+    }
+}
+
 impl fmt::Display for Exception<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         "Error:".fmt(f)?;
@@ -227,3 +346,98 @@ impl fmt::Display for Exception<'_> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::StackFrame;
+    use crate::{
+        test_with, value::Constructor, CatchResultExt, CaughtError, Ctx, Exception, Function,
+        Module, Result,
+    };
+
+    #[test]
+    fn throw_custom_error() {
+        test_with(|ctx| {
+            ctx.eval::<(), _>("globalThis.HttpError = class HttpError extends Error {}")
+                .unwrap();
+            let func = Function::new(ctx.clone(), |ctx: Ctx, status: i32| -> Result<()> {
+                let http_error: Constructor = ctx.globals().get("HttpError")?;
+                Err(Exception::from_constructor(&http_error, "request failed")?
+                    .with_property("status", status)?
+                    .throw())
+            })
+            .unwrap();
+            ctx.globals().set("request", func).unwrap();
+
+            let res: String = ctx
+                .eval(
+                    r#"
+                    try {
+                        request(404);
+                    } catch(e) {
+                        [e instanceof HttpError, e instanceof Error, e.status, e.message].join()
+                    }
+                "#,
+                )
+                .catch(&ctx)
+                .unwrap();
+            assert_eq!(res, "true,true,404,request failed");
+        })
+    }
+
+    #[test]
+    fn stack_frames() {
+        test_with(|ctx| {
+            let source = r#"
+function thrower() {
+    throw new Error("oops");
+}
+[1].forEach(thrower);
+"#;
+            let err = Module::evaluate(ctx.clone(), "named.js", source)
+                .and_then(|promise| promise.finish::<()>())
+                .catch(&ctx)
+                .unwrap_err();
+            let CaughtError::Exception(err) = err else {
+                panic!("expected an exception");
+            };
+            assert!(err.stack().is_some());
+
+            let frames = err.stack_frames();
+            assert_eq!(
+                frames[0],
+                StackFrame {
+                    function: Some("thrower".into()),
+                    file: Some("named.js".into()),
+                    line: Some(3),
+                    column: Some(15),
+                }
+            );
+            assert_eq!(
+                frames[1],
+                StackFrame {
+                    function: Some("forEach".into()),
+                    file: None,
+                    line: None,
+                    column: None,
+                }
+            );
+        })
+    }
+
+    #[test]
+    fn parse_stack_frame() {
+        let frame = StackFrame::parse("    at some_file.js").unwrap();
+        assert_eq!(frame.function, None);
+        assert_eq!(frame.file.as_deref(), Some("some_file.js"));
+        assert_eq!(frame.line, None);
+
+        let frame = StackFrame::parse("at foo (C:\\dir\\file.js:4)").unwrap();
+        assert_eq!(frame.function.as_deref(), Some("foo"));
+        assert_eq!(frame.file.as_deref(), Some("C:\\dir\\file.js"));
+        assert_eq!(frame.line, Some(4));
+        assert_eq!(frame.column, None);
+
+        assert_eq!(StackFrame::parse("   "), None);
+    }
+}
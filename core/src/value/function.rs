@@ -4,7 +4,7 @@ use crate::{
     atom::PredefinedAtom,
     class::{Class, JsClass},
     function::ffi::RustFunc,
-    qjs, Ctx, Error, FromJs, IntoJs, Object, Result, Value,
+    qjs, Ctx, Error, FromJs, IntoJs, Object, Result, StdString, Value,
 };
 
 mod args;
@@ -18,7 +18,7 @@ pub use ffi::RustFunction;
 pub use params::{FromParam, FromParams, ParamRequirement, Params, ParamsAccessor};
 #[cfg(feature = "futures")]
 pub use types::Async;
-pub use types::{Exhaustive, Flat, Func, FuncArg, MutFn, Null, OnceFn, Opt, Rest, This};
+pub use types::{Exhaustive, Flat, Func, FuncArg, MutFn, NewTarget, Null, OnceFn, Opt, Rest, This};
 
 /// A trait for converting a Rust function to a JavaScript function.
 pub trait IntoJsFunc<'js, P> {
@@ -57,6 +57,19 @@ impl<'js> Function<'js> {
         Function(cls.into_inner()).with_length(F::param_requirements().min())
     }
 
+    /// Create a new function from a Rust function with the given `length` property.
+    ///
+    /// By default the `length` of a function is the number of its required parameters, so
+    /// functions taking [`Rest`] or [`Opt`] parameters often report a smaller `length` than the
+    /// number of arguments they expect. The length is only advertised, it doesn't change which
+    /// arguments the function accepts.
+    pub fn new_arity<P, F>(ctx: Ctx<'js>, length: usize, f: F) -> Result<Self>
+    where
+        F: IntoJsFunc<'js, P> + 'js,
+    {
+        Self::new(ctx, f)?.with_length(length)
+    }
+
     /// Call the function with given arguments.
     pub fn call<A, R>(&self, args: A) -> Result<R>
     where
@@ -147,6 +160,74 @@ impl<'js> Function<'js> {
         Ok(self)
     }
 
+    /// Returns the `length` property of the function, i.e. the number of parameters it expects.
+    pub fn length(&self) -> Result<usize> {
+        self.0.get(PredefinedAtom::Length)
+    }
+
+    /// Returns whether this is an async function or an async generator function.
+    ///
+    /// This checks the class of the function object, so it is not affected by changes to the
+    /// function's prototype.
+    pub fn is_async_function(&self) -> bool {
+        matches!(
+            self.0.builtin_class_ids(),
+            Some((ids, id)) if id == ids.async_function || id == ids.async_generator_function
+        )
+    }
+
+    /// Returns whether this is a generator function or an async generator function.
+    ///
+    /// Like [`Function::is_async_function`] this checks the class of the function object.
+    pub fn is_generator_function(&self) -> bool {
+        matches!(
+            self.0.builtin_class_ids(),
+            Some((ids, id)) if id == ids.generator_function || id == ids.async_generator_function
+        )
+    }
+
+    /// Create a new function with a fixed `this` value and leading arguments, like
+    /// `Function.prototype.bind`.
+    ///
+    /// The `this` value the returned function is called with is ignored and the bound arguments
+    /// are prepended to the arguments given at call time. Unlike functions returned by
+    /// `Function.prototype.bind` the returned function is not a constructor.
+    pub fn bind<A>(&self, this: This<Value<'js>>, args: A) -> Result<Function<'js>>
+    where
+        A: IntoArgs<'js>,
+    {
+        let ctx = self.ctx().clone();
+        let mut bound_args = Args::new(ctx.clone(), args.num_args());
+        args.into_args(&mut bound_args)?;
+        let bound_args = bound_args.take_args();
+
+        let name: Option<StdString> = self.0.get(PredefinedAtom::Name)?;
+        let length = self.length().unwrap_or(0).saturating_sub(bound_args.len());
+
+        let mut data = Vec::with_capacity(bound_args.len() + 2);
+        data.push(self.0.as_js_value());
+        data.push(this.0.as_js_value());
+        data.extend(bound_args.iter().map(Value::as_js_value));
+
+        // The engine keeps its own references to the data values and marks them during garbage
+        // collection, so the bound function doesn't keep its target alive through a cycle.
+        let bound = unsafe {
+            let val = qjs::JS_NewCFunctionData(
+                ctx.as_ptr(),
+                Some(call_bound),
+                0,
+                bound_args.len() as _,
+                data.len() as _,
+                data.as_mut_ptr(),
+            );
+            let val = ctx.handle_exception(val)?;
+            Function(Object(Value::from_js_value(ctx, val)))
+        };
+        bound
+            .with_name(format!("bound {}", name.unwrap_or_default()))?
+            .with_length(length)
+    }
+
     /// Returns the prototype which all JavaScript function by default have as its prototype, i.e.
     /// `Function.prototype`.
     pub fn prototype(ctx: Ctx<'js>) -> Object<'js> {
@@ -223,6 +304,7 @@ impl<'js> Constructor<'js> {
         });
         let func = Function(Class::instance(ctx.clone(), RustFunction(func))?.into_inner())
             .with_name(C::NAME)?
+            .with_length(F::param_requirements().min())?
             .with_constructor(true);
         unsafe {
             qjs::JS_SetConstructor(
@@ -264,6 +346,7 @@ impl<'js> Constructor<'js> {
             Ok(res)
         });
         let func = Function(Class::instance(ctx.clone(), RustFunction(func))?.into_inner())
+            .with_length(F::param_requirements().min())?
             .with_constructor(true);
         unsafe {
             qjs::JS_SetConstructor(ctx.as_ptr(), func.as_js_value(), prototype.as_js_value())
@@ -271,6 +354,18 @@ impl<'js> Constructor<'js> {
         Ok(Constructor(func))
     }
 
+    /// Set the `name` property of this constructor and then return self.
+    pub fn with_name<S: AsRef<str>>(self, name: S) -> Result<Self> {
+        self.0.set_name(name)?;
+        Ok(self)
+    }
+
+    /// Set the `length` property of this constructor and then return self.
+    pub fn with_length(self, len: usize) -> Result<Self> {
+        self.0.set_length(len)?;
+        Ok(self)
+    }
+
     /// Call the constructor as a constructor.
     ///
     /// Equivalent to calling any constructor function with the new keyword.
@@ -297,11 +392,127 @@ impl<'js> Constructor<'js> {
     }
 }
 
+/// The native side of functions created by [`Function::bind`].
+///
+/// `data` holds the target function, the bound `this` value and the bound arguments, in that
+/// order. `magic` is the number of bound arguments.
+unsafe extern "C" fn call_bound(
+    ctx: *mut qjs::JSContext,
+    _this: qjs::JSValue,
+    argc: qjs::c_int,
+    argv: *mut qjs::JSValue,
+    magic: qjs::c_int,
+    data: *mut qjs::JSValue,
+) -> qjs::JSValue {
+    let bound = std::slice::from_raw_parts(data.add(2), magic as usize);
+    let mut args = Vec::with_capacity(bound.len() + argc as usize);
+    args.extend_from_slice(bound);
+    if argc > 0 {
+        args.extend_from_slice(std::slice::from_raw_parts(argv, argc as usize));
+    }
+    qjs::JS_Call(ctx, *data, *data.add(1), args.len() as _, args.as_mut_ptr())
+}
+
 #[cfg(test)]
 mod test {
     use crate::{prelude::*, *};
     use approx::assert_abs_diff_eq as assert_approx_eq;
 
+    #[test]
+    fn fn_pointer_into_js() {
+        fn add(a: i32, b: i32) -> i32 {
+            a + b
+        }
+
+        test_with(|ctx| {
+            let globals = ctx.globals();
+            globals
+                .set("inc", (|a: i32| a + 1) as fn(i32) -> i32)
+                .unwrap();
+            globals.set("add", add as fn(_, _) -> _).unwrap();
+            globals.set("answer", (|| 42) as fn() -> i32).unwrap();
+            let res: Vec<i32> = ctx
+                .eval("[inc(1), add(2, 3), answer(), add.length]")
+                .unwrap();
+            assert_eq!(res, [2, 5, 42, 2]);
+        })
+    }
+
+    #[test]
+    fn new_arity() {
+        test_with(|ctx| {
+            let func =
+                Function::new_arity(ctx.clone(), 3, |args: Rest<i32>| args.0.iter().sum::<i32>())
+                    .unwrap();
+            assert_eq!(func.length().unwrap(), 3);
+            ctx.globals().set("sum", func).unwrap();
+            let res: Vec<i32> = ctx
+                .eval("[sum.length, sum(), sum(1, 2), sum(1, 2, 3, 4)]")
+                .unwrap();
+            assert_eq!(res, [3, 0, 3, 10]);
+        })
+    }
+
+    #[test]
+    fn bind() {
+        test_with(|ctx| {
+            let f: Function = ctx
+                .eval("(function f(a, b, c) { return [this.name, a, b, c].join(' ') })")
+                .unwrap();
+            let this: Value = ctx.eval("({ name: 'bound' })").unwrap();
+            ctx.eval::<(), _>("Function.prototype.bind = () => { throw new Error('changed') }")
+                .unwrap();
+            let bound = f.bind(This(this), (1, 2)).unwrap();
+            assert_eq!(bound.get::<_, StdString>("name").unwrap(), "bound f");
+            assert_eq!(bound.length().unwrap(), 1);
+
+            let res: StdString = bound.call((3,)).unwrap();
+            assert_eq!(res, "bound 1 2 3");
+
+            ctx.globals().set("bound", bound).unwrap();
+            let res: StdString = ctx.eval("bound.call({ name: 'other' }, 'x')").unwrap();
+            assert_eq!(res, "bound 1 2 x");
+        })
+    }
+
+    #[test]
+    fn reflection() {
+        test_with(|ctx| {
+            let f: Function = ctx.eval("(a, b) => {}").unwrap();
+            assert_eq!(f.length().unwrap(), 2);
+            assert!(!f.is_async_function());
+            assert!(!f.is_generator_function());
+
+            let f: Function = ctx.eval("async () => {}").unwrap();
+            assert_eq!(f.length().unwrap(), 0);
+            assert!(f.is_async_function());
+            assert!(!f.is_generator_function());
+
+            let f: Function = ctx.eval("(function* (a) {})").unwrap();
+            assert_eq!(f.length().unwrap(), 1);
+            assert!(!f.is_async_function());
+            assert!(f.is_generator_function());
+
+            let f: Function = ctx.eval("(async function* () {})").unwrap();
+            assert!(f.is_async_function());
+            assert!(f.is_generator_function());
+
+            let f = Function::new(ctx.clone(), |_a: i32, _b: i32, _c: i32| {}).unwrap();
+            assert_eq!(f.length().unwrap(), 3);
+            assert!(!f.is_async_function());
+
+            let f: Function = ctx
+                .eval("Object.setPrototypeOf(() => {}, Object.getPrototypeOf(async () => {}))")
+                .unwrap();
+            assert!(!f.is_async_function());
+            let f: Function = ctx
+                .eval("Object.setPrototypeOf(async function* () {}, Function.prototype)")
+                .unwrap();
+            assert!(f.is_async_function());
+            assert!(f.is_generator_function());
+        })
+    }
+
     #[test]
     fn call_js_fn_with_no_args_and_no_return() {
         test_with(|ctx| {
@@ -108,6 +108,19 @@ impl<'js> Args<'js> {
         }
     }
 
+    /// Remove all arguments from the list, returning them as values.
+    pub(crate) fn take_args(&mut self) -> Vec<Value<'js>> {
+        let values = unsafe { std::slice::from_raw_parts(self.as_ptr(), self.len()) }
+            .iter()
+            .map(|v| unsafe { Value::from_js_value(self.ctx.clone(), *v) })
+            .collect();
+        match self.args {
+            ArgsSlice::Stack { ref mut offset, .. } => *offset = 0,
+            ArgsSlice::Heap(ref mut h) => h.clear(),
+        }
+        values
+    }
+
     /// The number of arguments currently in the list.
     fn len(&self) -> usize {
         match self.args {
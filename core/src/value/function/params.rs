@@ -1,5 +1,5 @@
 use crate::{
-    function::{Exhaustive, Flat, FuncArg, Opt, Rest, This},
+    function::{Exhaustive, Flat, FuncArg, NewTarget, Opt, Rest, This},
     qjs, Ctx, FromJs, Result, Value,
 };
 use std::slice;
@@ -23,7 +23,7 @@ impl<'a, 'js> Params<'a, 'js> {
         this: qjs::JSValue,
         argc: qjs::c_int,
         argv: *mut qjs::JSValue,
-        _flags: qjs::c_int,
+        flags: qjs::c_int,
     ) -> Self {
         let args = if argv.is_null() {
             assert_eq!(
@@ -41,7 +41,7 @@ impl<'a, 'js> Params<'a, 'js> {
             function,
             this,
             args,
-            is_constructor: false,
+            is_constructor: flags & qjs::JS_CALL_FLAG_CONSTRUCTOR as qjs::c_int != 0,
         }
     }
 
@@ -132,6 +132,11 @@ impl<'a, 'js> ParamsAccessor<'a, 'js> {
         self.params.function()
     }
 
+    /// Returns if the function is called as a constructor.
+    pub fn is_constructor(&self) -> bool {
+        self.params.is_constructor()
+    }
+
     /// Returns the next arguments.
     ///
     /// Each call to this function returns a different argument
@@ -295,6 +300,20 @@ impl<'js, T: FromJs<'js>> FromParam<'js> for This<T> {
     }
 }
 
+impl<'js> FromParam<'js> for NewTarget<'js> {
+    fn param_requirement() -> ParamRequirement {
+        ParamRequirement::any()
+    }
+
+    fn from_param<'a>(params: &mut ParamsAccessor<'a, 'js>) -> Result<Self> {
+        if params.is_constructor() {
+            Ok(NewTarget(params.this().into_constructor()))
+        } else {
+            Ok(NewTarget(None))
+        }
+    }
+}
+
 impl<'js, T: FromJs<'js>> FromParam<'js> for FuncArg<T> {
     fn param_requirement() -> ParamRequirement {
         ParamRequirement::any()
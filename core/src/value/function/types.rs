@@ -4,11 +4,14 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use crate::{Ctx, Function, IntoJs, Result, Value};
+use crate::{function::Constructor, Ctx, Function, IntoJs, Result, Value};
 
-use super::IntoJsFunc;
+use super::{FromParams, IntoJsFunc};
 
 /// Helper type to implement [`IntoJsFunc`] for closure by constraining arguments.
+///
+/// Function pointers, like `fn(i32) -> i32`, convert into JavaScript functions without this
+/// wrapper, so closures which don't capture anything can be cast into one instead.
 pub struct Func<T, P>(T, PhantomData<P>);
 
 impl<'js, T, P> Func<T, P>
@@ -39,12 +42,48 @@ where
     }
 }
 
+// Closures can't be converted directly since a blanket implementation for `Fn` traits would leave
+// the argument types unconstrained. Function pointers name their argument types, so closures
+// which don't capture anything can be cast into one instead of being wrapped in `Func`:
+// `obj.set("add", (|a: i32, b: i32| a + b) as fn(i32, i32) -> i32)`.
+macro_rules! impl_fn_pointer_into_js {
+    ($($t:ident),*$(,)?) => {
+        impl<'js, R $(,$t)*> IntoJs<'js> for fn($($t),*) -> R
+        where
+            ($($t,)*): FromParams<'js> + 'js,
+            R: IntoJs<'js> + 'js,
+        {
+            fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+                Function::new(ctx.clone(), self).map(Function::into_value)
+            }
+        }
+    };
+}
+
+impl_fn_pointer_into_js!();
+impl_fn_pointer_into_js!(A);
+impl_fn_pointer_into_js!(A, B);
+impl_fn_pointer_into_js!(A, B, C);
+impl_fn_pointer_into_js!(A, B, C, D);
+impl_fn_pointer_into_js!(A, B, C, D, E);
+impl_fn_pointer_into_js!(A, B, C, D, E, F);
+impl_fn_pointer_into_js!(A, B, C, D, E, F, G);
+impl_fn_pointer_into_js!(A, B, C, D, E, F, G, H);
+
 /// helper type for working setting and retrieving `this` values.
 pub struct This<T>(pub T);
 
 /// helper type for retrieving function object on which a function is called..
 pub struct FuncArg<T>(pub T);
 
+/// Helper type for retrieving the `new.target` of a call.
+///
+/// Contains the constructor on which `new` was called, which for a class extended in JavaScript
+/// is the constructor of the subclass. Is `None` if the function was not called as a constructor.
+///
+/// Note that during a constructor call [`This`] also refers to `new.target`.
+pub struct NewTarget<'js>(pub Option<Constructor<'js>>);
+
 /// Helper type for optional parameters.
 pub struct Opt<T>(pub Option<T>);
 
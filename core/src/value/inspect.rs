@@ -0,0 +1,357 @@
+use crate::{
+    atom::PredefinedAtom, qjs, Array, Atom, Coerced, Ctx, Filter, Object, Result, Type, Value,
+};
+use std::{fmt::Write, string::String as StdString};
+
+/// Options for [`Value::inspect`].
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct InspectOptions {
+    /// The number of nested objects which are printed before an object is abbreviated to
+    /// its type, like `[Object]`.
+    pub depth: usize,
+}
+
+impl Default for InspectOptions {
+    fn default() -> Self {
+        InspectOptions { depth: 2 }
+    }
+}
+
+impl<'js> Value<'js> {
+    /// Returns a human readable representation of the value, similar to Node's `util.inspect`.
+    ///
+    /// Objects are printed as `{ key: value }`, arrays as `[ value ]`, and `Map`, `Set` and
+    /// `Date` objects get a type tag. Objects nested deeper than [`InspectOptions::depth`] are
+    /// abbreviated and references to an object which is already being printed are replaced by
+    /// `[Circular]`.
+    ///
+    /// ```
+    /// # use rquickjs::{Runtime, Context, Value, InspectOptions};
+    /// # let rt = Runtime::new().unwrap();
+    /// # let ctx = Context::full(&rt).unwrap();
+    /// # ctx.with(|ctx| {
+    /// let value: Value = ctx.eval("({ a: [1, 'two'], b: new Map([[1, 2]]) })").unwrap();
+    /// assert_eq!(
+    ///     value.inspect(InspectOptions::default()).unwrap(),
+    ///     "{ a: [ 1, 'two' ], b: Map(1) { 1 => 2 } }"
+    /// );
+    /// # });
+    /// ```
+    pub fn inspect(&self, options: InspectOptions) -> Result<StdString> {
+        let mut inspector = Inspector {
+            ctx: self.ctx.clone(),
+            options,
+            seen: Vec::new(),
+            out: StdString::new(),
+        };
+        inspector.value(self, 0)?;
+        Ok(inspector.out)
+    }
+}
+
+struct Inspector<'js> {
+    ctx: Ctx<'js>,
+    options: InspectOptions,
+    /// The objects currently being printed, used to detect cycles.
+    seen: Vec<Object<'js>>,
+    out: StdString,
+}
+
+impl<'js> Inspector<'js> {
+    fn value(&mut self, value: &Value<'js>, depth: usize) -> Result<()> {
+        match value.type_of() {
+            Type::String => {
+                let s: StdString = value.get()?;
+                self.string(&s);
+            }
+            Type::Symbol => {
+                let desc = match value.as_symbol().unwrap().description_string()? {
+                    Some(desc) => desc.to_string()?,
+                    None => StdString::new(),
+                };
+                write!(self.out, "Symbol({})", desc).unwrap();
+            }
+            Type::BigInt => {
+                let s: Coerced<StdString> = value.get()?;
+                write!(self.out, "{}n", s.0).unwrap();
+            }
+            Type::Function | Type::Constructor => {
+                let name: Option<StdString> = value.as_object().unwrap().get("name")?;
+                match name.filter(|x| !x.is_empty()) {
+                    Some(name) => write!(self.out, "[Function: {}]", name).unwrap(),
+                    None => self.out.push_str("[Function (anonymous)]"),
+                }
+            }
+            Type::Array | Type::Object | Type::Exception | Type::Promise => {
+                self.object(value.as_object().unwrap(), depth)?;
+            }
+            _ => {
+                let s: Coerced<StdString> = value.get()?;
+                self.out.push_str(&s.0);
+            }
+        }
+        Ok(())
+    }
+
+    fn object(&mut self, obj: &Object<'js>, depth: usize) -> Result<()> {
+        if self.seen.contains(obj) {
+            self.out.push_str("[Circular]");
+            return Ok(());
+        }
+
+        if obj.is_error() {
+            let s: StdString = obj.call_method("toString", ())?;
+            write!(self.out, "[{}]", s).unwrap();
+            return Ok(());
+        }
+        if obj.as_value().is_date() {
+            let s: StdString = obj.call_method("toISOString", ())?;
+            self.out.push_str(&s);
+            return Ok(());
+        }
+
+        let name = constructor_name(obj)?;
+        if depth > self.options.depth {
+            write!(self.out, "[{}]", name.as_deref().unwrap_or("Object")).unwrap();
+            return Ok(());
+        }
+
+        self.seen.push(obj.clone());
+        if let Some(array) = obj.as_array() {
+            self.array(array, depth)?;
+        } else if obj.as_value().is_map() {
+            self.collection(obj, name.as_deref().unwrap_or_default(), true, depth)?;
+        } else if obj.as_value().is_set() {
+            self.collection(obj, name.as_deref().unwrap_or_default(), false, depth)?;
+        } else {
+            self.plain(obj, name.as_deref(), depth)?;
+        }
+        self.seen.pop();
+        Ok(())
+    }
+
+    fn array(&mut self, array: &Array<'js>, depth: usize) -> Result<()> {
+        if array.is_empty() {
+            self.out.push_str("[]");
+            return Ok(());
+        }
+        self.out.push_str("[ ");
+        for (idx, value) in array.iter::<Value>().enumerate() {
+            if idx != 0 {
+                self.out.push_str(", ");
+            }
+            self.value(&value?, depth + 1)?;
+        }
+        self.out.push_str(" ]");
+        Ok(())
+    }
+
+    fn collection(
+        &mut self,
+        obj: &Object<'js>,
+        name: &str,
+        is_map: bool,
+        depth: usize,
+    ) -> Result<()> {
+        let size: usize = obj.get("size")?;
+        write!(self.out, "{}({}) ", name, size).unwrap();
+        if size == 0 {
+            self.out.push_str("{}");
+            return Ok(());
+        }
+
+        // Iterating the entries of a map yields `[key, value]` pairs, the values of a set are
+        // printed directly.
+        let iter: Object = obj.call_method(if is_map { "entries" } else { "values" }, ())?;
+        self.out.push_str("{ ");
+        for idx in 0.. {
+            let res: Object = iter.call_method("next", ())?;
+            if res.get(PredefinedAtom::Done)? {
+                break;
+            }
+            if idx != 0 {
+                self.out.push_str(", ");
+            }
+            let entry: Value = res.get(PredefinedAtom::Value)?;
+            match entry.as_array().filter(|_| is_map) {
+                Some(pair) => {
+                    self.value(&pair.get(0)?, depth + 1)?;
+                    self.out.push_str(" => ");
+                    self.value(&pair.get(1)?, depth + 1)?;
+                }
+                None => self.value(&entry, depth + 1)?,
+            }
+        }
+        self.out.push_str(" }");
+        Ok(())
+    }
+
+    fn plain(&mut self, obj: &Object<'js>, name: Option<&str>, depth: usize) -> Result<()> {
+        match name {
+            Some("Object") => {}
+            Some(name) => write!(self.out, "{} ", name).unwrap(),
+            None => self.out.push_str("[Object: null prototype] "),
+        }
+
+        let filter = Filter::new().string().symbol().enum_only();
+        let mut props = obj.own_props::<Atom, Value>(filter).peekable();
+        if props.peek().is_none() {
+            self.out.push_str("{}");
+            return Ok(());
+        }
+        self.out.push_str("{ ");
+        for (idx, prop) in props.enumerate() {
+            if idx != 0 {
+                self.out.push_str(", ");
+            }
+            let (key, value) = prop?;
+            self.key(&key)?;
+            self.out.push_str(": ");
+            self.value(&value, depth + 1)?;
+        }
+        self.out.push_str(" }");
+        Ok(())
+    }
+
+    fn key(&mut self, key: &Atom<'js>) -> Result<()> {
+        // `Atom::to_value` always returns a string, symbols need `JS_AtomToValue`.
+        let key = unsafe {
+            let value = qjs::JS_AtomToValue(self.ctx.as_ptr(), key.atom);
+            let value = self.ctx.handle_exception(value)?;
+            Value::from_js_value(self.ctx.clone(), value)
+        };
+        if key.is_symbol() {
+            self.out.push('[');
+            self.value(&key, 0)?;
+            self.out.push(']');
+            return Ok(());
+        }
+
+        let key: StdString = key.get()?;
+        let mut chars = key.chars();
+        let is_ident = matches!(chars.next(), Some(c) if c.is_alphabetic() || c == '_' || c == '$')
+            && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+        if is_ident || key.parse::<u32>().is_ok() {
+            self.out.push_str(&key);
+        } else {
+            self.string(&key);
+        }
+        Ok(())
+    }
+
+    fn string(&mut self, s: &str) {
+        self.out.push('\'');
+        for c in s.chars() {
+            match c {
+                '\'' => self.out.push_str("\\'"),
+                '\\' => self.out.push_str("\\\\"),
+                '\n' => self.out.push_str("\\n"),
+                '\r' => self.out.push_str("\\r"),
+                '\t' => self.out.push_str("\\t"),
+                c => self.out.push(c),
+            }
+        }
+        self.out.push('\'');
+    }
+}
+
+/// Returns the name of the constructor of an object, or `None` for objects without a prototype.
+fn constructor_name<'js>(obj: &Object<'js>) -> Result<Option<StdString>> {
+    let Some(proto) = obj.get_prototype() else {
+        return Ok(None);
+    };
+    let name = proto
+        .get::<_, Option<Object>>("constructor")?
+        .map(|ctor| ctor.get::<_, Option<StdString>>("name"))
+        .transpose()?
+        .flatten()
+        .filter(|x| !x.is_empty());
+    Ok(Some(name.unwrap_or_else(|| "Object".into())))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{value::InspectOptions, *};
+
+    #[test]
+    fn inspect_primitives() {
+        test_with(|ctx| {
+            let inspect = |src: &str| {
+                let value: Value = ctx.eval(src).unwrap();
+                value.inspect(InspectOptions::default()).unwrap()
+            };
+            assert_eq!(inspect("undefined"), "undefined");
+            assert_eq!(inspect("null"), "null");
+            assert_eq!(inspect("1.5"), "1.5");
+            assert_eq!(inspect("10n"), "10n");
+            assert_eq!(inspect("'it\\'s'"), "'it\\'s'");
+            assert_eq!(inspect("Symbol('foo')"), "Symbol(foo)");
+            assert_eq!(inspect("(function foo(){})"), "[Function: foo]");
+            assert_eq!(inspect("new Set([1, 'a'])"), "Set(2) { 1, 'a' }");
+            assert_eq!(inspect("new Date(0)"), "1970-01-01T00:00:00.000Z");
+            assert_eq!(inspect("new TypeError('bad')"), "[TypeError: bad]");
+            assert_eq!(inspect("class Foo { x = 1 }; new Foo()"), "Foo { x: 1 }");
+            assert_eq!(
+                inspect("({ [Symbol('key')]: 1, 'a b': 2 })"),
+                "{ 'a b': 2, [Symbol(key)]: 1 }"
+            );
+            assert_eq!(
+                inspect("Object.create(null)"),
+                "[Object: null prototype] {}"
+            );
+        })
+    }
+
+    #[test]
+    fn inspect_nested_cycle() {
+        test_with(|ctx| {
+            let value: Value = ctx
+                .eval(
+                    r#"
+                    const a = { name: "a", 'some-key': [1, 2], nested: { deep: { deeper: { x: 1 } } } };
+                    a.self = a;
+                    a.nested.parent = a;
+                    a
+                "#,
+                )
+                .unwrap();
+            assert_eq!(
+                value.inspect(InspectOptions::default()).unwrap(),
+                "{ name: 'a', 'some-key': [ 1, 2 ], nested: { deep: { deeper: [Object] }, parent: [Circular] }, self: [Circular] }"
+            );
+
+            let options = InspectOptions {
+                depth: 0,
+                ..InspectOptions::default()
+            };
+            assert_eq!(
+                value.inspect(options).unwrap(),
+                "{ name: 'a', 'some-key': [Array], nested: [Object], self: [Circular] }"
+            );
+        })
+    }
+
+    #[test]
+    fn inspect_shadowed_globals() {
+        test_with(|ctx| {
+            let value: Value = ctx
+                .eval(
+                    r#"
+                    const values = [new Map([[1, 2]]), new Set([3]), new Date(0)];
+                    globalThis.Map = function() {};
+                    globalThis.Set = function() {};
+                    globalThis.Date = Object;
+                    Array.from = () => { throw new Error("Array.from used") };
+                    values.push(Object.create(values[0]));
+                    values
+                "#,
+                )
+                .unwrap();
+            assert_eq!(
+                value.inspect(InspectOptions::default()).unwrap(),
+                "[ Map(1) { 1 => 2 }, Set(1) { 3 }, 1970-01-01T00:00:00.000Z, Map {} ]"
+            );
+        })
+    }
+}
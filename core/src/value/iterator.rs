@@ -0,0 +1,111 @@
+use crate::{
+    function::{MutFn, This},
+    Ctx, Function, IntoJs, Object, Result, Symbol, Value,
+};
+
+/// A JavaScript iterator object backed by a Rust iterator.
+///
+/// The object implements the iterator protocol and can thus be consumed with `for...of` or
+/// spread syntax. The Rust iterator is advanced lazily, each call to `next()` takes exactly one
+/// item. Items which are a Rust error are thrown from `next()`.
+///
+/// ```
+/// # use rquickjs::{Runtime, Context, Function, JsIterator};
+/// # let rt = Runtime::new().unwrap();
+/// # let ctx = Context::full(&rt).unwrap();
+/// # ctx.with(|ctx| {
+/// let range = Function::new(ctx.clone(), |ctx| JsIterator::new(ctx, 0..3)).unwrap();
+/// ctx.globals().set("range", range).unwrap();
+/// let sum: i32 = ctx.eval("let sum = 0; for (const x of range()) { sum += x }; sum").unwrap();
+/// assert_eq!(sum, 3);
+/// # });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct JsIterator<'js>(Object<'js>);
+
+impl<'js> JsIterator<'js> {
+    /// Create a new JavaScript iterator object from a Rust iterator.
+    pub fn new<I>(ctx: Ctx<'js>, iter: I) -> Result<Self>
+    where
+        I: IntoIterator + 'js,
+        I::IntoIter: 'js,
+        I::Item: IntoJs<'js>,
+    {
+        let mut iter = iter.into_iter();
+        let next = Function::new(
+            ctx.clone(),
+            MutFn::new(move |ctx: Ctx<'js>| iter_result(&ctx, iter.next())),
+        )?
+        .with_name("next")?;
+
+        let object = Object::new(ctx.clone())?;
+        object.set("next", next)?;
+        object.set(
+            Symbol::iterator(ctx.clone()).as_atom(),
+            Function::new(ctx, |this: This<Value<'js>>| this.0)?,
+        )?;
+        Ok(JsIterator(object))
+    }
+
+    /// Reference to the iterator object.
+    pub fn as_object(&self) -> &Object<'js> {
+        &self.0
+    }
+
+    /// Convert into the iterator object.
+    pub fn into_object(self) -> Object<'js> {
+        self.0
+    }
+}
+
+impl<'js> IntoJs<'js> for JsIterator<'js> {
+    fn into_js(self, _: &Ctx<'js>) -> Result<Value<'js>> {
+        Ok(self.0.into_value())
+    }
+}
+
+/// Creates an iterator result object, i.e. `{ value, done }`.
+pub(crate) fn iter_result<'js, R: IntoJs<'js>>(
+    ctx: &Ctx<'js>,
+    item: Option<R>,
+) -> Result<Object<'js>> {
+    let res = Object::new(ctx.clone())?;
+    let done = item.is_none();
+    res.set("value", item)?;
+    res.set("done", done)?;
+    Ok(res)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn spread_range() {
+        test_with(|ctx| {
+            let func = Function::new(ctx.clone(), |ctx| JsIterator::new(ctx, 0..5)).unwrap();
+            ctx.globals().set("rustIter", func).unwrap();
+            let res: Vec<i32> = ctx.eval("[...rustIter()]").unwrap();
+            assert_eq!(res, vec![0, 1, 2, 3, 4]);
+        })
+    }
+
+    #[test]
+    fn lazy_and_fallible() {
+        test_with(|ctx| {
+            let items = vec![Ok(1), Err(Error::new_from_js("number", "item"))];
+            let iter = JsIterator::new(ctx.clone(), items).unwrap();
+            ctx.globals().set("iter", iter).unwrap();
+
+            let first: i32 = ctx.eval("iter.next().value").unwrap();
+            assert_eq!(first, 1);
+            let threw: bool = ctx
+                .eval("try { iter.next(); false } catch (e) { true }")
+                .unwrap();
+            assert!(threw);
+            let done: bool = ctx.eval("iter.next().done").unwrap();
+            assert!(done);
+        })
+    }
+}
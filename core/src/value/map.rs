@@ -0,0 +1,208 @@
+//! JavaScript `Map` objects.
+
+use crate::{
+    atom::PredefinedAtom, function::Constructor, Ctx, Error, FromJs, IntoJs, Object, Result, Value,
+};
+use std::marker::PhantomData;
+
+/// Rust representation of a JavaScript `Map` object.
+///
+/// Unlike plain objects, a `Map` can use any value as key, including objects, which are compared
+/// by identity.
+///
+/// ```
+/// # use rquickjs::{Runtime, Context, Map, Object};
+/// # let rt = Runtime::new().unwrap();
+/// # let ctx = Context::full(&rt).unwrap();
+/// # ctx.with(|ctx| {
+/// let map = Map::new(ctx.clone()).unwrap();
+/// let key = Object::new(ctx.clone()).unwrap();
+/// map.set(key.clone(), "value").unwrap();
+/// assert_eq!(map.get::<_, String>(key).unwrap(), "value");
+/// assert!(!map.has(Object::new(ctx.clone()).unwrap()).unwrap());
+/// # });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Map<'js>(pub(crate) Object<'js>);
+
+impl<'js> Map<'js> {
+    /// Create a new empty `Map`.
+    pub fn new(ctx: Ctx<'js>) -> Result<Self> {
+        let constructor: Constructor = ctx.globals().get("Map")?;
+        constructor.construct(()).map(Map)
+    }
+
+    /// Returns whether the object is a `Map`, see [`Value::is_map`].
+    pub fn is_map(object: &Object<'js>) -> Result<bool> {
+        Ok(object.as_value().is_map())
+    }
+
+    /// Convert an object into a `Map`, returns `None` if the object isn't a `Map`.
+    pub fn from_object(object: Object<'js>) -> Result<Option<Self>> {
+        Ok(Self::is_map(&object)?.then_some(Map(object)))
+    }
+
+    /// Set the value for a key.
+    pub fn set<K: IntoJs<'js>, V: IntoJs<'js>>(&self, key: K, value: V) -> Result<()> {
+        self.0.call_method::<_, _, Value>("set", (key, value))?;
+        Ok(())
+    }
+
+    /// Get the value for a key, returns `undefined` if the key isn't in the map.
+    pub fn get<K: IntoJs<'js>, V: FromJs<'js>>(&self, key: K) -> Result<V> {
+        self.0.call_method("get", (key,))
+    }
+
+    /// Returns whether the map contains the key.
+    pub fn has<K: IntoJs<'js>>(&self, key: K) -> Result<bool> {
+        self.0.call_method("has", (key,))
+    }
+
+    /// Remove a key from the map, returns whether the key was in the map.
+    pub fn delete<K: IntoJs<'js>>(&self, key: K) -> Result<bool> {
+        self.0.call_method("delete", (key,))
+    }
+
+    /// Remove all entries from the map.
+    pub fn clear(&self) -> Result<()> {
+        self.0.call_method("clear", ())
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn size(&self) -> Result<usize> {
+        self.0.get("size")
+    }
+
+    /// Returns an iterator over the entries of the map in insertion order.
+    pub fn entries<K: FromJs<'js>, V: FromJs<'js>>(&self) -> Result<MapEntriesIter<'js, K, V>> {
+        let iter = self.0.call_method("entries", ())?;
+        Ok(MapEntriesIter {
+            iter: Some(iter),
+            marker: PhantomData,
+        })
+    }
+
+    /// Reference to the map object.
+    pub fn as_object(&self) -> &Object<'js> {
+        &self.0
+    }
+
+    /// Convert into the map object.
+    pub fn into_object(self) -> Object<'js> {
+        self.0
+    }
+}
+
+impl<'js> FromJs<'js> for Map<'js> {
+    fn from_js(_: &Ctx<'js>, value: Value<'js>) -> Result<Self> {
+        let type_name = value.type_name();
+        let error = || Error::new_from_js(type_name, "Map");
+        let object = value.into_object().ok_or_else(error)?;
+        Map::from_object(object)?.ok_or_else(error)
+    }
+}
+
+impl<'js> IntoJs<'js> for Map<'js> {
+    fn into_js(self, _: &Ctx<'js>) -> Result<Value<'js>> {
+        Ok(self.0.into_value())
+    }
+}
+
+/// An iterator over the entries of a [`Map`].
+pub struct MapEntriesIter<'js, K, V> {
+    iter: Option<Object<'js>>,
+    marker: PhantomData<(K, V)>,
+}
+
+impl<'js, K, V> Iterator for MapEntriesIter<'js, K, V>
+where
+    K: FromJs<'js>,
+    V: FromJs<'js>,
+{
+    type Item = Result<(K, V)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let iter = self.iter.as_ref()?;
+        let res = iter
+            .call_method::<_, _, Object>("next", ())
+            .and_then(|res| {
+                if res.get(PredefinedAtom::Done)? {
+                    return Ok(None);
+                }
+                let entry: Object = res.get(PredefinedAtom::Value)?;
+                Ok(Some((entry.get(0)?, entry.get(1)?)))
+            })
+            .transpose();
+        if !matches!(res, Some(Ok(_))) {
+            self.iter = None;
+        }
+        res
+    }
+}
+
+impl<'js, K, V> std::iter::FusedIterator for MapEntriesIter<'js, K, V>
+where
+    K: FromJs<'js>,
+    V: FromJs<'js>,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn object_keys() {
+        test_with(|ctx| {
+            let map = Map::new(ctx.clone()).unwrap();
+            let key = Object::new(ctx.clone()).unwrap();
+            map.set(key.clone(), 1).unwrap();
+            map.set("key", 2).unwrap();
+            map.set(3, "three").unwrap();
+            assert_eq!(map.size().unwrap(), 3);
+
+            assert_eq!(map.get::<_, i32>(key.clone()).unwrap(), 1);
+            assert!(map.has(key.clone()).unwrap());
+            let other = Object::new(ctx.clone()).unwrap();
+            assert!(!map.has(other.clone()).unwrap());
+            assert_eq!(map.get::<_, Option<i32>>(other).unwrap(), None);
+
+            let entries: Vec<(Value, Value)> =
+                map.entries().unwrap().collect::<Result<_>>().unwrap();
+            assert_eq!(entries.len(), 3);
+            assert_eq!(entries[0].0.as_object(), Some(&key));
+            assert_eq!(
+                entries[1].0.as_string().unwrap().to_string().unwrap(),
+                "key"
+            );
+            assert_eq!(
+                entries[2].1.as_string().unwrap().to_string().unwrap(),
+                "three"
+            );
+
+            assert!(map.delete(key.clone()).unwrap());
+            assert!(!map.delete(key).unwrap());
+            assert_eq!(map.size().unwrap(), 2);
+            map.clear().unwrap();
+            assert_eq!(map.size().unwrap(), 0);
+        })
+    }
+
+    #[test]
+    fn from_javascript() {
+        test_with(|ctx| {
+            let map: Map = ctx.eval("new Map([['a', 1], ['b', 2]])").unwrap();
+            let entries: Vec<(StdString, i32)> =
+                map.entries().unwrap().collect::<Result<_>>().unwrap();
+            assert_eq!(entries, vec![("a".into(), 1), ("b".into(), 2)]);
+
+            ctx.globals().set("map", map).unwrap();
+            let res: i32 = ctx.eval("map.get('b')").unwrap();
+            assert_eq!(res, 2);
+
+            assert!(ctx.eval::<Map, _>("({})").is_err());
+            assert!(ctx.eval::<Map, _>("1").is_err());
+        })
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::{
     ffi::{CStr, CString},
+    io::ErrorKind,
     marker::PhantomData,
     mem::MaybeUninit,
     ptr::{self, NonNull},
@@ -80,6 +81,27 @@ impl<'js> Declarations<'js> {
         unsafe { qjs::JS_AddModuleExport(self.0.ctx.as_ptr(), self.0.as_ptr(), name.as_ptr()) };
         Ok(self)
     }
+
+    /// Declare all the exports of another module as exports of this module, like
+    /// `export * from "specifier"` in JavaScript.
+    ///
+    /// The other module is loaded and evaluated when this is called, relative specifiers are
+    /// resolved against the name of this module. Like `export *` the `default` export is not
+    /// included. The values must be set with [`Exports::export_star`] with the same specifier
+    /// when this module is evaluated.
+    pub fn declare_star<S>(&self, specifier: S) -> Result<&Self>
+    where
+        S: Into<Vec<u8>>,
+    {
+        let namespace = self.0.import_namespace(specifier)?;
+        for name in namespace.keys::<String>() {
+            let name = name?;
+            if name != "default" {
+                self.declare(name)?;
+            }
+        }
+        Ok(self)
+    }
 }
 
 /// A struct used for setting the value of previously declared exporsts of a module.
@@ -111,6 +133,25 @@ impl<'js> Exports<'js> {
 
         Ok(self)
     }
+
+    /// Set the values of all the exports of another module which were declared with
+    /// [`Declarations::declare_star`].
+    ///
+    /// The values are copied from the other module when this is called, later changes to the
+    /// exports of the other module are not reflected in this module.
+    pub fn export_star<S>(&self, specifier: S) -> Result<&Self>
+    where
+        S: Into<Vec<u8>>,
+    {
+        let namespace = self.0.import_namespace(specifier)?;
+        for prop in namespace.props::<String, Value>() {
+            let (name, value) = prop?;
+            if name != "default" {
+                self.export(name, value)?;
+            }
+        }
+        Ok(self)
+    }
 }
 
 /// A marker struct used to indicate that a module is possibly not yet evaluated.
@@ -125,6 +166,9 @@ pub struct Evaluated;
 pub struct Module<'js, T = Declared> {
     ptr: NonNull<qjs::JSModuleDef>,
     ctx: Ctx<'js>,
+    /// Whether the module was evaluated through [`Module::eval`], kept by
+    /// [`Module::into_declared`].
+    evaluated: bool,
     _type_marker: PhantomData<T>,
 }
 
@@ -137,10 +181,20 @@ impl<'js, T> Module<'js, T> {
         Module {
             ptr,
             ctx,
+            evaluated: false,
             _type_marker: PhantomData,
         }
     }
 
+    /// Returns whether the module was evaluated with [`Module::eval`] or one of the functions
+    /// which evaluate a module, like [`Module::evaluate`].
+    ///
+    /// Modules which are only evaluated by JavaScript, for example because they were imported by
+    /// another module, are not tracked and return `false`.
+    pub fn is_evaluated(&self) -> bool {
+        self.evaluated
+    }
+
     unsafe extern "C" fn eval_fn<D>(
         ctx: *mut qjs::JSContext,
         ptr: *mut qjs::JSModuleDef,
@@ -162,6 +216,18 @@ impl<'js, T> Module<'js, T> {
         }
     }
 
+    /// Load and evaluate a module imported from this module, returning its namespace.
+    fn import_namespace<S: Into<Vec<u8>>>(&self, specifier: S) -> Result<Object<'js>> {
+        let specifier = CString::new(specifier)?;
+        let base_name = CString::new(self.name::<String>()?)?;
+        let promise = unsafe {
+            let res = qjs::JS_LoadModule(self.ctx.as_ptr(), base_name.as_ptr(), specifier.as_ptr());
+            let res = self.ctx.handle_exception(res)?;
+            Promise::from_js_value(self.ctx.clone(), res)
+        };
+        promise.finish()
+    }
+
     /// Returns the name of the module
     pub fn name<N>(&self) -> Result<N>
     where
@@ -274,6 +340,48 @@ impl<'js> Module<'js, Declared> {
         unsafe { Ok(Module::from_ptr(ctx, module_ptr)) }
     }
 
+    /// Load a bundle of modules written with [`Module::write_bundle`].
+    ///
+    /// All modules of the bundle are loaded before returning, so imports between modules inside
+    /// the bundle resolve to each other without requiring a module loader. The modules are
+    /// returned in the order in which they were written. Evaluating the entry module will
+    /// evaluate its dependencies as well.
+    ///
+    /// # Safety
+    /// User must ensure that bytes handed to this function contain a valid bundle.
+    pub unsafe fn load_bundle(ctx: Ctx<'js>, bytes: &[u8]) -> Result<Vec<Module<'js, Declared>>> {
+        fn read_u32(bytes: &mut &[u8]) -> Result<usize> {
+            if bytes.len() < 4 {
+                return Err(Error::Io(ErrorKind::UnexpectedEof.into()));
+            }
+            let (head, rest) = bytes.split_at(4);
+            *bytes = rest;
+            Ok(u32::from_le_bytes(head.try_into().unwrap()) as usize)
+        }
+
+        let mut bytes = bytes;
+        let count = read_u32(&mut bytes)?;
+        let mut modules = Vec::with_capacity(count);
+        for _ in 0..count {
+            let len = read_u32(&mut bytes)?;
+            if bytes.len() < len {
+                return Err(Error::Io(ErrorKind::UnexpectedEof.into()));
+            }
+            let (module, rest) = bytes.split_at(len);
+            bytes = rest;
+            modules.push(Self::load(ctx.clone(), module)?);
+        }
+        // Resolve only after every module is loaded so imports within the bundle are found among
+        // the already loaded modules.
+        for module in &modules {
+            let v = qjs::JS_MKPTR(qjs::JS_TAG_MODULE, module.as_ptr().cast());
+            if qjs::JS_ResolveModule(ctx.as_ptr(), v) < 0 {
+                return Err(ctx.raise_exception());
+            }
+        }
+        Ok(modules)
+    }
+
     /// Load a module from a raw module loading function.
     ///
     /// # Safety
@@ -297,6 +405,9 @@ impl<'js> Module<'js, Declared> {
     ///
     /// Returns the module as being evaluated and a promise which resolves when the module has finished evaluating.
     /// The return value of the promise is the JavaScript value undefined.
+    ///
+    /// Evaluating a module again returns the promise of the first evaluation, use
+    /// [`Module::eval_once`] to get an error instead.
     pub fn eval(self) -> Result<(Module<'js, Evaluated>, Promise<'js>)> {
         let ret = unsafe {
             // JS_EvalFunction `free's` the module so we should dup first
@@ -310,12 +421,25 @@ impl<'js> Module<'js, Declared> {
             Module {
                 ptr: self.ptr,
                 ctx: self.ctx,
+                evaluated: true,
                 _type_marker: PhantomData,
             },
             promise,
         ))
     }
 
+    /// Evaluate the module, like [`Module::eval`], but return
+    /// [`Error::ModuleAlreadyEvaluated`] if the module was already evaluated, see
+    /// [`Module::is_evaluated`].
+    pub fn eval_once(self) -> Result<(Module<'js, Evaluated>, Promise<'js>)> {
+        if self.is_evaluated() {
+            return Err(Error::ModuleAlreadyEvaluated {
+                name: self.name::<std::string::String>().unwrap_or_default(),
+            });
+        }
+        self.eval()
+    }
+
     /// A function for loading a Rust module from C.
     ///
     /// # Safety
@@ -406,7 +530,25 @@ impl<'js, Evaluated> Module<'js, Evaluated> {
         Ok(obj)
     }
 
+    /// Write object bytecode for multiple modules into a single bundle.
+    ///
+    /// The bundle can be loaded again with [`Module::load_bundle`]. Modules are written in the
+    /// given order, which is also the order in which they are loaded.
+    pub fn write_bundle(modules: &[Self], swap_endianess: bool) -> Result<Vec<u8>> {
+        let mut bundle = Vec::new();
+        bundle.extend_from_slice(&(modules.len() as u32).to_le_bytes());
+        for module in modules {
+            let bytecode = module.write(swap_endianess)?;
+            bundle.extend_from_slice(&(bytecode.len() as u32).to_le_bytes());
+            bundle.extend_from_slice(&bytecode);
+        }
+        Ok(bundle)
+    }
+
     /// Return the `import.meta` object of a module
+    ///
+    /// The object can be retrieved before the module is evaluated, which allows setting fields
+    /// like `url` or `resolve` which the module body can then access.
     pub fn meta(&self) -> Result<Object<'js>> {
         unsafe {
             Ok(Object::from_js_value(
@@ -417,7 +559,32 @@ impl<'js, Evaluated> Module<'js, Evaluated> {
         }
     }
 
+    /// Set a property on the `import.meta` object of a module.
+    ///
+    /// ```
+    /// # use rquickjs::{Runtime, Context, Module};
+    /// # let rt = Runtime::new().unwrap();
+    /// # let ctx = Context::full(&rt).unwrap();
+    /// # ctx.with(|ctx| {
+    /// let module = Module::declare(ctx, "main", "export const url = import.meta.url").unwrap();
+    /// module.set_meta_prop("url", "file:///main.js").unwrap();
+    /// let (module, promise) = module.eval().unwrap();
+    /// promise.finish::<()>().unwrap();
+    /// assert_eq!(module.get::<_, String>("url").unwrap(), "file:///main.js");
+    /// # });
+    /// ```
+    pub fn set_meta_prop<K, V>(&self, key: K, value: V) -> Result<()>
+    where
+        K: IntoAtom<'js>,
+        V: IntoJs<'js>,
+    {
+        self.meta()?.set(key, value)
+    }
+
     /// Returns the module namespace, an object containing all the module exported values.
+    ///
+    /// The properties of the namespace are live bindings: reading them returns the current value
+    /// of the exported variable, including changes the module made after it was evaluated.
     pub fn namespace(&self) -> Result<Object<'js>> {
         unsafe {
             let v = qjs::JS_GetModuleNamespace(self.ctx.as_ptr(), self.as_ptr());
@@ -438,10 +605,12 @@ impl<'js, Evaluated> Module<'js, Evaluated> {
     /// Change the module back to being only declared.
     ///
     /// This is always safe to do since calling eval again on an already evaluated module is safe.
+    /// [`Module::eval_once`] on the returned module returns an error.
     pub fn into_declared(self) -> Module<'js, Declared> {
         Module {
             ptr: self.ptr,
             ctx: self.ctx,
+            evaluated: self.evaluated,
             _type_marker: PhantomData,
         }
     }
@@ -494,6 +663,76 @@ mod test {
         })
     }
 
+    #[test]
+    fn eval_twice() {
+        test_with(|ctx| {
+            let module = Module::declare(
+                ctx.clone(),
+                "test",
+                "globalThis.count = (globalThis.count ?? 0) + 1;",
+            )
+            .unwrap();
+            assert!(!module.is_evaluated());
+            let (module, promise) = module.eval().unwrap();
+            promise.finish::<()>().unwrap();
+            assert!(module.is_evaluated());
+
+            let module = module.into_declared();
+            assert!(module.is_evaluated());
+            let err = module.clone().eval_once().map(|_| ()).unwrap_err();
+            assert!(matches!(err, Error::ModuleAlreadyEvaluated { ref name } if name == "test"));
+            assert_eq!(ctx.globals().get::<_, i32>("count").unwrap(), 1);
+
+            // Plain eval still allows evaluating again, which doesn't run the module twice.
+            let (_, promise) = module.eval().unwrap();
+            promise.finish::<()>().unwrap();
+            assert_eq!(ctx.globals().get::<_, i32>("count").unwrap(), 1);
+        })
+    }
+
+    #[test]
+    fn evaluated_state_not_shared_between_contexts() {
+        let rt = Runtime::new().unwrap();
+        for _ in 0..8 {
+            let ctx = Context::full(&rt).unwrap();
+            ctx.with(|ctx| {
+                let module = Module::declare(ctx.clone(), "test", "").unwrap();
+                assert!(!module.is_evaluated());
+                let (module, promise) = module.eval().unwrap();
+                promise.finish::<()>().unwrap();
+                assert!(module.is_evaluated());
+            });
+        }
+    }
+
+    #[test]
+    fn namespace_live_bindings() {
+        test_with(|ctx| {
+            let (module, promise) = Module::declare(
+                ctx.clone(),
+                "counter",
+                r#"
+                export let count = 0;
+                export function increment() {
+                    count += 1;
+                }
+                "#,
+            )
+            .unwrap()
+            .eval()
+            .unwrap();
+            promise.finish::<()>().unwrap();
+
+            let namespace = module.namespace().unwrap();
+            assert_eq!(namespace.get::<_, i32>("count").unwrap(), 0);
+            let increment: Function = namespace.get("increment").unwrap();
+            increment.call::<_, ()>(()).unwrap();
+            increment.call::<_, ()>(()).unwrap();
+            assert_eq!(namespace.get::<_, i32>("count").unwrap(), 2);
+            assert_eq!(module.get::<_, i32>("count").unwrap(), 2);
+        })
+    }
+
     #[test]
     fn import_native() {
         test_with(|ctx| {
@@ -520,6 +759,51 @@ mod test {
         })
     }
 
+    pub struct ReexportModule;
+
+    impl ModuleDef for ReexportModule {
+        fn declare(define: &Declarations) -> Result<()> {
+            define.declare_star("source_mod")?.declare("extra")?;
+            Ok(())
+        }
+
+        fn evaluate<'js>(_ctx: &Ctx<'js>, exports: &Exports<'js>) -> Result<()> {
+            exports.export_star("source_mod")?.export("extra", 3)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn import_star_reexport() {
+        test_with(|ctx| {
+            Module::declare(
+                ctx.clone(),
+                "source_mod",
+                "export const a = 1; export let b = 2; export default 'default';",
+            )
+            .unwrap();
+            Module::declare_def::<ReexportModule, _>(ctx.clone(), "reexport_mod").unwrap();
+            Module::evaluate(
+                ctx.clone(),
+                "test",
+                r#"
+                import { a, b, extra } from "reexport_mod";
+                import * as ns from "reexport_mod";
+                globalThis.values = [a, b, extra];
+                globalThis.hasDefault = "default" in ns;
+            "#,
+            )
+            .catch(&ctx)
+            .unwrap()
+            .finish::<()>()
+            .catch(&ctx)
+            .unwrap();
+            let values: Vec<i32> = ctx.globals().get("values").unwrap();
+            assert_eq!(values, vec![1, 2, 3]);
+            assert!(!ctx.globals().get::<_, bool>("hasDefault").unwrap());
+        })
+    }
+
     #[test]
     fn import_async() {
         test_with(|ctx| {
@@ -636,6 +920,68 @@ mod test {
         });
     }
 
+    #[test]
+    fn meta_before_eval() {
+        test_with(|ctx| {
+            let module = Module::declare(
+                ctx.clone(),
+                "meta",
+                r#"
+                export const foo = import.meta.foo;
+                export const resolved = import.meta.resolve("./dep.js");
+                "#,
+            )
+            .unwrap();
+            module.set_meta_prop("foo", 5).unwrap();
+            let resolve = Function::new(ctx.clone(), |specifier: StdString| {
+                specifier.replace("./", "/root/")
+            })
+            .unwrap();
+            module.meta().unwrap().set("resolve", resolve).unwrap();
+
+            let (module, promise) = module.eval().unwrap();
+            promise.finish::<()>().catch(&ctx).unwrap();
+            assert_eq!(module.get::<_, i32>("foo").unwrap(), 5);
+            assert_eq!(
+                module.get::<_, StdString>("resolved").unwrap(),
+                "/root/dep.js"
+            );
+        })
+    }
+
+    #[test]
+    fn load_bundle() {
+        let bundle = test_with(|ctx| {
+            let dep = Module::declare(ctx.clone(), "dep", "export const value = 21;").unwrap();
+            let entry = Module::declare(
+                ctx.clone(),
+                "entry",
+                r#"
+                import { value } from "dep";
+                export const doubled = value * 2;
+            "#,
+            )
+            .unwrap();
+            Module::write_bundle(&[dep, entry], false).unwrap()
+        });
+
+        test_with(|ctx| {
+            let mut modules = unsafe { Module::load_bundle(ctx.clone(), &bundle).unwrap() };
+            assert_eq!(modules.len(), 2);
+            let entry = modules.pop().unwrap();
+            assert_eq!(entry.name::<StdString>().unwrap(), "entry");
+
+            let (entry, promise) = entry.eval().unwrap();
+            promise.finish::<()>().unwrap();
+            assert_eq!(entry.get::<_, i32>("doubled").unwrap(), 42);
+        });
+
+        test_with(|ctx| {
+            let res = unsafe { Module::load_bundle(ctx, &bundle[..bundle.len() - 1]) };
+            assert!(matches!(res, Err(Error::Io(_))));
+        });
+    }
+
     #[test]
     fn from_javascript() {
         test_with(|ctx| {
@@ -1,11 +1,14 @@
 //! Module for types dealing with JS objects.
 
 use crate::{
-    convert::FromIteratorJs, qjs, Array, Atom, Ctx, FromAtom, FromJs, IntoAtom, IntoJs, Result,
-    Value,
+    convert::FromIteratorJs,
+    function::{Args, IntoArgs},
+    qjs, Array, Atom, Ctx, Error, Exception, FromAtom, FromJs, IntoAtom, IntoJs, Result, Value,
 };
 use std::{iter::FusedIterator, marker::PhantomData, mem};
 
+mod opaque;
+pub(crate) use opaque::OpaqueMap;
 mod property;
 pub use property::{Accessor, AsProperty, Property, PropertyFlags};
 
@@ -24,6 +27,28 @@ impl<'js> Object<'js> {
         })
     }
 
+    /// Create a new JavaScript object with the given object as its prototype.
+    ///
+    /// This is the same as `Object.create(proto)` in JavaScript.
+    pub fn with_prototype(ctx: Ctx<'js>, proto: &Object<'js>) -> Result<Self> {
+        Self::new_proto(ctx, proto.as_js_value())
+    }
+
+    /// Create a new JavaScript object without a prototype.
+    ///
+    /// This is the same as `Object.create(null)` in JavaScript.
+    pub fn new_null_proto(ctx: Ctx<'js>) -> Result<Self> {
+        Self::new_proto(ctx, qjs::JS_NULL)
+    }
+
+    fn new_proto(ctx: Ctx<'js>, proto: qjs::JSValueConst) -> Result<Self> {
+        Ok(unsafe {
+            let val = qjs::JS_NewObjectProto(ctx.as_ptr(), proto);
+            let val = ctx.handle_exception(val)?;
+            Object::from_js_value(ctx, val)
+        })
+    }
+
     /// Get a new value
     pub fn get<K: IntoAtom<'js>, V: FromJs<'js>>(&self, k: K) -> Result<V> {
         let atom = k.into_atom(self.ctx())?;
@@ -34,6 +59,61 @@ impl<'js> Object<'js> {
         })
     }
 
+    /// Get a value, returning `default` if the property is `undefined`.
+    ///
+    /// Inherited properties are found like with [`Object::get`]. Only a missing or `undefined`
+    /// property results in the default, a property which is `null` is converted into `V` like any
+    /// other value.
+    pub fn get_or<K: IntoAtom<'js>, V: FromJs<'js>>(&self, k: K, default: V) -> Result<V> {
+        self.get_with_default(k, || default)
+    }
+
+    /// Get a value, calling `default` if the property is `undefined`.
+    ///
+    /// Like [`Object::get_or`] but the default is only created when it is needed.
+    pub fn get_with_default<K, V, F>(&self, k: K, default: F) -> Result<V>
+    where
+        K: IntoAtom<'js>,
+        V: FromJs<'js>,
+        F: FnOnce() -> V,
+    {
+        let value: Value = self.get(k)?;
+        if value.is_undefined() {
+            Ok(default())
+        } else {
+            V::from_js(self.ctx(), value)
+        }
+    }
+
+    /// Get the value at a path of nested properties, like `obj.a.b.c` in JavaScript.
+    ///
+    /// Returns `None` if any of the values along the path, including the final value, is
+    /// `undefined` or `null`. Returns an error if an intermediate value is not an object.
+    ///
+    /// ```
+    /// # use rquickjs::{Runtime, Context, Object};
+    /// # let rt = Runtime::new().unwrap();
+    /// # let ctx = Context::full(&rt).unwrap();
+    /// # ctx.with(|ctx| {
+    /// let config: Object = ctx.eval("({ server: { port: 8080 } })").unwrap();
+    /// assert_eq!(config.get_path::<u16>(&["server", "port"]).unwrap(), Some(8080));
+    /// assert_eq!(config.get_path::<u16>(&["client", "port"]).unwrap(), None);
+    /// # });
+    /// ```
+    pub fn get_path<V: FromJs<'js>>(&self, path: &[&str]) -> Result<Option<V>> {
+        let mut value = self.0.clone();
+        for key in path {
+            if value.is_undefined() || value.is_null() {
+                return Ok(None);
+            }
+            value = Object::from_value(value)?.get(*key)?;
+        }
+        if value.is_undefined() || value.is_null() {
+            return Ok(None);
+        }
+        V::from_js(self.ctx(), value).map(Some)
+    }
+
     /// check whether the object contains a certain key.
     pub fn contains_key<K>(&self, k: K) -> Result<bool>
     where
@@ -67,6 +147,72 @@ impl<'js> Object<'js> {
         Ok(())
     }
 
+    /// Set multiple members of an object at once.
+    ///
+    /// Entries are set in order like with [`Object::set`], stopping at the first entry which
+    /// fails.
+    pub fn set_many<I, K, V>(&self, entries: I) -> Result<()>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: IntoAtom<'js>,
+        V: IntoJs<'js>,
+    {
+        for (key, value) in entries {
+            self.set(key, value)?;
+        }
+        Ok(())
+    }
+
+    /// Define a constant property on the object.
+    ///
+    /// The property is enumerable but neither writable nor configurable, so scripts can't
+    /// reassign or delete it. Assigning to it throws a `TypeError` in strict mode and is silently
+    /// ignored otherwise. Returns an error if the object already has a non-configurable property
+    /// with the key or isn't extensible.
+    pub fn define_const<K, V>(&self, key: K, value: V) -> Result<()>
+    where
+        K: IntoAtom<'js>,
+        V: IntoJs<'js>,
+    {
+        let atom = key.into_atom(self.ctx())?;
+        let val = value.into_js(self.ctx())?;
+        unsafe {
+            if qjs::JS_DefinePropertyValue(
+                self.0.ctx.as_ptr(),
+                self.0.as_js_value(),
+                atom.atom,
+                val.into_js_value(),
+                (qjs::JS_PROP_ENUMERABLE | qjs::JS_PROP_THROW) as _,
+            ) < 0
+            {
+                return Err(self.0.ctx.raise_exception());
+            }
+        }
+        Ok(())
+    }
+
+    /// Call a method of the object with the object as `this`.
+    ///
+    /// Equivalent to `obj[name](...args)` in JavaScript. Throws a `TypeError` if the property
+    /// isn't a function.
+    pub fn call_method<K, A, R>(&self, name: K, args: A) -> Result<R>
+    where
+        K: IntoAtom<'js>,
+        A: IntoArgs<'js>,
+        R: FromJs<'js>,
+    {
+        let name = name.into_atom(self.ctx())?;
+        let func = self.get::<_, Value>(name.clone())?;
+        let Some(func) = func.into_function() else {
+            let message = format!("{} is not a function", name.to_string()?);
+            return Err(Exception::throw_type(self.ctx(), &message));
+        };
+        let mut accum_args = Args::new(self.ctx().clone(), args.num_args());
+        accum_args.this(self.clone())?;
+        args.into_args(&mut accum_args)?;
+        func.call_arg(accum_args)
+    }
+
     /// Remove a member of an object
     pub fn remove<K: IntoAtom<'js>>(&self, key: K) -> Result<()> {
         let atom = key.into_atom(self.ctx())?;
@@ -107,6 +253,24 @@ impl<'js> Object<'js> {
         }
     }
 
+    /// Get the own property names of an object which match the given filter.
+    ///
+    /// ```
+    /// # use rquickjs::{Runtime, Context, Object, Atom, Filter};
+    /// # let rt = Runtime::new().unwrap();
+    /// # let ctx = Context::full(&rt).unwrap();
+    /// # ctx.with(|ctx| {
+    /// let obj: Object = ctx.eval("({ a: 1, [Symbol.iterator]: 2 })").unwrap();
+    /// let names = obj
+    ///     .own_property_names(Filter::new().string().symbol())
+    ///     .unwrap();
+    /// assert_eq!(names.len(), 2);
+    /// # });
+    /// ```
+    pub fn own_property_names(&self, filter: Filter) -> Result<Vec<Atom<'js>>> {
+        Ok(IterState::new(&self.0, filter.flags)?.collect())
+    }
+
     /// Get own string enumerable properties of an object
     pub fn props<K: FromAtom<'js>, V: FromJs<'js>>(&self) -> ObjectIter<'js, K, V> {
         self.own_props(Filter::default())
@@ -138,6 +302,21 @@ impl<'js> Object<'js> {
         }
     }
 
+    /// Consume the object, yielding its own enumerable string properties.
+    ///
+    /// Unlike iterating over the object with [`Object::props`], every property is removed from the
+    /// object as it is yielded. This way the object doesn't keep the values alive once they have
+    /// been handled, keeping memory usage low when processing a large object once.
+    ///
+    /// Properties which are not yet yielded remain in the object and are freed with it when the
+    /// iterator is dropped, unless the object is still referenced elsewhere.
+    pub fn into_entries(self) -> ObjectEntriesIter<'js> {
+        ObjectEntriesIter {
+            state: Some(IterState::new(&self.0, Filter::default().flags)),
+            object: self,
+        }
+    }
+
     /// Get an object prototype
     ///
     /// Objects can have no prototype, in this case this function will return null.
@@ -152,6 +331,19 @@ impl<'js> Object<'js> {
         }
     }
 
+    /// Returns an iterator over the prototype chain of the object.
+    ///
+    /// The iterator yields the prototype of the object, then the prototype of that prototype and
+    /// so on until the end of the chain is reached, `null` itself is not yielded. If a prototype
+    /// reappears in the chain, which is possible with proxies, iteration stops before yielding it
+    /// again.
+    pub fn prototypes(&self) -> ObjectPrototypesIter<'js> {
+        ObjectPrototypesIter {
+            current: Some(self.clone()),
+            visited: vec![self.clone()],
+        }
+    }
+
     /// Set an object prototype
     ///
     /// If called with None the function will set the prototype of the object to null.
@@ -168,6 +360,26 @@ impl<'js> Object<'js> {
         }
     }
 
+    /// Prevent new properties from being added to the object, like `Object.preventExtensions`.
+    pub fn prevent_extensions(&self) -> Result<()> {
+        let res = unsafe { qjs::JS_PreventExtensions(self.0.ctx.as_ptr(), self.0.as_js_value()) };
+        if res < 0 {
+            Err(self.0.ctx.raise_exception())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns whether new properties can be added to the object, like `Object.isExtensible`.
+    pub fn is_extensible(&self) -> Result<bool> {
+        let res = unsafe { qjs::JS_IsExtensible(self.0.ctx.as_ptr(), self.0.as_js_value()) };
+        if res < 0 {
+            Err(self.0.ctx.raise_exception())
+        } else {
+            Ok(res != 0)
+        }
+    }
+
     /// Check instance of object
     pub fn is_instance_of(&self, class: impl AsRef<Value<'js>>) -> bool {
         let class = class.as_ref();
@@ -188,6 +400,18 @@ impl<'js> Object<'js> {
             None
         }
     }
+
+    /// Returns the same object bound to a different context of the same runtime.
+    ///
+    /// Returns `Error::UnrelatedRuntime` if the given context is part of a different runtime.
+    pub fn clone_into_context<'to>(&self, ctx: &Ctx<'to>) -> Result<Object<'to>> {
+        let from_runtime_ptr = unsafe { qjs::JS_GetRuntime(self.ctx().as_ptr()) };
+        let to_runtime_ptr = unsafe { qjs::JS_GetRuntime(ctx.as_ptr()) };
+        if from_runtime_ptr != to_runtime_ptr {
+            return Err(Error::UnrelatedRuntime);
+        }
+        Ok(unsafe { Object::from_js_value_const(ctx.clone(), self.as_js_value()) })
+    }
 }
 
 /// The property filter
@@ -570,6 +794,87 @@ where
 
 impl<'js, V> FusedIterator for ObjectValuesIter<'js, V> where V: FromJs<'js> {}
 
+/// The owning iterator returned by [`Object::into_entries`].
+pub struct ObjectEntriesIter<'js> {
+    state: Option<Result<IterState<'js>>>,
+    object: Object<'js>,
+}
+
+impl<'js> Iterator for ObjectEntriesIter<'js> {
+    type Item = Result<(Atom<'js>, Value<'js>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(Ok(state)) = &mut self.state {
+            match state.next() {
+                Some(atom) => Some(self.object.get(atom.clone()).and_then(|value| {
+                    self.object.remove(atom.clone())?;
+                    Ok((atom, value))
+                })),
+                None => {
+                    self.state = None;
+                    None
+                }
+            }
+        } else if self.state.is_none() {
+            None
+        } else if let Some(Err(error)) = self.state.take() {
+            Some(Err(error))
+        } else {
+            unreachable!();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'js> ExactSizeIterator for ObjectEntriesIter<'js> {
+    fn len(&self) -> usize {
+        if let Some(Ok(state)) = &self.state {
+            state.len()
+        } else {
+            0
+        }
+    }
+}
+
+impl<'js> FusedIterator for ObjectEntriesIter<'js> {}
+
+/// The iterator returned by [`Object::prototypes`].
+pub struct ObjectPrototypesIter<'js> {
+    current: Option<Object<'js>>,
+    visited: Vec<Object<'js>>,
+}
+
+impl<'js> Iterator for ObjectPrototypesIter<'js> {
+    type Item = Result<Object<'js>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current.take()?;
+        let ctx = current.ctx();
+        let proto = unsafe {
+            let proto = qjs::JS_GetPrototype(ctx.as_ptr(), current.as_js_value());
+            if qjs::JS_IsException(proto) {
+                return Some(Err(ctx.raise_exception()));
+            }
+            if qjs::JS_IsNull(proto) {
+                return None;
+            }
+            Object::from_js_value(ctx.clone(), proto)
+        };
+        if self.visited.contains(&proto) {
+            return None;
+        }
+        self.visited.push(proto.clone());
+        self.current = Some(proto.clone());
+        Some(Ok(proto))
+    }
+}
+
+impl<'js> FusedIterator for ObjectPrototypesIter<'js> {}
+
 impl<'js> IntoIterator for Object<'js> {
     type Item = Result<(Atom<'js>, Value<'js>)>;
     type IntoIter = ObjectIter<'js, Atom<'js>, Value<'js>>;
@@ -609,6 +914,244 @@ where
 mod test {
     use crate::*;
 
+    #[test]
+    fn get_or() {
+        test_with(|ctx| {
+            let config: Object = ctx
+                .eval(
+                    "({ __proto__: { inherited: 2 }, port: 80, missing: undefined, empty: null })",
+                )
+                .unwrap();
+            assert_eq!(config.get_or("port", 8080).unwrap(), 80);
+            assert_eq!(config.get_or("other", 8080).unwrap(), 8080);
+            assert_eq!(config.get_or("missing", 8080).unwrap(), 8080);
+            assert_eq!(config.get_or("inherited", 1).unwrap(), 2);
+            assert_eq!(
+                config.get_or::<_, Option<i32>>("empty", Some(1)).unwrap(),
+                None
+            );
+            assert!(config.get_or("empty", 1).is_err());
+            assert_eq!(
+                config
+                    .get_with_default("name", || StdString::from("default"))
+                    .unwrap(),
+                "default"
+            );
+        })
+    }
+
+    #[test]
+    fn define_const() {
+        test_with(|ctx| {
+            let obj = Object::new(ctx.clone()).unwrap();
+            obj.define_const("VERSION", 3).unwrap();
+            ctx.globals().set("api", obj.clone()).unwrap();
+
+            let threw: bool = ctx
+                .eval("'use strict'; try { api.VERSION = 4; false } catch (e) { e instanceof TypeError }")
+                .unwrap();
+            assert!(threw);
+            ctx.eval_with_options::<(), _>(
+                "api.VERSION = 5; delete api.VERSION",
+                context::EvalOptions {
+                    strict: false,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+            assert_eq!(obj.get::<_, i32>("VERSION").unwrap(), 3);
+            assert_eq!(obj.keys::<StdString>().count(), 1);
+
+            assert!(obj.define_const("VERSION", 4).is_err());
+            ctx.catch();
+        })
+    }
+
+    #[test]
+    fn get_path() {
+        test_with(|ctx| {
+            let obj: Object = ctx
+                .eval("({ a: { b: { c: 42, n: null } }, x: 1 })")
+                .unwrap();
+            assert_eq!(obj.get_path::<i32>(&["a", "b", "c"]).unwrap(), Some(42));
+            assert_eq!(obj.get_path::<i32>(&["a", "missing", "c"]).unwrap(), None);
+            assert_eq!(obj.get_path::<i32>(&["a", "b", "n"]).unwrap(), None);
+            assert_eq!(obj.get_path::<i32>(&["a", "b", "n", "c"]).unwrap(), None);
+            assert!(obj.get_path::<Object>(&[]).unwrap().is_some());
+            assert!(obj.get_path::<i32>(&["x", "y"]).is_err());
+        })
+    }
+
+    #[test]
+    fn prototypes() {
+        test_with(|ctx| {
+            let obj: Object = ctx
+                .eval(
+                    r#"
+                    class A {}
+                    class B extends A {}
+                    new B()
+                "#,
+                )
+                .unwrap();
+            let protos = obj.prototypes().collect::<Result<Vec<_>>>().unwrap();
+            assert_eq!(protos.len(), 3);
+            let names = protos
+                .iter()
+                .map(|proto| {
+                    proto
+                        .get::<_, Object>("constructor")
+                        .unwrap()
+                        .get::<_, StdString>("name")
+                        .unwrap()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(names, ["B", "A", "Object"]);
+
+            let obj = Object::new(ctx.clone()).unwrap();
+            obj.set_prototype(None).unwrap();
+            assert_eq!(obj.prototypes().count(), 0);
+        })
+    }
+
+    #[test]
+    fn set_many() {
+        test_with(|ctx| {
+            let obj = Object::new(ctx.clone()).unwrap();
+            let entries = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4), ("e", 5)];
+            obj.set_many(entries).unwrap();
+            assert_eq!(obj.len(), 5);
+
+            ctx.globals().set("obj", obj.clone()).unwrap();
+            let res: StdString = ctx
+                .eval("Object.entries(obj).map(([k, v]) => k + v).join()")
+                .unwrap();
+            assert_eq!(res, "a1,b2,c3,d4,e5");
+
+            let frozen: Object = ctx.eval("Object.freeze({ a: 1 })").unwrap();
+            assert!(frozen.set_many([("b", 2)]).is_err());
+        })
+    }
+
+    #[test]
+    fn with_prototype() {
+        test_with(|ctx| {
+            let proto: Object = ctx
+                .eval(
+                    r#"
+                    class Greeter {
+                        greet() {
+                            return "hello " + this.name;
+                        }
+                    }
+                    Greeter.prototype
+                "#,
+                )
+                .unwrap();
+            let obj = Object::with_prototype(ctx.clone(), &proto).unwrap();
+            obj.set("name", "world").unwrap();
+            assert_eq!(obj.get_prototype(), Some(proto));
+
+            ctx.globals().set("obj", obj).unwrap();
+            let res: StdString = ctx
+                .eval("obj instanceof Greeter ? obj.greet() : 'wrong prototype'")
+                .unwrap();
+            assert_eq!(res, "hello world");
+
+            let obj = Object::new_null_proto(ctx.clone()).unwrap();
+            assert!(obj.get_prototype().is_none());
+            assert!(!obj.contains_key("toString").unwrap());
+        })
+    }
+
+    #[test]
+    fn prototypes_cycle() {
+        test_with(|ctx| {
+            let obj: Object = ctx
+                .eval(
+                    r#"
+                    const inner = {};
+                    const proxy = new Proxy(inner, {
+                        getPrototypeOf() {
+                            return proxy;
+                        }
+                    });
+                    Object.create(proxy)
+                "#,
+                )
+                .unwrap();
+            assert_eq!(obj.prototypes().count(), 1);
+
+            let obj: Object = ctx
+                .eval("new Proxy({}, { getPrototypeOf() { throw new Error('nope') } })")
+                .unwrap();
+            let mut protos = obj.prototypes();
+            assert!(protos.next().unwrap().is_err());
+            assert!(protos.next().is_none());
+        })
+    }
+
+    #[test]
+    fn into_entries() {
+        fn malloc_size(ctx: &Ctx) -> i64 {
+            let mut usage = std::mem::MaybeUninit::uninit();
+            unsafe {
+                qjs::JS_ComputeMemoryUsage(qjs::JS_GetRuntime(ctx.as_ptr()), usage.as_mut_ptr());
+                usage.assume_init().malloc_size
+            }
+        }
+
+        test_with(|ctx| {
+            let obj: Object = ctx
+                .eval(
+                    r#"
+                    let obj = {};
+                    for(let i = 0; i < 1000; i++){
+                        obj["key" + i] = "x".repeat(1000) + i;
+                    }
+                    obj
+                "#,
+                )
+                .unwrap();
+            ctx.eval::<(), _>("obj = undefined").unwrap();
+            ctx.run_gc();
+
+            let start = malloc_size(&ctx);
+            let mut iter = obj.into_entries();
+            assert_eq!(iter.len(), 1000);
+            for (idx, entry) in iter.by_ref().take(500).enumerate() {
+                let (key, value) = entry.unwrap();
+                assert_eq!(key.to_string().unwrap(), format!("key{idx}"));
+                assert!(value.is_string());
+            }
+            let halfway = malloc_size(&ctx);
+            assert!(start - halfway > 400 * 1000);
+            assert_eq!(iter.len(), 500);
+
+            // Dropping the iterator frees the remaining entries.
+            std::mem::drop(iter);
+            ctx.run_gc();
+            assert!(halfway - malloc_size(&ctx) > 400 * 1000);
+        })
+    }
+
+    #[test]
+    fn call_method() {
+        test_with(|ctx| {
+            let arr: Object = ctx.eval("[1, 2, 3]").unwrap();
+            assert!(arr.call_method::<_, _, bool>("includes", (3,)).unwrap());
+            assert!(!arr.call_method::<_, _, bool>("includes", (4,)).unwrap());
+            let joined: StdString = arr.call_method("join", ("-",)).unwrap();
+            assert_eq!(joined, "1-2-3");
+
+            let err = arr
+                .call_method::<_, _, ()>("length", ())
+                .catch(&ctx)
+                .unwrap_err();
+            assert!(err.to_string().contains("length is not a function"));
+        })
+    }
+
     #[test]
     fn from_javascript() {
         test_with(|ctx| {
@@ -801,4 +1344,88 @@ mod test {
             );
         })
     }
+
+    #[test]
+    fn own_property_names_partition() {
+        test_with(|ctx| {
+            let obj: Object = ctx
+                .eval(
+                    r#"
+                    const sym = Symbol("s");
+                    const obj = { a: 1, [sym]: 2, b: 3 };
+                    Object.defineProperty(obj, "hidden", { value: 4, enumerable: false });
+                    obj
+                "#,
+                )
+                .unwrap();
+
+            let to_strings = |atoms: Vec<Atom>| -> Vec<StdString> {
+                atoms
+                    .into_iter()
+                    .map(|atom| atom.to_string().unwrap())
+                    .collect()
+            };
+
+            let strings = obj.own_property_names(Filter::new().string()).unwrap();
+            assert_eq!(to_strings(strings), ["a", "b", "hidden"]);
+
+            let enumerable = obj
+                .own_property_names(Filter::new().string().enum_only())
+                .unwrap();
+            assert_eq!(to_strings(enumerable), ["a", "b"]);
+
+            let symbols = obj.own_property_names(Filter::new().symbol()).unwrap();
+            assert_eq!(to_strings(symbols), ["s"]);
+
+            let all = obj
+                .own_property_names(Filter::new().string().symbol())
+                .unwrap();
+            assert_eq!(all.len(), 4);
+            assert!(obj.own_property_names(Filter::new()).unwrap().is_empty());
+        })
+    }
+
+    #[test]
+    fn prevent_extensions() {
+        test_with(|ctx| {
+            let obj: Object = ctx.eval("({ a: 1 })").unwrap();
+            assert!(obj.is_extensible().unwrap());
+            obj.prevent_extensions().unwrap();
+            assert!(!obj.is_extensible().unwrap());
+            ctx.globals().set("obj", obj.clone()).unwrap();
+            assert!(ctx.eval::<(), _>("'use strict'; obj.b = 2").is_err());
+            assert!(!obj.contains_key("b").unwrap());
+            assert_eq!(obj.get::<_, i32>("a").unwrap(), 1);
+        })
+    }
+
+    #[test]
+    fn clone_object_into_unrelated_context() {
+        let rt1 = Runtime::new().unwrap();
+        let ctx1 = Context::full(&rt1).unwrap();
+        let rt2 = Runtime::new().unwrap();
+        let ctx2 = Context::full(&rt2).unwrap();
+
+        ctx1.with(|ctx1| {
+            let obj: Object = ctx1.eval("({ a: 2 })").unwrap();
+            ctx2.with(|ctx2| {
+                let err = obj.clone_into_context(&ctx2).unwrap_err();
+                assert!(matches!(err, Error::UnrelatedRuntime));
+            });
+        });
+    }
+
+    #[cfg(feature = "multi-ctx")]
+    #[test]
+    fn clone_object_into_context() {
+        let rt = Runtime::new().unwrap();
+        let ctx_a = Context::full(&rt).unwrap();
+        let ctx_b = Context::full(&rt).unwrap();
+
+        (&ctx_a, &ctx_b).with(|(ctx_a, ctx_b)| {
+            let obj: Object = ctx_a.eval("({ a: 2 })").unwrap();
+            let obj = obj.clone_into_context(&ctx_b).unwrap();
+            assert_eq!(obj.get::<_, i32>("a").unwrap(), 2);
+        });
+    }
 }
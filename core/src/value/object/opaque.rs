@@ -0,0 +1,245 @@
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+    ptr::NonNull,
+};
+
+use crate::{
+    class::{JsClass, Readable, Trace, Tracer},
+    function::This,
+    qjs,
+    value::Constructor,
+    Class, Ctx, Error, Function, JsLifetime, Object, Result,
+};
+
+/// The Rust data attached to an object, stored as the value of the object in a weak map.
+pub(crate) struct OpaqueData(RefCell<HashMap<TypeId, Box<dyn Any>>>);
+
+impl<'js> Trace<'js> for OpaqueData {
+    fn trace<'a>(&self, _tracer: Tracer<'a, 'js>) {}
+}
+
+unsafe impl<'js> JsLifetime<'js> for OpaqueData {
+    type Changed<'to> = OpaqueData;
+}
+
+impl<'js> JsClass<'js> for OpaqueData {
+    const NAME: &'static str = "OpaqueData";
+
+    type Mutable = Readable;
+
+    fn prototype(_ctx: &Ctx<'js>) -> Result<Option<Object<'js>>> {
+        Ok(None)
+    }
+
+    fn constructor(_ctx: &Ctx<'js>) -> Result<Option<Constructor<'js>>> {
+        Ok(None)
+    }
+}
+
+/// The weak map holding the data attached with [`Object::set_opaque`].
+///
+/// The map is created in a context of its own which is never handed out, so scripts can't replace
+/// or intercept the map or its methods.
+#[derive(Clone)]
+pub(crate) struct OpaqueMap<'js> {
+    map: Object<'js>,
+    get: Function<'js>,
+    set: Function<'js>,
+}
+
+impl<'js> OpaqueMap<'js> {
+    pub fn new(ctx: &Ctx<'js>) -> Result<Self> {
+        let private = unsafe {
+            let rt = qjs::JS_GetRuntime(ctx.as_ptr());
+            let private = NonNull::new(qjs::JS_NewContextRaw(rt)).ok_or(Error::Allocation)?;
+            qjs::JS_AddIntrinsicBaseObjects(private.as_ptr());
+            qjs::JS_AddIntrinsicMapSet(private.as_ptr());
+            let res = Ctx::from_ptr(private.as_ptr());
+            qjs::JS_FreeContext(private.as_ptr());
+            res
+        };
+        Self::new_in(&private).map_err(|e| match e {
+            // Move the exception to the context of the caller.
+            Error::Exception => ctx.throw(private.catch()),
+            e => e,
+        })
+    }
+
+    fn new_in(private: &Ctx<'js>) -> Result<Self> {
+        let constructor: Constructor = private.globals().get("WeakMap")?;
+        let map: Object = constructor.construct(())?;
+        Ok(OpaqueMap {
+            get: map.get("get")?,
+            set: map.get("set")?,
+            map,
+        })
+    }
+
+    fn get(&self, object: &Object<'js>) -> Result<Option<Class<'js, OpaqueData>>> {
+        // Call the methods with the context of the object so exceptions are raised there.
+        let get =
+            unsafe { Function::from_js_value_const(object.ctx().clone(), self.get.as_js_value()) };
+        get.call((This(self.map.clone()), object.clone()))
+    }
+
+    fn set(&self, object: &Object<'js>, data: Class<'js, OpaqueData>) -> Result<()> {
+        let set =
+            unsafe { Function::from_js_value_const(object.ctx().clone(), self.set.as_js_value()) };
+        set.call((This(self.map.clone()), object.clone(), data))
+    }
+}
+
+impl<'js> Object<'js> {
+    /// Attach Rust data to the object.
+    ///
+    /// The data can be retrieved with [`Object::get_opaque`] and is dropped when the object is
+    /// garbage collected. Every object can hold one value of each type, setting a value of a type
+    /// the object already holds replaces the previous value.
+    ///
+    /// The data is not visible from JavaScript: it is kept in a weak map owned by the runtime
+    /// instead of in a property, so this also works on frozen objects.
+    ///
+    /// ```
+    /// # use rquickjs::{Runtime, Context, Object};
+    /// # let rt = Runtime::new().unwrap();
+    /// # let ctx = Context::full(&rt).unwrap();
+    /// # ctx.with(|ctx| {
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct CreatedByApi(u32);
+    ///
+    /// let obj = Object::new(ctx.clone()).unwrap();
+    /// obj.set_opaque(CreatedByApi(1)).unwrap();
+    /// assert_eq!(obj.get_opaque::<CreatedByApi>().unwrap(), Some(CreatedByApi(1)));
+    /// assert_eq!(obj.get_opaque::<u32>().unwrap(), None);
+    /// # });
+    /// ```
+    pub fn set_opaque<T: 'static>(&self, data: T) -> Result<()> {
+        let data = Box::new(data) as Box<dyn Any>;
+        let ctx = self.ctx();
+        let weak_map = unsafe { ctx.get_opaque().get_or_insert_opaque_map(ctx)? };
+        if let Some(existing) = weak_map.get(self)? {
+            // Drop the previous value only after releasing the borrow, its drop might access the
+            // data of this object again.
+            let previous = existing
+                .borrow()
+                .0
+                .borrow_mut()
+                .insert(TypeId::of::<T>(), data);
+            drop(previous);
+            return Ok(());
+        }
+
+        let map = HashMap::from([(TypeId::of::<T>(), data)]);
+        let holder = Class::instance(ctx.clone(), OpaqueData(RefCell::new(map)))?;
+        weak_map.set(self, holder)
+    }
+
+    /// Returns a copy of the Rust data of type `T` attached to the object with
+    /// [`Object::set_opaque`], if any.
+    ///
+    /// Wrap the data in a [`Rc`](std::rc::Rc) to share data which can't be cloned.
+    pub fn get_opaque<T: Clone + 'static>(&self) -> Result<Option<T>> {
+        let Some(existing) = self.opaque_data()? else {
+            return Ok(None);
+        };
+        let borrow = existing.borrow();
+        let map = borrow.0.borrow();
+        Ok(map
+            .get(&TypeId::of::<T>())
+            .and_then(|x| x.downcast_ref::<T>())
+            .cloned())
+    }
+
+    /// Removes the Rust data of type `T` from the object, returning it if there was any.
+    pub fn remove_opaque<T: 'static>(&self) -> Result<Option<T>> {
+        let Some(existing) = self.opaque_data()? else {
+            return Ok(None);
+        };
+        let borrow = existing.borrow();
+        let res = borrow.0.borrow_mut().remove(&TypeId::of::<T>());
+        Ok(res.and_then(|x| x.downcast::<T>().ok()).map(|x| *x))
+    }
+
+    fn opaque_data(&self) -> Result<Option<Class<'js, OpaqueData>>> {
+        let ctx = self.ctx();
+        let weak_map = unsafe { ctx.get_opaque().get_or_insert_opaque_map(ctx)? };
+        weak_map.get(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use std::{cell::Cell, rc::Rc};
+
+    struct DropFlag(Rc<Cell<bool>>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    #[test]
+    fn set_and_get() {
+        test_with(|ctx| {
+            let obj: Object = ctx.eval("Object.freeze({ a: 1 })").unwrap();
+            assert_eq!(obj.get_opaque::<u32>().unwrap(), None);
+            obj.set_opaque(1u32).unwrap();
+            obj.set_opaque("tag").unwrap();
+            assert_eq!(obj.get_opaque::<u32>().unwrap(), Some(1));
+            assert_eq!(obj.get_opaque::<&str>().unwrap(), Some("tag"));
+
+            obj.set_opaque(2u32).unwrap();
+            assert_eq!(obj.get_opaque::<u32>().unwrap(), Some(2));
+            assert_eq!(obj.remove_opaque::<u32>().unwrap(), Some(2));
+            assert_eq!(obj.get_opaque::<u32>().unwrap(), None);
+
+            let other = Object::new(ctx.clone()).unwrap();
+            assert_eq!(other.get_opaque::<&str>().unwrap(), None);
+            assert_eq!(obj.keys::<StdString>().count(), 1);
+        })
+    }
+
+    #[test]
+    fn hidden_from_scripts() {
+        test_with(|ctx| {
+            ctx.eval::<(), _>(
+                r#"
+                WeakMap.prototype.get = () => { throw new Error("intercepted") };
+                WeakMap.prototype.set = () => { throw new Error("intercepted") };
+                globalThis.WeakMap = null;
+                Object.prototype.get = () => { throw new Error("intercepted") };
+            "#,
+            )
+            .unwrap();
+            let obj: Object = ctx.eval("new Proxy({}, {})").unwrap();
+            obj.set_opaque(1u32).unwrap();
+            assert_eq!(obj.get_opaque::<u32>().unwrap(), Some(1));
+
+            ctx.globals().set("obj", obj).unwrap();
+            let keys: usize = ctx
+                .eval("Reflect.ownKeys(obj).length + Object.getOwnPropertySymbols(obj).length")
+                .unwrap();
+            assert_eq!(keys, 0);
+        })
+    }
+
+    #[test]
+    fn dropped_with_object() {
+        let rt = Runtime::new().unwrap();
+        let ctx = Context::full(&rt).unwrap();
+        let dropped = Rc::new(Cell::new(false));
+        ctx.with(|ctx| {
+            let obj = Object::new(ctx.clone()).unwrap();
+            obj.set_opaque(Rc::new(DropFlag(dropped.clone()))).unwrap();
+            assert!(obj.get_opaque::<Rc<DropFlag>>().unwrap().is_some());
+            ctx.run_gc();
+            assert!(!dropped.get());
+        });
+        rt.run_gc();
+        assert!(dropped.get());
+    }
+}
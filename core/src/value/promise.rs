@@ -1,9 +1,11 @@
 //! Javascript promises and future integration.
 use crate::{
-    atom::PredefinedAtom, qjs, Ctx, Error, FromJs, Function, IntoJs, Object, Result, Value,
+    atom::PredefinedAtom,
+    function::{IntoJsFunc, This},
+    qjs, Ctx, Error, FromJs, Function, IntoJs, Object, Result, Value,
 };
 #[cfg(feature = "futures")]
-use crate::{function::This, CatchResultExt, CaughtError};
+use crate::{CatchResultExt, CaughtError};
 #[cfg(feature = "futures")]
 use std::{
     cell::RefCell,
@@ -91,11 +93,62 @@ impl<'js> Promise<'js> {
         self.0.get(PredefinedAtom::Catch)
     }
 
+    /// Attach a Rust function which is called with the value the promise resolves to, like
+    /// `promise.then(on_fulfilled)`.
+    ///
+    /// Returns a new promise which resolves to the value returned by the function. The function
+    /// is run from the job queue, see [`Ctx::execute_pending_job`].
+    ///
+    /// ```
+    /// # use rquickjs::{Runtime, Context, Promise};
+    /// # let rt = Runtime::new().unwrap();
+    /// # let ctx = Context::full(&rt).unwrap();
+    /// # ctx.with(|ctx| {
+    /// let promise: Promise = ctx.eval("Promise.resolve(21)").unwrap();
+    /// let doubled = promise.then_with(|x: i32| x * 2).unwrap();
+    /// assert_eq!(doubled.finish::<i32>().unwrap(), 42);
+    /// # });
+    /// ```
+    pub fn then_with<F, P>(&self, on_fulfilled: F) -> Result<Promise<'js>>
+    where
+        F: IntoJsFunc<'js, P> + 'js,
+    {
+        let on_fulfilled = Function::new(self.ctx().clone(), on_fulfilled)?;
+        self.then()?.call((This(self.clone()), on_fulfilled))
+    }
+
+    /// Attach a Rust function which is called with the value the promise is rejected with, like
+    /// `promise.catch(on_rejected)`.
+    ///
+    /// Returns a new promise which resolves to the value returned by the function, or to the
+    /// value of this promise if it was not rejected.
+    pub fn catch_with<F, P>(&self, on_rejected: F) -> Result<Promise<'js>>
+    where
+        F: IntoJsFunc<'js, P> + 'js,
+    {
+        let on_rejected = Function::new(self.ctx().clone(), on_rejected)?;
+        self.catch()?.call((This(self.clone()), on_rejected))
+    }
+
     /// Returns the result of the future if there is one.
     ///
     /// Returns None if the promise has not yet been completed, Ok if the promise was resolved, and
     /// [`Error::Exception`] if the promise rejected with the rejected value as the thrown
     /// value retrievable via [`Ctx::catch`].
+    ///
+    /// This doesn't run any pending jobs so it can be used to poll a promise without blocking.
+    ///
+    /// ```
+    /// # use rquickjs::{Runtime, Context, Promise};
+    /// # let rt = Runtime::new().unwrap();
+    /// # let ctx = Context::full(&rt).unwrap();
+    /// # ctx.with(|ctx| {
+    /// let (promise, resolve, _) = Promise::new(&ctx).unwrap();
+    /// assert!(promise.result::<i32>().is_none());
+    /// resolve.call::<_, ()>((1,)).unwrap();
+    /// assert_eq!(promise.result::<i32>().unwrap().unwrap(), 1);
+    /// # });
+    /// ```
     pub fn result<T: FromJs<'js>>(&self) -> Option<Result<T>> {
         match self.state() {
             PromiseState::Pending => None,
@@ -503,4 +556,64 @@ mod test {
             assert!(DID_EXECUTE.load(Ordering::SeqCst));
         })
     }
+
+    #[test]
+    fn promise_state_and_result() {
+        let rt = Runtime::new().unwrap();
+        let ctx = Context::full(&rt).unwrap();
+
+        ctx.with(|ctx| {
+            let resolved: Promise = ctx.eval("Promise.resolve(42)").unwrap();
+            assert_eq!(resolved.state(), PromiseState::Resolved);
+            assert_eq!(resolved.result::<i32>().unwrap().unwrap(), 42);
+
+            let pending: Promise = ctx.eval("new Promise(() => {})").unwrap();
+            assert_eq!(pending.state(), PromiseState::Pending);
+            assert!(pending.result::<i32>().is_none());
+
+            let rejected: Promise = ctx.eval("Promise.reject(new Error('nope'))").unwrap();
+            assert_eq!(rejected.state(), PromiseState::Rejected);
+            let err = rejected.result::<i32>().unwrap().catch(&ctx).unwrap_err();
+            assert!(err.to_string().contains("nope"));
+        })
+    }
+
+    #[test]
+    fn promise_then_chain() {
+        let rt = Runtime::new().unwrap();
+        let ctx = Context::full(&rt).unwrap();
+
+        ctx.with(|ctx| {
+            let (promise, resolve, _) = Promise::new(&ctx).unwrap();
+            let doubled = promise.then_with(|x: i32| x * 2).unwrap();
+            let plus_one = doubled.then_with(|x: i32| x + 1).unwrap();
+            assert_eq!(plus_one.state(), PromiseState::Pending);
+
+            resolve.call::<_, ()>((21,)).unwrap();
+            while ctx.execute_pending_job() {}
+
+            assert_eq!(doubled.result::<i32>().unwrap().unwrap(), 42);
+            assert_eq!(plus_one.result::<i32>().unwrap().unwrap(), 43);
+        })
+    }
+
+    #[test]
+    fn promise_catch() {
+        let rt = Runtime::new().unwrap();
+        let ctx = Context::full(&rt).unwrap();
+
+        ctx.with(|ctx| {
+            let promise: Promise = ctx.eval("Promise.reject(new Error('oops'))").unwrap();
+            let caught = promise
+                .then_with(|_: i32| -> String { unreachable!() })
+                .unwrap()
+                .catch_with(|e: crate::Exception| e.message())
+                .unwrap();
+            assert_eq!(caught.finish::<String>().unwrap(), "oops");
+
+            let resolved: Promise = ctx.eval("Promise.resolve(1)").unwrap();
+            let passed = resolved.catch_with(|| 2).unwrap();
+            assert_eq!(passed.finish::<i32>().unwrap(), 1);
+        })
+    }
 }
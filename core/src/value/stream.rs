@@ -0,0 +1,261 @@
+//! Interop between Rust streams and JavaScript async iterators.
+
+use std::{
+    cell::RefCell,
+    future::{poll_fn, Future},
+    marker::PhantomData,
+    pin::Pin,
+    rc::Rc,
+    task::{Context as TaskContext, Poll},
+};
+
+use futures_core::Stream;
+
+use super::iterator::iter_result;
+use crate::{
+    function::This,
+    promise::{MaybePromise, MaybePromiseFuture, Promised},
+    Coerced, Ctx, Exception, FromJs, Function, IntoJs, Object, Result, Symbol, Value,
+};
+
+/// Wrapper for streams to convert to JS async iterators.
+///
+/// The resulting object implements the async iterator protocol and can thus be consumed with
+/// `for await` in JavaScript. The stream is polled lazily: each call to `next()` polls the stream
+/// for exactly one item.
+///
+/// Items which are a Rust error reject the promise returned by `next()`.
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "futures")))]
+#[repr(transparent)]
+pub struct Streamed<S>(pub S);
+
+impl<S> From<S> for Streamed<S> {
+    fn from(stream: S) -> Self {
+        Self(stream)
+    }
+}
+
+impl<'js, S, R> IntoJs<'js> for Streamed<S>
+where
+    S: Stream<Item = R> + 'js,
+    R: IntoJs<'js> + 'js,
+{
+    fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+        let stream = Rc::new(RefCell::new(Box::pin(self.0)));
+
+        let next = Function::new(ctx.clone(), move |ctx: Ctx<'js>| {
+            let stream = stream.clone();
+            Promised(async move {
+                let item = poll_fn(|cx| stream.borrow_mut().as_mut().poll_next(cx)).await;
+                iter_result(&ctx, item)
+            })
+        })?
+        .with_name("next")?;
+
+        let iter = Object::new(ctx.clone())?;
+        iter.set("next", next)?;
+        iter.set(
+            Symbol::async_iterator(ctx.clone()).as_atom(),
+            Function::new(ctx.clone(), |this: This<Value<'js>>| this.0)?,
+        )?;
+        Ok(iter.into_value())
+    }
+}
+
+/// A Rust stream over the values of a JavaScript async iterator.
+///
+/// Created with [`Object::async_iter`]. Every poll which needs a new item calls `next()` on the
+/// iterator and awaits the returned promise. The stream ends when the iterator is done or after
+/// the first error, like `next()` throwing or rejecting or a value which can't be converted
+/// into `T`.
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "futures")))]
+#[must_use = "streams do nothing unless polled"]
+#[derive(Debug)]
+pub struct AsyncIter<'js, T> {
+    iter: Object<'js>,
+    next: Function<'js>,
+    pending: Option<MaybePromiseFuture<'js, Object<'js>>>,
+    done: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<'js, T> Unpin for AsyncIter<'js, T> {}
+
+impl<'js, T> AsyncIter<'js, T>
+where
+    T: FromJs<'js>,
+{
+    fn poll_item(&mut self, cx: &mut TaskContext<'_>) -> Poll<Result<Option<T>>> {
+        if self.pending.is_none() {
+            let res: MaybePromise = self.next.call((This(self.iter.clone()),))?;
+            self.pending = Some(res.into_future());
+        }
+        let res = match Pin::new(self.pending.as_mut().unwrap()).poll(cx) {
+            Poll::Ready(x) => x,
+            Poll::Pending => return Poll::Pending,
+        };
+        self.pending = None;
+        let res = res?;
+        if res.get::<_, Coerced<bool>>("done")?.0 {
+            return Poll::Ready(Ok(None));
+        }
+        Poll::Ready(res.get("value").map(Some))
+    }
+}
+
+impl<'js, T> Stream for AsyncIter<'js, T>
+where
+    T: FromJs<'js>,
+{
+    type Item = Result<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+        let res = match this.poll_item(cx) {
+            Poll::Ready(x) => x,
+            Poll::Pending => return Poll::Pending,
+        };
+        if !matches!(res, Ok(Some(_))) {
+            this.done = true;
+        }
+        Poll::Ready(res.transpose())
+    }
+}
+
+impl<'js> Object<'js> {
+    /// Iterate over the values of an async iterable object, like `for await` in JavaScript.
+    ///
+    /// Returns a `TypeError` if the object has no `Symbol.asyncIterator` method.
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "futures")))]
+    pub fn async_iter<T: FromJs<'js>>(&self) -> Result<AsyncIter<'js, T>> {
+        let method: Value = self.get(Symbol::async_iterator(self.ctx().clone()).as_atom())?;
+        let Some(method) = method.into_function() else {
+            return Err(Exception::throw_type(
+                self.ctx(),
+                "object is not async iterable",
+            ));
+        };
+        let iter: Object = method.call((This(self.clone()),))?;
+        let next = iter.get("next")?;
+        Ok(AsyncIter {
+            iter,
+            next,
+            pending: None,
+            done: false,
+            _marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, rc::Rc};
+
+    use futures_rs::{stream, StreamExt};
+
+    use super::Streamed;
+    use crate::{
+        async_with, AsyncContext, AsyncRuntime, CatchResultExt, Function, Object, Promise,
+    };
+
+    #[tokio::test]
+    async fn async_iter() {
+        let rt = AsyncRuntime::new().unwrap();
+        let ctx = AsyncContext::full(&rt).await.unwrap();
+
+        async_with!(ctx => |ctx| {
+            let generator: Object = ctx
+                .eval(
+                    r#"
+                    (async function* () {
+                        yield 1;
+                        await null;
+                        yield 2;
+                        yield 3;
+                    })()
+                "#,
+                )
+                .catch(&ctx)
+                .unwrap();
+            let items: Vec<i32> = generator
+                .async_iter::<i32>()
+                .unwrap()
+                .map(|x| x.unwrap())
+                .collect()
+                .await;
+            assert_eq!(items, [1, 2, 3]);
+
+            let failing: Object = ctx
+                .eval("(async function* () { yield 1; throw new Error('oops'); })()")
+                .unwrap();
+            let mut stream = failing.async_iter::<i32>().unwrap();
+            assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+            assert!(stream.next().await.unwrap().is_err());
+            ctx.catch();
+            assert!(stream.next().await.is_none());
+
+            let object = Object::new(ctx.clone()).unwrap();
+            assert!(object.async_iter::<i32>().is_err());
+            ctx.catch();
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn for_await_stream() {
+        let rt = AsyncRuntime::new().unwrap();
+        let ctx = AsyncContext::full(&rt).await.unwrap();
+
+        async_with!(ctx => |ctx| {
+            let polled = Rc::new(Cell::new(0));
+            let polled_clone = polled.clone();
+            let func = Function::new(ctx.clone(), move || {
+                let polled = polled_clone.clone();
+                Streamed(stream::iter(1..=3).map(move |x| {
+                    polled.set(polled.get() + 1);
+                    x
+                }))
+            })
+            .unwrap();
+            ctx.globals().set("numbers", func).unwrap();
+
+            let promise: Promise = ctx
+                .eval(
+                    r#"
+                    (async () => {
+                        let res = [];
+                        for await (const x of numbers()) {
+                            res.push(x);
+                        }
+                        return res.join();
+                    })()
+                "#,
+                )
+                .catch(&ctx)
+                .unwrap();
+            let res: String = promise.into_future().await.catch(&ctx).unwrap();
+            assert_eq!(res, "1,2,3");
+            assert_eq!(polled.get(), 3);
+
+            polled.set(0);
+            let promise: Promise = ctx
+                .eval(
+                    r#"
+                    (async () => {
+                        let iter = numbers();
+                        return (await iter.next()).value;
+                    })()
+                "#,
+                )
+                .catch(&ctx)
+                .unwrap();
+            let res: i32 = promise.into_future().await.catch(&ctx).unwrap();
+            assert_eq!(res, 1);
+            assert_eq!(polled.get(), 1);
+        })
+        .await
+    }
+}
@@ -1,5 +1,5 @@
-use crate::{qjs, Ctx, Error, Result, StdString, Value};
-use std::{ffi::c_char, mem, ptr::NonNull, slice, str};
+use crate::{atom::PredefinedAtom, qjs, Atom, Ctx, Error, Result, StdString, Value};
+use std::{borrow::Cow, ffi::c_char, mem, ptr::NonNull, slice, str};
 
 /// Rust representation of a JavaScript string.
 #[derive(Debug, Clone, PartialEq, Hash)]
@@ -31,6 +31,8 @@ impl<'js> String<'js> {
     }
 
     /// Create a new JavaScript string from an Rust string.
+    ///
+    /// The string can contain NUL characters, the full length of the string is used.
     pub fn from_str(ctx: Ctx<'js>, s: &str) -> Result<Self> {
         let len = s.as_bytes().len();
         let ptr = s.as_ptr();
@@ -40,6 +42,55 @@ impl<'js> String<'js> {
             String::from_js_value(ctx, js_val)
         })
     }
+
+    /// Create a new JavaScript string from UTF-8 encoded bytes.
+    ///
+    /// Like [`String::from_str`] the bytes can contain NUL bytes. Returns [`Error::Utf8`] if the
+    /// bytes are not valid UTF-8.
+    pub fn from_bytes(ctx: Ctx<'js>, bytes: &[u8]) -> Result<Self> {
+        Self::from_str(ctx, str::from_utf8(bytes)?)
+    }
+
+    /// Create a new JavaScript string from UTF-8 encoded bytes, replacing invalid sequences with
+    /// `U+FFFD REPLACEMENT CHARACTER`.
+    pub fn from_bytes_lossy(ctx: Ctx<'js>, bytes: &[u8]) -> Result<Self> {
+        Self::from_str(ctx, &StdString::from_utf8_lossy(bytes))
+    }
+
+    /// Concatenate two JavaScript strings without converting them to Rust strings.
+    pub fn concat(&self, other: &String<'js>) -> Result<String<'js>> {
+        let concat = Atom::from_predefined(self.0.ctx.clone(), PredefinedAtom::Concat);
+        self.invoke(&concat, &mut [other.as_js_value()])
+    }
+
+    /// Extract a section of the string, following the semantics of
+    /// `String.prototype.slice`.
+    ///
+    /// The section starts at the UTF-16 code unit index `start` and ends before `end`, or at the
+    /// end of the string if `end` is `None`. Negative indices count back from the end of the
+    /// string.
+    pub fn slice(&self, start: i32, end: Option<i32>) -> Result<String<'js>> {
+        let slice = Atom::from_str(self.0.ctx.clone(), "slice")?;
+        let end = end.map_or(qjs::JS_UNDEFINED, |end| qjs::JS_MKVAL(qjs::JS_TAG_INT, end));
+        self.invoke(&slice, &mut [qjs::JS_MKVAL(qjs::JS_TAG_INT, start), end])
+    }
+
+    /// Call a `String.prototype` method on this string which returns a string.
+    fn invoke(&self, method: &Atom<'js>, args: &mut [qjs::JSValueConst]) -> Result<String<'js>> {
+        let ctx = &self.0.ctx;
+        let value = unsafe {
+            let val = qjs::JS_Invoke(
+                ctx.as_ptr(),
+                self.as_js_value(),
+                method.atom,
+                args.len() as _,
+                args.as_mut_ptr(),
+            );
+            let val = ctx.handle_exception(val)?;
+            Value::from_js_value(ctx.clone(), val)
+        };
+        String::from_value(value)
+    }
 }
 
 /// Rust representation of a JavaScript C string.
@@ -93,6 +144,22 @@ impl<'js> CString<'js> {
         // SAFETY: The bytes are garanteed to be valid utf8 by QuickJS
         unsafe { str::from_utf8_unchecked(bytes) }
     }
+
+    /// Returns the bytes of this `CString` without the null terminator.
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: The pointer points to a JSString content which is ref counted
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr() as *const u8, self.len) }
+    }
+
+    /// Returns the content of this `CString` as a string slice, borrowing from the JavaScript
+    /// string if possible.
+    ///
+    /// JavaScript strings can contain lone surrogates which are not valid UTF-8. For such strings
+    /// the invalid sequences are replaced with `U+FFFD REPLACEMENT CHARACTER` and an owned
+    /// string is returned.
+    pub fn to_str_lossy(&self) -> Cow<'_, str> {
+        StdString::from_utf8_lossy(self.as_bytes())
+    }
 }
 
 impl<'js> Drop for CString<'js> {
@@ -104,6 +171,23 @@ impl<'js> Drop for CString<'js> {
 #[cfg(test)]
 mod test {
     use crate::{prelude::*, *};
+    use std::borrow::Cow;
+    #[test]
+    fn from_bytes() {
+        test_with(|ctx| {
+            let s = String::from_bytes(ctx.clone(), b"a\0b").unwrap();
+            ctx.globals().set("s", s.clone()).unwrap();
+            let res: Vec<i32> = ctx.eval("[s.length, s.charCodeAt(1)]").unwrap();
+            assert_eq!(res, [3, 0]);
+            assert_eq!(s.to_string().unwrap(), "a\0b");
+
+            let err = String::from_bytes(ctx.clone(), b"a\xffb").unwrap_err();
+            assert!(matches!(err, Error::Utf8(_)));
+            let s = String::from_bytes_lossy(ctx.clone(), b"a\xff\0b").unwrap();
+            assert_eq!(s.to_string().unwrap(), "a\u{fffd}\0b");
+        });
+    }
+
     #[test]
     fn from_javascript() {
         test_with(|ctx| {
@@ -122,6 +206,34 @@ mod test {
         });
     }
 
+    #[test]
+    fn concat_and_slice() {
+        test_with(|ctx| {
+            let hello = String::from_str(ctx.clone(), "hello ").unwrap();
+            let world = String::from_str(ctx.clone(), "world").unwrap();
+            let joined = hello.concat(&world).unwrap();
+            assert_eq!(joined.to_string().unwrap(), "hello world");
+
+            assert_eq!(
+                joined.slice(-5, None).unwrap().to_string().unwrap(),
+                "world"
+            );
+            assert_eq!(
+                joined.slice(0, Some(-6)).unwrap().to_string().unwrap(),
+                "hello"
+            );
+            assert_eq!(
+                joined.slice(-3, Some(-1)).unwrap().to_string().unwrap(),
+                "rl"
+            );
+            assert_eq!(joined.slice(4, Some(2)).unwrap().to_string().unwrap(), "");
+            assert_eq!(
+                joined.slice(-100, Some(100)).unwrap().to_string().unwrap(),
+                "hello world"
+            );
+        })
+    }
+
     #[test]
     fn from_javascript_c() {
         test_with(|ctx| {
@@ -130,6 +242,49 @@ mod test {
         });
     }
 
+    #[test]
+    fn c_string_to_str_lossy() {
+        test_with(|ctx| {
+            let s: CString = ctx.eval(" 'foo bar baz' ").unwrap();
+            let cow = s.to_str_lossy();
+            assert!(matches!(cow, Cow::Borrowed(_)));
+            assert_eq!(cow, "foo bar baz");
+
+            let s: CString = ctx.eval(" 'a\\uD800b' ").unwrap();
+            let cow = s.to_str_lossy();
+            assert!(matches!(cow, Cow::Owned(_)));
+            assert_eq!(cow, "a\u{FFFD}\u{FFFD}\u{FFFD}b");
+        });
+    }
+
+    #[test]
+    fn from_javascript_cow_borrowed() {
+        test_with(|ctx| {
+            let func = Function::new(ctx.clone(), |s: CString| {
+                let cow = s.to_str_lossy();
+                // The slice points into the buffer owned by the `CString`.
+                assert!(matches!(cow, Cow::Borrowed(_)));
+                assert_eq!(cow.as_ptr(), s.as_ptr() as *const u8);
+                cow.len()
+            })
+            .unwrap();
+            let len: usize = func.call(("foo bar",)).unwrap();
+            assert_eq!(len, 7);
+        });
+    }
+
+    #[test]
+    fn from_javascript_cow() {
+        test_with(|ctx| {
+            let func = Function::new(ctx.clone(), |s: Cow<str>| s.len()).unwrap();
+            let len: usize = func.call(("foo bar",)).unwrap();
+            assert_eq!(len, 7);
+
+            let s: Cow<str> = ctx.eval(" 'baz' ").unwrap();
+            assert_eq!(s, "baz");
+        });
+    }
+
     #[test]
     fn to_javascript_c() {
         test_with(|ctx| {
@@ -1,4 +1,4 @@
-use crate::{qjs, Atom, Ctx, Result, Value};
+use crate::{qjs, Atom, Ctx, FromJs, Result, String, Value};
 
 /// Rust representation of a JavaScript symbol.
 #[derive(Debug, Clone, PartialEq, Hash)]
@@ -16,6 +16,13 @@ impl<'js> Symbol<'js> {
         }
     }
 
+    /// Get the symbol description as a string.
+    ///
+    /// Returns `None` if the symbol was created without a description, like `Symbol()`.
+    pub fn description_string(&self) -> Result<Option<String<'js>>> {
+        FromJs::from_js(self.0.ctx(), self.description()?)
+    }
+
     /// Convert a symbol into a atom.
     pub fn as_atom(&self) -> Atom<'js> {
         Atom::from_value(self.0.ctx().clone(), &self.0)
@@ -89,4 +96,32 @@ mod test {
             assert!(s.description().unwrap().is_undefined());
         });
     }
+
+    #[test]
+    fn description_string() {
+        test_with(|ctx| {
+            let s: Symbol<'_> = ctx.eval("Symbol('x')").unwrap();
+            assert_eq!(
+                s.description_string()
+                    .unwrap()
+                    .unwrap()
+                    .to_string()
+                    .unwrap(),
+                "x"
+            );
+
+            let s: Symbol<'_> = ctx.eval("Symbol('')").unwrap();
+            assert_eq!(
+                s.description_string()
+                    .unwrap()
+                    .unwrap()
+                    .to_string()
+                    .unwrap(),
+                ""
+            );
+
+            let s: Symbol<'_> = ctx.eval("Symbol()").unwrap();
+            assert!(s.description_string().unwrap().is_none());
+        });
+    }
 }
@@ -1,17 +1,20 @@
 use crate::{
-    atom::PredefinedAtom, qjs, ArrayBuffer, Ctx, Error, FromJs, Function, IntoJs, JsLifetime,
-    Object, Result, Value,
+    atom::PredefinedAtom, convert::FromIteratorJs, qjs, Array, ArrayBuffer, Ctx, Error, FromJs,
+    Function, IntoJs, JsLifetime, Object, Result, Value,
 };
 use std::{
     fmt,
     marker::PhantomData,
     mem::{self, MaybeUninit},
-    ops::Deref,
-    ptr::{null_mut, NonNull},
+    ops::{Deref, Range},
+    ptr::{self, null_mut, NonNull},
     slice,
 };
 
-use super::{array_buffer::RawArrayBuffer, Constructor};
+use super::{
+    array_buffer::{AsSliceError, RawArrayBuffer},
+    Constructor,
+};
 
 /// The trait which implements types which capable to be TypedArray items
 ///
@@ -39,22 +42,79 @@ typedarray_items! {
     Float64Array: f64,
     BigInt64Array: i64,
     BigUint64Array: u64,
+    Uint8ClampedArray: ClampedU8,
+}
+
+/// An element of a `Uint8ClampedArray`.
+///
+/// Values assigned to the elements of a `Uint8ClampedArray` in JavaScript are clamped to the
+/// range `0..=255` and rounded to the nearest integer instead of wrapping around. Converting a
+/// JavaScript value into a `ClampedU8` follows the same rules.
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "array-buffer")))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ClampedU8(pub u8);
+
+impl ClampedU8 {
+    /// Clamp a number like assigning it to an element of a `Uint8ClampedArray`.
+    pub fn clamp(value: f64) -> Self {
+        if value.is_nan() || value <= 0.0 {
+            return ClampedU8(0);
+        }
+        if value >= 255.0 {
+            return ClampedU8(255);
+        }
+        // Round half to even.
+        let floor = value.floor();
+        let res = match value - floor {
+            x if x < 0.5 => floor,
+            x if x > 0.5 => floor + 1.0,
+            _ if floor % 2.0 == 0.0 => floor,
+            _ => floor + 1.0,
+        };
+        ClampedU8(res as u8)
+    }
+}
+
+impl From<u8> for ClampedU8 {
+    fn from(value: u8) -> Self {
+        ClampedU8(value)
+    }
+}
+
+impl From<ClampedU8> for u8 {
+    fn from(value: ClampedU8) -> Self {
+        value.0
+    }
+}
+
+impl<'js> FromJs<'js> for ClampedU8 {
+    fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> Result<Self> {
+        f64::from_js(ctx, value).map(Self::clamp)
+    }
+}
+
+impl<'js> IntoJs<'js> for ClampedU8 {
+    fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+        self.0.into_js(ctx)
+    }
 }
 
 /// Rust representation of a JavaScript objects of TypedArray classes.
 ///
-/// | ES Type            | Rust Type             |
-/// | ------------------ | --------------------- |
-/// | `Int8Array`        | [`TypedArray<i8>`]    |
-/// | `Uint8Array`       | [`TypedArray<u8>`]    |
-/// | `Int16Array`       | [`TypedArray<i16>`]   |
-/// | `Uint16Array`      | [`TypedArray<u16>`]   |
-/// | `Int32Array`       | [`TypedArray<i32>`]   |
-/// | `Uint32Array`      | [`TypedArray<u32>`]   |
-/// | `Float32Array`     | [`TypedArray<f32>`]   |
-/// | `Float64Array`     | [`TypedArray<f64>`]   |
-/// | `BigInt64Array`    | [`TypedArray<i64>`]   |
-/// | `BigUint64Array`   | [`TypedArray<u64>`]   |
+/// | ES Type             | Rust Type                 |
+/// | ------------------- | ------------------------- |
+/// | `Int8Array`         | [`TypedArray<i8>`]        |
+/// | `Uint8Array`        | [`TypedArray<u8>`]        |
+/// | `Uint8ClampedArray` | [`TypedArray<ClampedU8>`] |
+/// | `Int16Array`        | [`TypedArray<i16>`]       |
+/// | `Uint16Array`       | [`TypedArray<u16>`]       |
+/// | `Int32Array`        | [`TypedArray<i32>`]       |
+/// | `Uint32Array`       | [`TypedArray<u32>`]       |
+/// | `Float32Array`      | [`TypedArray<f32>`]       |
+/// | `Float64Array`      | [`TypedArray<f64>`]       |
+/// | `BigInt64Array`     | [`TypedArray<i64>`]       |
+/// | `BigUint64Array`    | [`TypedArray<u64>`]       |
 ///
 #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "array-buffer")))]
 #[repr(transparent)]
@@ -171,6 +231,68 @@ impl<'js, T> TypedArray<'js, T> {
         Some(unsafe { slice::from_raw_parts(ptr.as_ptr(), len) })
     }
 
+    /// Set the elements in the given range to `value`.
+    ///
+    /// Unlike `TypedArray.prototype.fill` the range is not clamped, a range outside of the
+    /// current length of the array results in an [`AsSliceError::OutOfBounds`] error.
+    ///
+    /// This writes to the backing memory of the array, so like JavaScript code modifying the
+    /// array it changes the contents of slices returned by `as_ref` or [`TypedArray::as_bytes`]
+    /// on this or any other view of the same buffer.
+    pub fn fill(&self, value: T, range: Range<usize>) -> Result<()>
+    where
+        T: TypedArrayItem,
+    {
+        let (len, ptr) = Self::get_raw(&self.0).ok_or(Error::AsSlice(AsSliceError::BufferUsed))?;
+        Self::check_range(&range, len)?;
+        for idx in range {
+            unsafe { ptr.as_ptr().add(idx).write(value) };
+        }
+        Ok(())
+    }
+
+    /// Copy the elements in the range `src` to the position starting at `dest`, the ranges
+    /// may overlap.
+    ///
+    /// Like [`slice::copy_within`] but returns an [`AsSliceError::OutOfBounds`] error if either
+    /// range is outside of the current length of the array.
+    ///
+    /// Like [`TypedArray::fill`] this modifies the memory behind slices of the buffer.
+    pub fn copy_within(&self, src: Range<usize>, dest: usize) -> Result<()>
+    where
+        T: TypedArrayItem,
+    {
+        let (len, ptr) = Self::get_raw(&self.0).ok_or(Error::AsSlice(AsSliceError::BufferUsed))?;
+        Self::check_range(&src, len)?;
+        let count = src.end - src.start;
+        Self::check_range(&(dest..dest.saturating_add(count)), len)?;
+        unsafe { ptr::copy(ptr.as_ptr().add(src.start), ptr.as_ptr().add(dest), count) };
+        Ok(())
+    }
+
+    fn check_range(range: &Range<usize>, len: usize) -> Result<()> {
+        if range.start > range.end || range.end > len {
+            return Err(Error::AsSlice(AsSliceError::OutOfBounds {
+                start: range.start,
+                end: range.end,
+                len,
+            }));
+        }
+        Ok(())
+    }
+
+    /// Create a regular JavaScript array containing the elements of this typed array.
+    ///
+    /// Returns an error if the typed array is detached.
+    pub fn to_array(&self) -> Result<Array<'js>>
+    where
+        T: TypedArrayItem + IntoJs<'js>,
+    {
+        let (len, ptr) = Self::get_raw(&self.0).ok_or(Error::AsSlice(AsSliceError::BufferUsed))?;
+        let items = unsafe { slice::from_raw_parts(ptr.as_ptr(), len) };
+        Array::from_iter_js(self.ctx(), items.iter().copied())
+    }
+
     pub fn as_raw(&self) -> Option<RawArrayBuffer> {
         let (_, len, ptr) = Self::get_raw_bytes(self.as_value())?;
         Some(RawArrayBuffer { len, ptr })
@@ -307,6 +429,20 @@ impl<'js, T> IntoJs<'js> for TypedArray<'js, T> {
     }
 }
 
+impl<'js> Array<'js> {
+    /// Create a typed array from the elements of this array.
+    ///
+    /// Every element is converted with [`FromJs`], so the conversion fails if the array contains
+    /// an element which isn't a number.
+    pub fn to_typed_array<T>(&self) -> Result<TypedArray<'js, T>>
+    where
+        T: TypedArrayItem + FromJs<'js>,
+    {
+        let items = self.iter::<T>().collect::<Result<Vec<_>>>()?;
+        TypedArray::new(self.ctx().clone(), items)
+    }
+}
+
 impl<'js> Object<'js> {
     pub fn is_typed_array<T: TypedArrayItem>(&self) -> bool {
         // This should not error unless the global ArrayBuffer object suddenly isn't a Function
@@ -333,7 +469,62 @@ impl<'js> Object<'js> {
 
 #[cfg(test)]
 mod test {
-    use crate::*;
+    use crate::{value::array_buffer::AsSliceError, *};
+
+    #[test]
+    fn array_to_typed_array_and_back() {
+        test_with(|ctx| {
+            let array: Array = ctx.eval("[1, 2, 3]").unwrap();
+            let typed = array.to_typed_array::<f64>().unwrap();
+            assert_eq!(typed.as_ref() as &[f64], &[1.0, 2.0, 3.0]);
+
+            ctx.globals().set("typed", typed.clone()).unwrap();
+            let name: StdString = ctx.eval("typed.constructor.name").unwrap();
+            assert_eq!(name, "Float64Array");
+
+            let array = typed.to_array().unwrap();
+            assert!(array.as_object().is_array());
+            let items = array.iter::<f64>().collect::<Result<Vec<_>>>().unwrap();
+            assert_eq!(items, [1.0, 2.0, 3.0]);
+
+            let array: Array = ctx.eval("[1, 'two', 3]").unwrap();
+            assert!(array.to_typed_array::<f64>().is_err());
+        });
+    }
+
+    #[test]
+    fn clamped_u8() {
+        test_with(|ctx| {
+            let val =
+                TypedArray::<ClampedU8>::new(ctx.clone(), [ClampedU8(1), ClampedU8(2)]).unwrap();
+            ctx.globals().set("v", val.clone()).unwrap();
+            let name: StdString = ctx
+                .eval("v[0] = 300; v[1] = -5; v.constructor.name")
+                .unwrap();
+            assert_eq!(name, "Uint8ClampedArray");
+            assert_eq!(
+                val.as_ref() as &[ClampedU8],
+                &[ClampedU8(255), ClampedU8(0)]
+            );
+
+            let val: TypedArray<ClampedU8> =
+                ctx.eval("new Uint8ClampedArray([300, 2.5, 3.5])").unwrap();
+            assert_eq!(
+                val.as_ref() as &[ClampedU8],
+                &[ClampedU8(255), ClampedU8(2), ClampedU8(4)]
+            );
+            assert!(ctx
+                .eval::<TypedArray<ClampedU8>, _>("new Uint8Array(2)")
+                .is_err());
+
+            let array: Array = ctx.eval("[300, 2.5, 3.5, -1, NaN]").unwrap();
+            let clamped = array.to_typed_array::<ClampedU8>().unwrap();
+            let expected: Vec<ClampedU8> = ctx
+                .eval("Array.from(new Uint8ClampedArray([300, 2.5, 3.5, -1, NaN]))")
+                .unwrap();
+            assert_eq!(clamped.as_ref() as &[ClampedU8], expected.as_slice());
+        });
+    }
 
     #[test]
     fn from_javascript_i8() {
@@ -425,4 +616,53 @@ mod test {
             assert_eq!(val.as_bytes().unwrap(), &res)
         });
     }
+
+    #[test]
+    fn fill() {
+        test_with(|ctx| {
+            let val = TypedArray::<u8>::new(ctx.clone(), [0u8; 6]).unwrap();
+            val.fill(7, 1..4).unwrap();
+            ctx.globals().set("v", val.clone()).unwrap();
+            let res: StdString = ctx.eval("v.join()").unwrap();
+            assert_eq!(res, "0,7,7,7,0,0");
+
+            let err = val.fill(1, 4..7).unwrap_err();
+            assert!(matches!(
+                err,
+                Error::AsSlice(AsSliceError::OutOfBounds {
+                    start: 4,
+                    end: 7,
+                    len: 6
+                })
+            ));
+            assert_eq!(unsafe { qjs::JS_HasException(ctx.as_ptr()) }, 0);
+            assert_eq!(val.as_ref() as &[u8], &[0, 7, 7, 7, 0, 0]);
+        })
+    }
+
+    #[test]
+    fn copy_within() {
+        test_with(|ctx| {
+            let val = TypedArray::<u16>::new(ctx.clone(), [1u16, 2, 3, 4, 5]).unwrap();
+            val.copy_within(0..3, 2).unwrap();
+            ctx.globals().set("v", val.clone()).unwrap();
+            let res: StdString = ctx.eval("v.join()").unwrap();
+            assert_eq!(res, "1,2,1,2,3");
+
+            assert!(val.copy_within(0..3, 3).is_err());
+            assert!(val.copy_within(4..6, 0).is_err());
+        })
+    }
+
+    #[test]
+    fn fill_detached() {
+        test_with(|ctx| {
+            let val = TypedArray::<u8>::new(ctx.clone(), [0u8; 4]).unwrap();
+            val.arraybuffer().unwrap().detach();
+            assert!(matches!(
+                val.fill(1, 0..0),
+                Err(Error::AsSlice(AsSliceError::BufferUsed))
+            ));
+        })
+    }
 }
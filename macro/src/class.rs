@@ -20,6 +20,7 @@ pub(crate) struct ClassConfig {
     pub crate_: Option<String>,
     pub rename: Option<String>,
     pub rename_all: Option<Case>,
+    pub extend: Vec<syn::Path>,
 }
 
 pub(crate) enum ClassOption {
@@ -27,6 +28,7 @@ pub(crate) enum ClassOption {
     Crate(ValueOption<Token![crate], LitStr>),
     Rename(ValueOption<kw::rename, LitStr>),
     RenameAll(ValueOption<kw::rename_all, Case>),
+    Extend(ValueOption<kw::extend, syn::Path>),
 }
 
 impl Parse for ClassOption {
@@ -39,6 +41,8 @@ impl Parse for ClassOption {
             input.parse().map(Self::Rename)
         } else if input.peek(kw::rename_all) {
             input.parse().map(Self::RenameAll)
+        } else if input.peek(kw::extend) {
+            input.parse().map(Self::Extend)
         } else {
             Err(syn::Error::new(input.span(), "invalid class attribute"))
         }
@@ -60,6 +64,9 @@ impl ClassConfig {
             ClassOption::RenameAll(ref x) => {
                 self.rename_all = Some(x.value);
             }
+            ClassOption::Extend(ref x) => {
+                self.extend.push(x.value.clone());
+            }
         }
     }
 
@@ -71,6 +78,35 @@ impl ClassConfig {
     }
 }
 
+#[derive(Debug, Default, Clone)]
+pub(crate) struct VariantConfig {
+    pub skip: bool,
+}
+
+pub(crate) enum VariantOption {
+    Skip(FlagOption<kw::skip>),
+}
+
+impl Parse for VariantOption {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(kw::skip) {
+            input.parse().map(Self::Skip)
+        } else {
+            Err(syn::Error::new(input.span(), "invalid variant attribute"))
+        }
+    }
+}
+
+impl VariantConfig {
+    pub fn apply(&mut self, option: &VariantOption) {
+        match option {
+            VariantOption::Skip(ref x) => {
+                self.skip = x.is_true();
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) enum Class {
     Enum {
@@ -81,6 +117,8 @@ pub(crate) enum Class {
         ident: Ident,
         generics: syn::Generics,
         variants: syn::punctuated::Punctuated<syn::Variant, Token![,]>,
+        /// Variants marked with `#[qjs(skip)]`.
+        skipped: Vec<Ident>,
     },
     Struct {
         config: ClassConfig,
@@ -151,22 +189,17 @@ impl Class {
             ..
         } = enum_;
 
+        let mut skipped = Vec::new();
         let mut new_variants = Punctuated::new();
         for variant in variants.into_pairs() {
             match variant {
                 Pair::Punctuated(v, c) => {
-                    let mut ensure_valid = ErrorAttribute(Ok(()));
-                    let v = ensure_valid.fold_variant(v);
-                    ensure_valid.0?;
-
+                    let v = Self::take_variant(v, &mut skipped)?;
                     new_variants.push(v);
                     new_variants.push_punct(c);
                 }
                 Pair::End(v) => {
-                    let mut ensure_valid = ErrorAttribute(Ok(()));
-                    let v = ensure_valid.fold_variant(v);
-                    ensure_valid.0?;
-
+                    let v = Self::take_variant(v, &mut skipped)?;
                     new_variants.push(v);
                 }
             }
@@ -193,9 +226,34 @@ impl Class {
             ident,
             generics,
             variants,
+            skipped,
         })
     }
 
+    /// Parses the options of a variant, removing the attributes and ensuring no other qjs
+    /// attributes are present.
+    fn take_variant(mut variant: syn::Variant, skipped: &mut Vec<Ident>) -> Result<syn::Variant> {
+        let mut config = VariantConfig::default();
+        take_attributes(&mut variant.attrs, |attr| {
+            if !attr.path().is_ident("qjs") {
+                return Ok(false);
+            }
+
+            let options: OptionList<VariantOption> = attr.parse_args()?;
+            options.0.iter().for_each(|x| config.apply(x));
+            Ok(true)
+        })?;
+
+        let mut ensure_valid = ErrorAttribute(Ok(()));
+        let variant = ensure_valid.fold_variant(variant);
+        ensure_valid.0?;
+
+        if config.skip {
+            skipped.push(variant.ident.clone());
+        }
+        Ok(variant)
+    }
+
     pub fn from_struct(mut config: ClassConfig, struct_: ItemStruct) -> Result<Self> {
         let ItemStruct {
             mut attrs,
@@ -281,6 +339,24 @@ impl Class {
         }
     }
 
+    /// Expands the checks which prevent skipped variants from being converted into JavaScript.
+    pub fn expand_skipped_check(&self, crate_name: &Ident) -> TokenStream {
+        let Class::Enum { ref skipped, .. } = self else {
+            return TokenStream::new();
+        };
+
+        let name = self.ident().to_string();
+        let checks = skipped.iter().map(|variant| {
+            let message = format!("variant `{name}::{variant}` is not exposed to JavaScript");
+            quote! {
+                if let Self::#variant{ .. } = &self {
+                    return Err(#crate_name::Error::new_into_js_message(#name, "object", #message));
+                }
+            }
+        });
+        quote!(#(#checks)*)
+    }
+
     // Aeexpand the original definition with the attributes removed..
     pub fn reexpand(&self) -> TokenStream {
         match self {
@@ -341,10 +417,45 @@ impl Class {
 
         let generics = self.generics().clone();
         let generics_with_lifetimes = add_js_lifetime(&generics);
+        let (impl_generics, _, _) = generics_with_lifetimes.split_for_impl();
+        let (_, ty_generics, _) = generics.split_for_impl();
+
+        // Every instantiation of a generic class is a distinct class. The type parameters must be
+        // usable as part of a class and the name has to come from the instantiation. Autoref
+        // specialization doesn't work for generic types, so the methods and constructor have to
+        // be implemented by a methods impl block for every instantiation.
+        let mut predicates: Vec<syn::WherePredicate> = generics
+            .where_clause
+            .iter()
+            .flat_map(|x| x.predicates.iter().cloned())
+            .collect();
+        let is_generic = generics.type_params().next().is_some();
+        for param in generics.type_params() {
+            let ident = &param.ident;
+            predicates.push(syn::parse_quote! {
+                #ident: 'static + #crate_name::class::Trace<'js> + #crate_name::JsLifetime<'js>
+            });
+        }
+        if is_generic {
+            predicates.push(syn::parse_quote! {
+                #crate_name::class::impl_::MethodImpl<Self>: #crate_name::class::impl_::MethodImplementor<Self>
+            });
+            predicates.push(syn::parse_quote! {
+                #crate_name::class::impl_::ConstructorCreate<Self>: #crate_name::class::impl_::ConstructorCreator<'js, Self>
+            });
+        }
+        let javascript_name = if is_generic && self.config().rename.is_none() {
+            predicates.push(syn::parse_quote!(Self: #crate_name::class::ClassName));
+            quote!(<Self as #crate_name::class::ClassName>::NAME)
+        } else {
+            quote!(#javascript_name)
+        };
 
         let mutability = self.mutability();
         let props = self.expand_props(&crate_name);
+        let skipped_check = self.expand_skipped_check(&crate_name);
         let reexpand = self.reexpand();
+        let extend = &self.config().extend;
 
         let res = quote! {
             #reexpand
@@ -353,7 +464,7 @@ impl Class {
             mod #module_name{
                 pub use super::*;
 
-                impl #generics_with_lifetimes #crate_name::class::JsClass<'js> for #class_name #generics{
+                impl #impl_generics #crate_name::class::JsClass<'js> for #class_name #ty_generics where #(#predicates,)* {
                     const NAME: &'static str = #javascript_name;
 
                     type Mutable = #crate_name::class::#mutability;
@@ -365,6 +476,7 @@ impl Class {
                         #props
                         let implementor = #crate_name::class::impl_::MethodImpl::<Self>::new();
                         (&implementor).implement(&proto)?;
+                        #(<#extend as MethodImplementor<Self>>::implement(&#extend, &proto)?;)*
                         Ok(Some(proto))
                     }
 
@@ -376,15 +488,17 @@ impl Class {
                     }
                 }
 
-                impl #generics_with_lifetimes #crate_name::IntoJs<'js> for #class_name #generics{
+                impl #impl_generics #crate_name::IntoJs<'js> for #class_name #ty_generics where #(#predicates,)* {
                     fn into_js(self,ctx: &#crate_name::Ctx<'js>) -> #crate_name::Result<#crate_name::Value<'js>>{
+                        #skipped_check
                         let cls = #crate_name::class::Class::<Self>::instance(ctx.clone(),self)?;
                         #crate_name::IntoJs::into_js(cls, ctx)
                     }
                 }
 
-                impl #generics_with_lifetimes #crate_name::FromJs<'js> for #class_name #generics
+                impl #impl_generics #crate_name::FromJs<'js> for #class_name #ty_generics
                 where
+                    #(#predicates,)*
                     for<'a> #crate_name::class::impl_::CloneWrapper<'a,Self>: #crate_name::class::impl_::CloneTrait<Self>,
                 {
                     fn from_js(ctx: &#crate_name::Ctx<'js>, value: #crate_name::Value<'js>) -> #crate_name::Result<Self>{
@@ -129,6 +129,7 @@ pub(crate) mod kw {
     syn::custom_keyword!(frozen);
     syn::custom_keyword!(skip_trace);
     syn::custom_keyword!(rename);
+    syn::custom_keyword!(predicate);
     syn::custom_keyword!(rename_all);
     syn::custom_keyword!(rename_vars);
     syn::custom_keyword!(rename_types);
@@ -141,4 +142,5 @@ pub(crate) mod kw {
     syn::custom_keyword!(prefix);
     syn::custom_keyword!(declare);
     syn::custom_keyword!(evaluate);
+    syn::custom_keyword!(extend);
 }
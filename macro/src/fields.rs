@@ -1,9 +1,9 @@
 use convert_case::Casing;
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
-    Attribute, Ident, LitStr, Result, Type, Visibility,
+    Attribute, Error, Ident, LitStr, Path, Result, Type, Visibility,
 };
 
 use crate::{
@@ -19,6 +19,7 @@ pub struct FieldConfig {
     pub configurable: bool,
     pub skip_trace: bool,
     pub rename: Option<String>,
+    pub predicate: Option<LitStr>,
 }
 
 #[derive(Debug)]
@@ -29,6 +30,7 @@ pub(crate) enum FieldOption {
     Configurable(FlagOption<kw::configurable>),
     SkipTrace(FlagOption<kw::skip_trace>),
     Rename(ValueOption<kw::rename, LitStr>),
+    Predicate(ValueOption<kw::predicate, LitStr>),
 }
 
 impl Parse for FieldOption {
@@ -45,6 +47,8 @@ impl Parse for FieldOption {
             input.parse().map(Self::SkipTrace)
         } else if input.peek(kw::rename) {
             input.parse().map(Self::Rename)
+        } else if input.peek(kw::predicate) {
+            input.parse().map(Self::Predicate)
         } else {
             Err(syn::Error::new(
                 input.span(),
@@ -68,6 +72,16 @@ impl FieldConfig {
             Ok(true)
         })?;
 
+        if let Some(predicate) = config.predicate.as_ref() {
+            predicate.parse::<Path>()?;
+            if !config.get {
+                return Err(Error::new(
+                    predicate.span(),
+                    "the `predicate` option requires the `get` option",
+                ));
+            }
+        }
+
         Ok(config)
     }
 
@@ -91,6 +105,9 @@ impl FieldConfig {
             FieldOption::Rename(ref x) => {
                 self.rename = Some(x.value.value());
             }
+            FieldOption::Predicate(ref x) => {
+                self.predicate = Some(x.value.clone());
+            }
         }
     }
 }
@@ -196,8 +213,6 @@ impl Field {
         let field = self.ident.as_ref().unwrap();
         let ty = &self.ty;
 
-        let accessor = self.expand_accessor(field, crate_name, ty);
-        let prop_config = self.expand_prop_config();
         let name = if let Some(rename) = self.config.rename.clone() {
             rename
         } else if let Some(case) = case {
@@ -205,6 +220,8 @@ impl Field {
         } else {
             field.to_string()
         };
+        let accessor = self.expand_accessor(field, &name, crate_name, ty);
+        let prop_config = self.expand_prop_config();
 
         quote! {
             proto.prop(#name, #accessor #prop_config)?;
@@ -218,26 +235,64 @@ impl Field {
 
         let field = format_ident!("{}", name);
         let ty = &self.ty;
-        let accessor = self.expand_accessor(&field, crate_name, ty);
-        let prop_config = self.expand_prop_config();
-        let name = if let Some(rename) = self.config.rename.clone() {
-            quote!(#rename)
+        let (accessor, name) = if let Some(rename) = self.config.rename.clone() {
+            (
+                self.expand_accessor(&field, &rename, crate_name, ty),
+                quote!(#rename),
+            )
         } else {
-            quote!(#name as u32)
+            (
+                self.expand_accessor(&field, &name.to_string(), crate_name, ty),
+                quote!(#name as u32),
+            )
         };
+        let prop_config = self.expand_prop_config();
 
         quote! {
             proto.prop(#name, #accessor #prop_config)?;
         }
     }
 
-    pub fn expand_accessor(&self, field: &Ident, crate_name: &Ident, ty: &Type) -> TokenStream {
+    /// Expands the getter closure, which checks the predicate first if there is one.
+    fn expand_getter(&self, field: &Ident, name: &str, crate_name: &Ident) -> TokenStream {
+        let Some(predicate) = self.config.predicate.as_ref() else {
+            return quote! {
+                |this: #crate_name::function::This<#crate_name::class::OwnedBorrow<'js, Self>>|{
+                    this.0.#field.clone()
+                }
+            };
+        };
+        let predicate = match predicate.parse::<Path>() {
+            Ok(x) => x,
+            Err(e) => return e.into_compile_error(),
+        };
+        let message = format!(
+            "property `{}` is not available: `{}` returned false",
+            name,
+            predicate.to_token_stream().to_string().replace(' ', "")
+        );
+        quote! {
+            |ctx: #crate_name::Ctx<'js>, this: #crate_name::function::This<#crate_name::class::OwnedBorrow<'js, Self>>|{
+                if !#predicate(&*this.0) {
+                    return Err(#crate_name::Exception::throw_type(&ctx, #message));
+                }
+                #crate_name::Result::Ok(this.0.#field.clone())
+            }
+        }
+    }
+
+    pub fn expand_accessor(
+        &self,
+        field: &Ident,
+        name: &str,
+        crate_name: &Ident,
+        ty: &Type,
+    ) -> TokenStream {
+        let getter = self.expand_getter(field, name, crate_name);
         if self.config.get && self.config.set {
             quote! {
                 #crate_name::object::Accessor::new(
-                    |this: #crate_name::function::This<#crate_name::class::OwnedBorrow<'js, Self>>|{
-                        this.0.#field.clone()
-                    },
+                    #getter,
                     |mut this: #crate_name::function::This<#crate_name::class::OwnedBorrowMut<'js, Self>>, v: #ty|{
                         this.0.#field = v;
                     }
@@ -246,9 +301,7 @@ impl Field {
         } else if self.config.get {
             quote! {
                 #crate_name::object::Accessor::new_get(
-                    |this: #crate_name::function::This<#crate_name::class::OwnedBorrow<'js, Self>>|{
-                        this.0.#field.clone()
-                    },
+                    #getter,
                 )
             }
         } else if self.config.set {
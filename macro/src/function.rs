@@ -7,7 +7,7 @@ use syn::{
     punctuated::Punctuated,
     spanned::Spanned,
     token::Comma,
-    Error, FnArg, LitStr, Result, Signature, Token, Type, Visibility,
+    Error, FnArg, LitStr, Result, ReturnType, Signature, Token, Type, TypeParamBound, Visibility,
 };
 
 use crate::{
@@ -129,6 +129,49 @@ pub(crate) fn expand(
     })
 }
 
+/// The kind of closure returned from a function with a `-> impl Fn..` return type.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum ClosureKind {
+    Fn,
+    FnMut,
+    FnOnce,
+}
+
+impl ClosureKind {
+    pub fn from_return_type(output: &ReturnType) -> Option<Self> {
+        let ReturnType::Type(_, ty) = output else {
+            return None;
+        };
+        let Type::ImplTrait(impl_trait) = &**ty else {
+            return None;
+        };
+        impl_trait.bounds.iter().find_map(|bound| {
+            let TypeParamBound::Trait(bound) = bound else {
+                return None;
+            };
+            match bound.path.segments.last()?.ident.to_string().as_str() {
+                "Fn" => Some(ClosureKind::Fn),
+                "FnMut" => Some(ClosureKind::FnMut),
+                "FnOnce" => Some(ClosureKind::FnOnce),
+                _ => None,
+            }
+        })
+    }
+
+    /// Expands the conversion of the returned closure into a type which implements `IntoJs`.
+    pub fn expand_wrap(&self, lib_crate: &Ident, value: TokenStream) -> TokenStream {
+        match self {
+            ClosureKind::Fn => quote!(#lib_crate::function::Func::new(#value)),
+            ClosureKind::FnMut => quote! {
+                #lib_crate::function::Func::new(#lib_crate::function::MutFn::new(#value))
+            },
+            ClosureKind::FnOnce => quote! {
+                #lib_crate::function::Func::new(#lib_crate::function::OnceFn::new(#value))
+            },
+        }
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct JsFunction {
     pub vis: Visibility,
@@ -136,6 +179,8 @@ pub(crate) struct JsFunction {
     pub rust_function: TokenStream,
     pub is_async: bool,
     pub params: JsParams,
+    /// Set if the function returns a closure which should be turned into a JavaScript function.
+    pub returns_closure: Option<ClosureKind>,
 }
 
 impl JsFunction {
@@ -147,6 +192,7 @@ impl JsFunction {
             ref variadic,
             ref ident,
             ref inputs,
+            ref output,
             ..
         } = sig;
 
@@ -181,9 +227,24 @@ impl JsFunction {
             is_async,
             rust_function,
             params,
+            returns_closure: ClosureKind::from_return_type(output),
         })
     }
 
+    /// Borrow class parameters of an async function when its future starts running instead of
+    /// when the function is called.
+    ///
+    /// Borrowing when the function is called would keep the class borrowed until the future
+    /// first runs, which conflicts with setting a property right after reading it.
+    pub fn defer_borrows(&mut self) {
+        if self.is_async {
+            self.params
+                .params
+                .iter_mut()
+                .for_each(|x| x.defer_borrow = true);
+        }
+    }
+
     pub fn expand_carry_type_name(&self, prefix: &str) -> Ident {
         format_ident!("{}{}", prefix, self.name)
     }
@@ -215,19 +276,33 @@ impl JsFunction {
         let rust_function = &self.rust_function;
 
         if self.is_async {
+            let borrows = self.params.expand_deferred_borrow();
+            let call = if borrows.is_empty() {
+                quote!(#rust_function(#arg_apply).await)
+            } else {
+                quote! {
+                    #borrows
+                    Ok::<_, #lib_crate::Error>(#rust_function(#arg_apply).await)
+                }
+            };
             quote! {
                 #arg_extract
 
                 let fut = async move {
-                    #rust_function(#arg_apply).await
+                    #call
                 };
 
                 #lib_crate::IntoJs::into_js(#lib_crate::promise::Promised(fut), &ctx)
             }
         } else {
+            let res = quote!(#rust_function(#arg_apply));
+            let res = match self.returns_closure {
+                Some(kind) => kind.expand_wrap(lib_crate, res),
+                None => res,
+            };
             quote! {
                 #arg_extract
-                let res = #rust_function(#arg_apply);
+                let res = #res;
                 #lib_crate::IntoJs::into_js(res,&ctx)
             }
         }
@@ -286,6 +361,14 @@ impl JsParams {
         let res = self.params.iter().map(|x| x.expand_extract(lib_crate));
         quote!(#(#res)*)
     }
+
+    pub fn expand_deferred_borrow(&self) -> TokenStream {
+        let res = self
+            .params
+            .iter()
+            .filter_map(|x| x.expand_deferred_borrow());
+        quote!(#(#res)*)
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -301,12 +384,15 @@ pub(crate) struct JsParam {
     number: usize,
     tokens: TokenStream,
     is_this: bool,
+    /// Extract a class instead of a borrow and only borrow it when the future of an async
+    /// function starts running.
+    defer_borrow: bool,
 }
 
 impl JsParam {
     pub fn expand_binding(&self) -> TokenStream {
         let tmp = format_ident!("tmp_{}", self.number);
-        if let ParamKind::BorrowMut = self.kind {
+        if let (ParamKind::BorrowMut, false) = (self.kind, self.defer_borrow) {
             quote! { mut #tmp }
         } else {
             quote! { #tmp }
@@ -320,7 +406,7 @@ impl JsParam {
             ParamKind::Borrow => quote!(&*#t),
             ParamKind::BorrowMut => quote!(&mut *#t),
         };
-        if self.is_this {
+        if self.is_this && (!self.defer_borrow || matches!(self.kind, ParamKind::Value)) {
             quote!(#apply.0)
         } else {
             apply
@@ -331,6 +417,9 @@ impl JsParam {
         let t = &self.tokens;
         let ty = match self.kind {
             ParamKind::Value => quote!(#t),
+            ParamKind::Borrow | ParamKind::BorrowMut if self.defer_borrow => {
+                quote!(#lib_crate::Class<'js,#t>)
+            }
             ParamKind::Borrow => quote!(#lib_crate::class::OwnedBorrow<'js,#t>),
             ParamKind::BorrowMut => quote!(#lib_crate::class::OwnedBorrowMut<'js,#t>),
         };
@@ -343,6 +432,25 @@ impl JsParam {
         }
     }
 
+    /// Expands the borrow of a deferred borrow parameter inside the future of an async function.
+    pub fn expand_deferred_borrow(&self) -> Option<TokenStream> {
+        let tmp = format_ident!("tmp_{}", self.number);
+        let borrow = match (self.kind, self.defer_borrow) {
+            (ParamKind::Borrow, true) => quote!(let #tmp = #tmp.try_borrow()?;),
+            (ParamKind::BorrowMut, true) => quote!(let mut #tmp = #tmp.try_borrow_mut()?;),
+            _ => return None,
+        };
+        let class = if self.is_this {
+            quote!(#tmp.0)
+        } else {
+            quote!(#tmp)
+        };
+        Some(quote! {
+            let #tmp = #class;
+            #borrow
+        })
+    }
+
     pub fn expand_extract(&self, lib_crate: &Ident) -> TokenStream {
         let ty = self.expand_type(lib_crate);
         let binding = self.expand_binding();
@@ -398,6 +506,7 @@ impl JsParams {
                         tokens: stream,
                         number: idx,
                         is_this: false,
+                        defer_borrow: false,
                     });
                 }
                 FnArg::Receiver(recv) => {
@@ -419,6 +528,7 @@ impl JsParams {
                             number: idx,
                             tokens: stream,
                             is_this: true,
+                            defer_borrow: false,
                         })
                     } else {
                         return Err(Error::new(
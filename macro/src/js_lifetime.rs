@@ -7,7 +7,7 @@ use syn::{
 
 use crate::{
     attrs::{take_attributes, OptionList},
-    common::crate_ident,
+    common::{add_js_lifetime, crate_ident},
     trace::{ImplConfig, TraceOption},
 };
 
@@ -109,9 +109,12 @@ pub fn extract_bounds(generics: &Generics) -> Result<Vec<TokenStream>> {
     for p in generics.params.iter() {
         match p {
             GenericParam::Lifetime(_) => {}
-            GenericParam::Type(x) => res.push(quote! {
-                #x: JsLifetime<'js>
-            }),
+            GenericParam::Type(x) => {
+                let ident = &x.ident;
+                res.push(quote! {
+                    #ident: JsLifetime<'js>
+                })
+            }
             GenericParam::Const(_) => {}
         }
     }
@@ -144,11 +147,18 @@ pub(crate) fn expand(mut input: DeriveInput) -> Result<TokenStream> {
     let bounds = extract_bounds(generics)?;
 
     let Some(lt) = lt else {
+        // Without a lifetime the type is the same for every lifetime, which requires type
+        // parameters to be 'static.
+        let impl_generics = add_js_lifetime(generics);
+        let (impl_generics, _, _) = impl_generics.split_for_impl();
+        let (_, ty_generics, where_clause) = generics.split_for_impl();
+        let where_clause = where_clause.map(|x| &x.predicates);
+        let static_bounds = generics.type_params().map(|x| &x.ident);
         let res = quote! {
-            unsafe impl<'js> #crate_name::JsLifetime<'js> for #name #generics
-                where #(#bounds),*
+            unsafe impl #impl_generics #crate_name::JsLifetime<'js> for #name #ty_generics
+                where #(#bounds,)* #(#static_bounds: 'static,)* #where_clause
             {
-                type Changed<'to> = #name;
+                type Changed<'to> = #name #ty_generics;
             }
         };
         return Ok(res);
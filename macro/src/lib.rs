@@ -42,6 +42,7 @@ mod trace;
 /// | `rename`     | String    | Changes the name of the implemented class on the JavaScript side.                                                                                                                       |
 /// | `rename_all` | Casing    | Converts the case of all the fields of this struct which have implement accessors. Can be one of `lowercase`, `UPPERCASE`, `camelCase`, `PascalCase`,`snake_case`, or `SCREAMING_SNAKE` |
 /// | `frozen`     | Flag      | Changes the class implementation to only allow borrowing immutably.  Trying to borrow mutably will result in an error.                                                                  |
+/// | `extend`     | Path      | Adds the methods of an impl block marked with `#[methods(extend = Name)]` to the class prototype. Can be specified multiple times.                                                      |
 ///
 /// # Field options
 ///
@@ -57,7 +58,23 @@ mod trace;
 /// | `configurable` | Flag      | Makes the field, if it has a getter or setter, configurable in JavaScript.              |
 /// | `skip_trace`   | Flag      | Skips the field deriving the `Trace` trait.                                             |
 /// | `rename`       | String    | Changes the name of the field getter and/or setter to the specified name in JavaScript. |
+/// | `predicate`    | String    | Path to a `fn(&self) -> bool`, the getter throws a `TypeError` when it returns false.   |
 ///
+/// # Variant options
+///
+/// The variants of an enum can be tagged with an attribute in the form of `#[qjs(option = value)]`.
+///
+/// | **Option** | **Value** | **Description**                                                                                                       |
+/// |------------|-----------|-----------------------------------------------------------------------------------------------------------------------|
+/// | `skip`     | Flag      | Hides the variant from JavaScript. Converting the variant into JavaScript, for example from a constructor, is an error. |
+///
+/// # Generic classes
+///
+/// The attribute can be applied to a type with type parameters which don't contain the `'js`
+/// lifetime, like `struct Wrapper<T>(T)`. Every instantiation, like `Wrapper<A>` and `Wrapper<B>`,
+/// is a distinct class. The JavaScript name of an instantiation is taken from
+/// [`ClassName`](rquickjs_core::class::ClassName) unless `rename` is used, and every instantiation
+/// needs its own `#[rquickjs::methods]` impl block, even if it is empty.
 ///
 /// # Example
 /// ```
@@ -128,6 +145,9 @@ pub fn class(attr: TokenStream1, item: TokenStream1) -> TokenStream1 {
 /// then when you use closures or the functions for which the proper traits are already
 /// implemented..
 ///
+/// A function returning `impl Fn(..)`, `impl FnMut(..)` or `impl FnOnce(..)` has the returned
+/// closure converted into a JavaScript function, so a function can construct callbacks lazily.
+///
 #[proc_macro_attribute]
 pub fn function(attr: TokenStream1, item: TokenStream1) -> TokenStream1 {
     let options = parse_macro_input!(attr as OptionList<FunctionOption>);
@@ -153,7 +173,10 @@ pub fn function(attr: TokenStream1, item: TokenStream1) -> TokenStream1 {
 ///
 /// # Limitations
 /// Due to limitations in the Rust type system this attribute can be used on only one impl block
-/// per type.
+/// per type. Methods can be split across additional impl blocks by marking them with
+/// `#[methods(extend = Name)]`, which defines a marker type `Name` that must then be registered on
+/// the class with `#[class(extend = Name)]`. Extending impl blocks can't define a constructor or
+/// static methods.
 ///
 /// # Attribute options
 ///
@@ -167,7 +190,8 @@ pub fn function(attr: TokenStream1, item: TokenStream1) -> TokenStream1 {
 /// |--------------|-----------|-----------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------|
 /// | `crate`      | String    | Changes the name from which the attribute tries to use rquickjs types. Use when the name behind which the rquickjs crate is declared is not properly resolved by the macro.             |
 /// | `rename`     | String    | Changes the name of the implemented class on the JavaScript side.                                                                                                                       |
-/// | `rename_all` | Casing    | Converts the case of all the fields of this struct which have implement accessors. Can be one of `lowercase`, `UPPERCASE`, `camelCase`, `PascalCase`,`snake_case`, or `SCREAMING_SNAKE` |
+/// | `rename_all` | Casing    | Converts the case of the names of all methods, static methods and accessors in this impl block which aren't renamed with `rename`. Can be one of `lowercase`, `UPPERCASE`, `camelCase`, `PascalCase`,`snake_case`, or `SCREAMING_SNAKE` |
+/// | `extend`     | Ident     | Marks this impl block as extending the methods of the primary impl block, defining a marker type with the given name to register on the class.                                         |
 ///
 ///
 /// # Item options
@@ -186,6 +210,24 @@ pub fn function(attr: TokenStream1, item: TokenStream1) -> TokenStream1 {
 /// | `constructor`  | Flag                                                              | Marks this method a the constructor for this type.                                              |
 /// | `skip`         | Flag                                                              | Skips defining this method on the JavaScript class.                                             |
 ///
+/// The `rename_all` option of the `class` attribute only applies to the fields of the struct. To
+/// expose a method like `fn do_thing` as `doThing`, set `rename_all` on the `methods` attribute as
+/// well: `#[rquickjs::methods(rename_all = "camelCase")]`.
+///
+/// The value of `rename` is not limited to literals, it can be any expression which evaluates to a
+/// type implementing [`IntoAtom`](rquickjs_core::IntoAtom), for example a `const` or a name built
+/// with `concat!("get_", "x")`. The expression is evaluated when the class prototype is
+/// created.
+///
+/// A constructor can take a [`NewTarget`](rquickjs_core::function::NewTarget) parameter to find out
+/// whether it was called with `new` and which constructor, possibly of a JavaScript subclass, `new`
+/// was called on.
+///
+/// With the `futures` feature methods, getters and setters can be `async`, they then return a
+/// promise which resolves to the result of the method. `self` and other class arguments taken by
+/// reference are only borrowed once the future starts running and stay borrowed until it has
+/// finished.
+///
 /// # Example
 /// ```
 /// use rquickjs::{
@@ -345,6 +387,7 @@ pub fn methods(attr: TokenStream1, item: TokenStream1) -> TokenStream1 {
 /// |------------|-----------|----------------|------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------|
 /// | `skip`     | Flag      | All            | Skips exporting this item from the JavaScript module.                                                                                                                                                      |
 /// | `rename`   | String    | All except use | Change the name from which this value is exported.                                                                                                                                                         |
+/// | `default`  | Flag      | All            | Also exports this item as the default export of the module, allowing it to be imported with `import item from 'module'`. Only one item can be the default export.                                            |
 /// | `declare`  | Flag      | Functions Only | Marks this function as the declaration function. This function will be called when the module is declared allowing for exporting items which otherwise are difficult to export using the attribute.        |
 /// | `evaluate` | Flag      | Functions Only | Marks this function as the evaluation function. This function will be called when the module is being evaluated allowing for exporting items which otherwise are difficult to export using the attribute.  |
 ///
@@ -486,6 +529,11 @@ pub fn module(attr: TokenStream1, item: TokenStream1) -> TokenStream1 {
 }
 
 /// A macro for auto deriving the trace trait.
+///
+/// Every field is traced with its own `Trace` implementation. Besides JavaScript values this
+/// includes std containers like `Vec<T>`, `Option<T>`, `HashMap<K, T>` and tuples, which trace
+/// each of their elements. Fields which don't implement `Trace` can be skipped with
+/// `#[qjs(skip_trace)]`.
 #[proc_macro_derive(Trace, attributes(qjs))]
 pub fn trace(stream: TokenStream1) -> TokenStream1 {
     let derive_input = parse_macro_input!(stream as DeriveInput);
@@ -5,7 +5,7 @@ use quote::{format_ident, quote};
 use syn::{
     parse::{Parse, ParseStream},
     spanned::Spanned,
-    Error, ItemImpl, LitStr, Result, Token, Type,
+    Error, GenericArgument, Ident, ItemImpl, LitStr, PathArguments, Result, Token, Type,
 };
 
 use crate::{
@@ -23,6 +23,7 @@ pub(crate) struct ImplConfig {
     pub(crate) prefix: Option<String>,
     pub(crate) crate_: Option<String>,
     pub(crate) rename_all: Option<Case>,
+    pub(crate) extend: Option<Ident>,
 }
 
 impl ImplConfig {
@@ -37,6 +38,9 @@ impl ImplConfig {
             ImplOption::RenameAll(x) => {
                 self.rename_all = Some(x.value);
             }
+            ImplOption::Extend(x) => {
+                self.extend = Some(x.value.clone());
+            }
         }
     }
 }
@@ -45,6 +49,7 @@ pub(crate) enum ImplOption {
     Prefix(ValueOption<kw::prefix, LitStr>),
     Crate(ValueOption<Token![crate], LitStr>),
     RenameAll(ValueOption<kw::rename_all, Case>),
+    Extend(ValueOption<kw::extend, Ident>),
 }
 
 impl Parse for ImplOption {
@@ -55,6 +60,8 @@ impl Parse for ImplOption {
             input.parse().map(Self::Crate)
         } else if input.peek(kw::rename_all) {
             input.parse().map(Self::RenameAll)
+        } else if input.peek(kw::extend) {
+            input.parse().map(Self::Extend)
         } else {
             Err(syn::Error::new(input.span(), "invalid impl attribute"))
         }
@@ -65,7 +72,21 @@ pub fn get_class_name(ty: &Type) -> String {
     match ty {
         Type::Array(_) => todo!(),
         Type::Paren(x) => get_class_name(&x.elem),
-        Type::Path(x) => x.path.segments.first().unwrap().ident.to_string(),
+        Type::Path(x) => {
+            let mut name = x.path.segments.first().unwrap().ident.to_string();
+            // Include type arguments so impl blocks for different instantiations of a generic
+            // class don't collide.
+            if let PathArguments::AngleBracketed(args) = &x.path.segments.last().unwrap().arguments
+            {
+                for arg in args.args.iter() {
+                    if let GenericArgument::Type(ty) = arg {
+                        name.push('_');
+                        name.push_str(&get_class_name(ty));
+                    }
+                }
+            }
+            name
+        }
         Type::Tuple(x) => {
             let name = x
                 .elems
@@ -171,6 +192,31 @@ pub(crate) fn expand(options: OptionList<ImplOption>, item: ItemImpl) -> Result<
         }
     }
 
+    if let Some(extend) = config.extend.as_ref() {
+        if let Some(constr) = constructor.as_ref() {
+            let mut error = Error::new(
+                constr.attr_span,
+                "A constructor can't be defined in an extending impl block",
+            );
+            error.extend(Error::new(
+                extend.span(),
+                "impl block marked as extending here",
+            ));
+            return Err(error);
+        }
+        if let Some(span) = static_span {
+            let mut error = Error::new(
+                span,
+                "Static methods can't be defined in an extending impl block",
+            );
+            error.extend(Error::new(
+                extend.span(),
+                "impl block marked as extending here",
+            ));
+            return Err(error);
+        }
+    }
+
     // Warn about unused static definitions if no constructor was created.
     /* if constructor.is_none() {
         if let Some(span) = static_span {
@@ -240,12 +286,37 @@ pub(crate) fn expand(options: OptionList<ImplOption>, item: ItemImpl) -> Result<
                 }
             }
         }
+    } else if config.extend.is_none() {
+        // Explicitly implement the creator without a constructor so generic classes, which can't
+        // rely on autoref specialization, can require it.
+        let js_added_generics = add_js_lifetime(&generics);
+        quote! {
+            impl #js_added_generics #crate_name::class::impl_::ConstructorCreator<'js,#self_ty> for #crate_name::class::impl_::ConstructorCreate<#self_ty> {}
+        }
     } else {
         TokenStream::new()
     };
 
     let class_name = get_class_name(&self_ty);
-    let impl_mod_name = format_ident!("__impl_methods_{class_name}__");
+    let (impl_mod_name, implementor, extend_decl) = if let Some(extend) = config.extend.as_ref() {
+        let doc = format!(
+            "Additional methods for [`{class_name}`], add to the class with `#[class(extend = {extend})]`."
+        );
+        (
+            format_ident!("__impl_methods_{class_name}_{extend}__"),
+            quote!(#extend),
+            quote! {
+                #[doc = #doc]
+                pub struct #extend;
+            },
+        )
+    } else {
+        (
+            format_ident!("__impl_methods_{class_name}__"),
+            quote!(#crate_name::class::impl_::MethodImpl<#self_ty>),
+            TokenStream::new(),
+        )
+    };
 
     let res = quote! {
         #(#attrs)*
@@ -255,6 +326,7 @@ pub(crate) fn expand(options: OptionList<ImplOption>, item: ItemImpl) -> Result<
             #constructor_impl
         }
 
+        #extend_decl
 
         mod #impl_mod_name{
             pub use super::*;
@@ -267,7 +339,7 @@ pub(crate) fn expand(options: OptionList<ImplOption>, item: ItemImpl) -> Result<
                 #(#associated_types)*
             }
 
-            impl #generics #crate_name::class::impl_::MethodImplementor<#self_ty> for #crate_name::class::impl_::MethodImpl<#self_ty> {
+            impl #generics #crate_name::class::impl_::MethodImplementor<#self_ty> for #implementor {
                 fn implement(&self, _proto: &#crate_name::Object<'_>) -> #crate_name::Result<()>{
                     #(#function_apply_proto)*
                     #(#accessor_apply_proto)*
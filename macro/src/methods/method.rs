@@ -188,7 +188,10 @@ impl Method {
 
         attrs.retain(|x| !x.path().is_ident("qjs"));
 
-        let function = JsFunction::new(vis.clone(), &sig, Some(self_ty))?;
+        let mut function = JsFunction::new(vis.clone(), &sig, Some(self_ty))?;
+        if config.get || config.set {
+            function.defer_borrows();
+        }
 
         Ok(Method {
             config,
@@ -86,6 +86,7 @@ pub(crate) struct ModuleFunctionConfig {
     pub declare: bool,
     pub evaluate: bool,
     pub skip: bool,
+    pub default: bool,
     pub function: FunctionConfig,
 }
 
@@ -104,6 +105,9 @@ impl ModuleFunctionConfig {
             ModuleFunctionOption::Skip(x) => {
                 self.skip = x.is_true();
             }
+            ModuleFunctionOption::Default(x) => {
+                self.default = x.is_true();
+            }
         }
     }
 
@@ -134,6 +138,12 @@ impl ModuleFunctionConfig {
         if self.skip && self.declare {
             return Err(Error::new(span, "Can't skip the module declare function"));
         }
+        if self.default && (self.declare || self.evaluate) {
+            return Err(Error::new(
+                span,
+                "The module declare or evaluate function can't be the default export.",
+            ));
+        }
         if self.declare && self.evaluate {
             return Err(Error::new(
                 span,
@@ -148,6 +158,7 @@ pub(crate) enum ModuleFunctionOption {
     Declare(FlagOption<kw::declare>),
     Evaluate(FlagOption<kw::evaluate>),
     Skip(FlagOption<kw::skip>),
+    Default(FlagOption<Token![default]>),
     Function(FunctionOption),
 }
 
@@ -159,6 +170,8 @@ impl Parse for ModuleFunctionOption {
             input.parse().map(Self::Evaluate)
         } else if input.peek(kw::skip) {
             input.parse().map(Self::Skip)
+        } else if input.peek(Token![default]) {
+            input.parse().map(Self::Default)
         } else {
             input.parse().map(Self::Function)
         }
@@ -168,6 +181,7 @@ impl Parse for ModuleFunctionOption {
 #[derive(Default, Debug)]
 pub(crate) struct ModuleTypeConfig {
     pub skip: bool,
+    pub default: bool,
     pub class: ClassConfig,
     pub class_macro_name: Option<Path>,
 }
@@ -178,6 +192,9 @@ impl ModuleTypeConfig {
             ModuleTypeOption::Skip(x) => {
                 self.skip = x.is_true();
             }
+            ModuleTypeOption::Default(x) => {
+                self.default = x.is_true();
+            }
             ModuleTypeOption::Class(x) => self.class.apply(x),
         }
     }
@@ -209,6 +226,7 @@ impl ModuleTypeConfig {
 
 pub(crate) enum ModuleTypeOption {
     Skip(FlagOption<kw::skip>),
+    Default(FlagOption<Token![default]>),
     Class(ClassOption),
 }
 
@@ -216,6 +234,8 @@ impl Parse for ModuleTypeOption {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         if input.peek(kw::skip) {
             input.parse().map(Self::Skip)
+        } else if input.peek(Token![default]) {
+            input.parse().map(Self::Default)
         } else {
             input.parse().map(Self::Class)
         }
@@ -225,6 +245,7 @@ impl Parse for ModuleTypeOption {
 #[derive(Default, Debug)]
 pub(crate) struct ModuleItemConfig {
     pub skip: bool,
+    pub default: bool,
     pub rename: Option<String>,
 }
 
@@ -237,6 +258,9 @@ impl ModuleItemConfig {
             ModuleItemOption::Rename(x) => {
                 self.rename = Some(x.value.value());
             }
+            ModuleItemOption::Default(x) => {
+                self.default = x.is_true();
+            }
         }
     }
 
@@ -256,6 +280,7 @@ impl ModuleItemConfig {
 pub(crate) enum ModuleItemOption {
     Skip(FlagOption<kw::skip>),
     Rename(ValueOption<kw::rename, LitStr>),
+    Default(FlagOption<Token![default]>),
 }
 
 impl Parse for ModuleItemOption {
@@ -264,6 +289,8 @@ impl Parse for ModuleItemOption {
             input.parse().map(Self::Skip)
         } else if input.peek(kw::rename) {
             input.parse().map(Self::Rename)
+        } else if input.peek(Token![default]) {
+            input.parse().map(Self::Default)
         } else {
             Err(syn::Error::new(
                 input.span(),
@@ -18,6 +18,8 @@ pub(crate) struct JsModule {
     pub config: ModuleConfig,
     pub name: Ident,
     pub declaration: HashMap<String, (Span, TokenStream)>,
+    /// The span of the item which is exported as the default export, if any.
+    pub default: Option<Span>,
 }
 
 impl JsModule {
@@ -26,6 +28,7 @@ impl JsModule {
             config,
             name: item.ident.clone(),
             declaration: HashMap::new(),
+            default: None,
         }
     }
 
@@ -48,11 +51,60 @@ impl JsModule {
         }
     }
 
+    /// Export the value of an expression under the given JavaScript name and, if `default` is
+    /// set, also as the default export of the module.
+    pub fn export_value(
+        &mut self,
+        name: String,
+        js_name: String,
+        span: Span,
+        value: TokenStream,
+        default: bool,
+    ) -> Result<()> {
+        if !default {
+            self.export(
+                name,
+                span,
+                quote! {
+                    _exports.export(#js_name,#value)?;
+                },
+            );
+            return Ok(());
+        }
+
+        if let Some(prev_span) = self.default {
+            let mut error = Error::new(span, "Found a second default export in module.");
+            error.combine(Error::new(prev_span, "First default export here."));
+            return Err(error);
+        }
+        self.default = Some(span);
+
+        let crate_name = Ident::new(&self.config.crate_name()?, span);
+        self.export(
+            name,
+            span,
+            quote! {
+                {
+                    let _value = #crate_name::IntoJs::into_js(#value,_ctx)?;
+                    _exports.export(#js_name,_value.clone())?;
+                    _exports.export("default",_value)?;
+                }
+            },
+        );
+        Ok(())
+    }
+
     pub fn expand_declarations(&mut self) -> TokenStream {
         let keys = self.declaration.keys();
+        let default = self.default.map(|_| {
+            quote! {
+                _declare.declare("default")?;
+            }
+        });
 
         quote! {
             #(_declare.declare(#keys)?;)*
+            #default
         }
     }
 
@@ -126,33 +178,35 @@ fn export_use(use_: &UseTree, module: &mut JsModule, config: &ModuleItemConfig)
             let js_name = config.js_name(ident, module.config.rename_types);
             let crate_name = Ident::new(&module.config.crate_name()?, ident.span());
             let mod_name = module.name.clone();
-            module.export(
+            module.export_value(
                 js_name.clone(),
+                js_name,
                 ident.span(),
                 quote! {
-                    let _constr = #crate_name::Class::<#mod_name::#ident>::create_constructor(&_ctx)?
+                    #crate_name::Class::<#mod_name::#ident>::create_constructor(&_ctx)?
                         .expect(concat!("Tried to export type `"
                                 ,stringify!(#ident),
                                 "` which did not define a constructor."
-                        ));
-                    _exports.export(#js_name,_constr)?;
+                        ))
                 },
-                )
+                config.default,
+            )?
         }
         UseTree::Rename(x) => {
             let ident = &x.rename;
             let js_name = config.js_name(ident, module.config.rename_types);
             let crate_name = Ident::new(&module.config.crate_name()?, ident.span());
             let mod_name = module.name.clone();
-            module.export(
+            module.export_value(
                 js_name.clone(),
+                js_name,
                 ident.span(),
                 quote! {
-                    let _constr = #crate_name::Class::<#mod_name::#ident>::create_constructor(&_ctx)?
-                        .expect("Tried to export type which did not define a constructor.");
-                    _exports.export(#js_name,_constr)?;
+                    #crate_name::Class::<#mod_name::#ident>::create_constructor(&_ctx)?
+                        .expect("Tried to export type which did not define a constructor.")
                 },
-                )
+                config.default,
+            )?
         }
         UseTree::Glob(x) => {
             return Err(Error::new(x.star_token.span(),"Using a glob export does not export the items to JavaScript.Please specify each item to be exported individially."))
@@ -362,73 +416,75 @@ pub(crate) fn expand(options: OptionList<ModuleOption>, mut item: ItemMod) -> Re
 
         let mod_name = module.name.clone();
 
-        module.export(
+        module.export_value(
             name,
+            js_name,
             f.sig.ident.span(),
-            quote! {
-                _exports.export(#js_name,#mod_name::#ident)?;
-            },
-        )
+            quote!(#mod_name::#ident),
+            function_config.default,
+        )?;
     }
 
     for (c, config) in _consts {
         let ident = &c.ident;
         let js_name = config.js_name(ident, module.config.rename_vars);
-        module.export(
+        module.export_value(
             js_name.clone(),
+            js_name,
             ident.span(),
-            quote! {
-                _exports.export(#js_name,#mod_name::#ident)?;
-            },
-        )
+            quote!(#mod_name::#ident),
+            config.default,
+        )?;
     }
 
     for (s, config) in _statics {
         let ident = &s.ident;
         let js_name = config.js_name(ident, module.config.rename_vars);
-        module.export(
+        module.export_value(
             js_name.clone(),
+            js_name,
             ident.span(),
-            quote! {
-                _exports.export(#js_name,#mod_name::#ident)?;
-            },
-        )
+            quote!(#mod_name::#ident),
+            config.default,
+        )?;
     }
 
     for (s, config) in _structs {
         let ident = &s.ident;
         let name = Class::from_struct(config.class.clone(), s.clone())?.javascript_name();
 
-        module.export(
+        module.export_value(
             name.clone(),
+            name,
             ident.span(),
             quote! {
-                let _constr = #crate_name::Class::<#mod_name::#ident>::create_constructor(&_ctx)?
+                #crate_name::Class::<#mod_name::#ident>::create_constructor(&_ctx)?
                     .expect(concat!("Tried to export type `"
                             ,stringify!(#ident),
                             "` which did not define a constructor."
-                    ));
-                _exports.export(#name,_constr)?;
+                    ))
             },
-        )
+            config.default,
+        )?;
     }
     for (e, config) in _enums {
         let ident = &e.ident;
 
         let name = Class::from_enum(config.class.clone(), e.clone())?.javascript_name();
 
-        module.export(
+        module.export_value(
             name.clone(),
+            name,
             ident.span(),
             quote! {
-                let _constr = #crate_name::Class::<#mod_name::#ident>::create_constructor(&_ctx)?
+                #crate_name::Class::<#mod_name::#ident>::create_constructor(&_ctx)?
                     .expect(concat!("Tried to export type `"
                             ,stringify!(#ident),
                             "` which did not define a constructor."
-                    ));
-                _exports.export(#name,_constr)?;
+                    ))
             },
-        )
+            config.default,
+        )?;
     }
 
     for (u, config) in _uses {
@@ -65,12 +65,21 @@ pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
     //options.0.iter().for_each(|x| config.apply(x));
 
     let lifetime_generics = add_js_lifetime(&generics);
+    let (impl_generics, _, _) = lifetime_generics.split_for_impl();
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+    let where_clause = where_clause.map(|x| &x.predicates);
     let crate_name = if let Some(x) = config.crate_ {
         format_ident!("{x}")
     } else {
         format_ident!("{}", crate_ident()?)
     };
 
+    // Like the std derives, require every type parameter to implement the trait.
+    let bounds = generics.type_params().map(|x| {
+        let ident = &x.ident;
+        quote!(#ident: #crate_name::class::Trace<'js>)
+    });
+
     match data {
         Data::Struct(struct_) => {
             let DataStruct { fields, .. } = struct_;
@@ -94,7 +103,7 @@ pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
             };
 
             Ok(quote! {
-                impl #lifetime_generics #crate_name::class::Trace<'js> for #ident #generics{
+                impl #impl_generics #crate_name::class::Trace<'js> for #ident #ty_generics where #(#bounds,)* #where_clause{
                     fn trace<'a>(&self, _tracer: #crate_name::class::Tracer<'a,'js>){
                         #trace_body
                     }
@@ -168,7 +177,7 @@ pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
             }
 
             Ok(quote! {
-                impl #lifetime_generics #crate_name::class::Trace<'js> for #ident #generics {
+                impl #impl_generics #crate_name::class::Trace<'js> for #ident #ty_generics where #(#bounds,)* #where_clause {
                     fn trace<'a>(&self, _tracer: #crate_name::class::Tracer<'a,'js>){
                         match *self{
                             #body
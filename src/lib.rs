@@ -84,6 +84,15 @@
 //! - `indexmap` adds [`FromJs`]/[`IntoJs`] implementations for [`IndexSet`](`indexmap::IndexSet`)
 //! and [`IndexMap`](`indexmap_rs::IndexMap`)
 //!
+//! - `net` adds [`FromJs`]/[`IntoJs`] implementations for the [`std::net`] address types, which
+//! are converted from and into their string representation
+//!
+//! - `path` adds [`FromJs`]/[`IntoJs`] implementations for [`PathBuf`](std::path::PathBuf) and
+//! [`Path`](std::path::Path), which are converted from and into strings
+//!
+//! - `serde-json` adds [`FromJs`]/[`IntoJs`] implementations for `serde_json::Value`, integers
+//! outside of the safe integer range of JavaScript numbers are converted into `BigInt`s
+//!
 //! ## Bindings
 //!
 //! The bindings are pre-generated for the following platforms:
@@ -103,9 +112,15 @@
 //!   - `x86_64-pc-windows-gnu`
 //!   - `i686-pc-windows-msvc`
 //!   - `x86_64-pc-windows-msvc`
+//! - WebAssembly:
+//!   - `wasm32-wasip1`
+//!   - `wasm32-wasip2`
 //!
 //! To build the crate for any other target you must enable the `bindgen` feature.
 //!
+//! QuickJS needs a C standard library, so WebAssembly is only supported through WASI. Targets
+//! without one, like `wasm32-unknown-unknown`, are not supported and fail to build.
+//!
 //! ## Experimental
 //!
 //! - `parallel` enables multithreading support.
@@ -145,6 +145,16 @@ fn main() {
 
     let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap();
     let target_env = env::var("CARGO_CFG_TARGET_ENV").unwrap();
+    let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
+
+    if target_arch.starts_with("wasm") && target_os == "unknown" {
+        // QuickJS is written against libc (malloc, math, time, stdio) which these targets don't
+        // provide, WASI does.
+        panic!(
+            "QuickJS requires a C standard library which is not available on `{}`, use a WASI target like `wasm32-wasip1` or `wasm32-wasip2` instead",
+            env::var("TARGET").unwrap()
+        );
+    }
 
     let mut builder = cc::Build::new();
     builder
@@ -0,0 +1,69 @@
+use rquickjs::{class::Trace, CatchResultExt, Class, Context, JsLifetime, Runtime};
+
+#[derive(Trace, JsLifetime)]
+#[rquickjs::class]
+pub enum State {
+    Ready,
+    Running(u32),
+    #[qjs(skip)]
+    Poisoned { reason: String },
+}
+
+#[rquickjs::methods]
+impl State {
+    #[qjs(constructor)]
+    pub fn new(kind: String) -> Self {
+        match kind.as_str() {
+            "ready" => State::Ready,
+            "running" => State::Running(1),
+            _ => State::Poisoned { reason: kind },
+        }
+    }
+
+    #[qjs(get)]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            State::Ready => "ready",
+            State::Running(_) => "running",
+            State::Poisoned { .. } => "poisoned",
+        }
+    }
+}
+
+pub fn main() {
+    let rt = Runtime::new().unwrap();
+    let ctx = Context::full(&rt).unwrap();
+
+    ctx.with(|ctx| {
+        Class::<State>::define(&ctx.globals()).unwrap();
+        ctx.eval::<(), _>(
+            r#"
+            if (new State("ready").kind !== "ready") {
+                throw new Error(1);
+            }
+            if (new State("running").kind !== "running") {
+                throw new Error(2);
+            }
+            let error;
+            try {
+                new State("internal");
+            } catch (e) {
+                error = e;
+            }
+            if (!error || !String(error).includes("State::Poisoned")) {
+                throw new Error("skipped variant was constructed: " + error);
+            }
+            "#,
+        )
+        .catch(&ctx)
+        .unwrap();
+
+        let res = rquickjs::IntoJs::into_js(
+            State::Poisoned {
+                reason: "test".to_string(),
+            },
+            &ctx,
+        );
+        assert!(res.is_err());
+    })
+}
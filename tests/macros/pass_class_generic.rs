@@ -0,0 +1,83 @@
+use rquickjs::{
+    class::{ClassName, Trace},
+    CatchResultExt, Class, Context, JsLifetime, Runtime, Value,
+};
+
+#[derive(Trace, JsLifetime, Clone, Copy)]
+pub struct A;
+
+#[derive(Trace, JsLifetime, Clone, Copy)]
+pub struct B;
+
+#[derive(Trace, JsLifetime)]
+#[rquickjs::class]
+pub struct Wrapper<T> {
+    inner: T,
+    #[qjs(get)]
+    id: u32,
+}
+
+impl ClassName for Wrapper<A> {
+    const NAME: &'static str = "WrapperA";
+}
+
+impl ClassName for Wrapper<B> {
+    const NAME: &'static str = "WrapperB";
+}
+
+#[rquickjs::methods]
+impl Wrapper<A> {
+    #[qjs(constructor)]
+    pub fn new(id: u32) -> Self {
+        Wrapper { inner: A, id }
+    }
+}
+
+#[rquickjs::methods]
+impl Wrapper<B> {
+    #[qjs(constructor)]
+    pub fn new(id: u32) -> Self {
+        Wrapper { inner: B, id }
+    }
+}
+
+pub fn main() {
+    let rt = Runtime::new().unwrap();
+    let ctx = Context::full(&rt).unwrap();
+
+    ctx.with(|ctx| {
+        Class::<Wrapper<A>>::define(&ctx.globals()).unwrap();
+        Class::<Wrapper<B>>::define(&ctx.globals()).unwrap();
+
+        let a: Value = ctx.eval("new WrapperA(1)").catch(&ctx).unwrap();
+        let b: Value = ctx.eval("new WrapperB(2)").catch(&ctx).unwrap();
+
+        let cls_a = Class::<Wrapper<A>>::from_value(&a).unwrap();
+        assert_eq!(cls_a.borrow().id, 1);
+        let _: A = cls_a.borrow().inner;
+        let cls_b = Class::<Wrapper<B>>::from_value(&b).unwrap();
+        assert_eq!(cls_b.borrow().id, 2);
+
+        assert!(Class::<Wrapper<A>>::from_value(&b).is_err());
+        assert!(Class::<Wrapper<B>>::from_value(&a).is_err());
+        assert!(!a.as_object().unwrap().instance_of::<Wrapper<B>>());
+
+        ctx.globals().set("a", a).unwrap();
+        ctx.globals().set("b", b).unwrap();
+        ctx.eval::<(), _>(
+            r#"
+            if (!(a instanceof WrapperA) || a instanceof WrapperB) {
+                throw new Error("a has the wrong class");
+            }
+            if (!(b instanceof WrapperB) || b instanceof WrapperA) {
+                throw new Error("b has the wrong class");
+            }
+            if (a.id !== 1 || b.id !== 2) {
+                throw new Error("unexpected id");
+            }
+            "#,
+        )
+        .catch(&ctx)
+        .unwrap();
+    })
+}
@@ -0,0 +1,76 @@
+use rquickjs::{
+    class::Trace, CatchResultExt, Class, Context, Function, JsLifetime, Null, Persistent, Runtime,
+};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static DROPPED: AtomicBool = AtomicBool::new(false);
+
+pub struct DropFlag;
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        DROPPED.store(true, Ordering::SeqCst);
+    }
+}
+
+#[derive(Trace, JsLifetime)]
+#[rquickjs::class]
+pub struct Callback {
+    func: Persistent<Function<'static>>,
+    #[qjs(skip_trace)]
+    flag: DropFlag,
+}
+
+#[rquickjs::methods]
+impl Callback {
+    #[qjs(constructor)]
+    pub fn new(func: Persistent<Function<'static>>) -> Self {
+        Callback {
+            func,
+            flag: DropFlag,
+        }
+    }
+
+    pub fn call<'js>(&self, ctx: rquickjs::Ctx<'js>) -> rquickjs::Result<i32> {
+        self.func.clone().restore(&ctx)?.call(())
+    }
+}
+
+pub fn main() {
+    let rt = Runtime::new().unwrap();
+    let ctx = Context::full(&rt).unwrap();
+
+    ctx.with(|ctx| {
+        Class::<Callback>::define(&ctx.globals()).unwrap();
+        // The function refers back to the instance holding it, which can only be collected if
+        // the persistent function is traced.
+        ctx.eval::<(), _>(
+            r#"
+            globalThis.value = 41;
+            globalThis.callback = new Callback(() => {
+                globalThis.callback;
+                return value + 1;
+            });
+            "#,
+        )
+        .catch(&ctx)
+        .unwrap();
+    });
+
+    rt.run_gc();
+    assert!(!DROPPED.load(Ordering::SeqCst));
+
+    ctx.with(|ctx| {
+        let res: i32 = ctx.eval("callback.call()").catch(&ctx).unwrap();
+        assert_eq!(res, 42);
+        let callback: Class<Callback> = ctx.globals().get("callback").unwrap();
+        assert_eq!(callback.borrow().call(ctx.clone()).unwrap(), 42);
+    });
+
+    ctx.with(|ctx| {
+        ctx.globals().set("callback", Null).unwrap();
+    });
+
+    rt.run_gc();
+    assert!(DROPPED.load(Ordering::SeqCst));
+}
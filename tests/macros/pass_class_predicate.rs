@@ -0,0 +1,64 @@
+use rquickjs::{class::Trace, CatchResultExt, Class, Context, JsLifetime, Runtime};
+
+#[derive(Trace, JsLifetime)]
+#[rquickjs::class]
+pub struct Job {
+    #[qjs(skip_trace)]
+    ready: bool,
+    #[qjs(get, set, predicate = "Self::is_ready")]
+    output: u32,
+    #[qjs(get, rename = "input")]
+    input: u32,
+}
+
+impl Job {
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+}
+
+#[rquickjs::methods]
+impl Job {
+    #[qjs(constructor)]
+    pub fn new(input: u32) -> Self {
+        Job {
+            ready: false,
+            output: 0,
+            input,
+        }
+    }
+
+    pub fn run(&mut self) {
+        self.output = self.input * 2;
+        self.ready = true;
+    }
+}
+
+pub fn main() {
+    let rt = Runtime::new().unwrap();
+    let ctx = Context::full(&rt).unwrap();
+
+    ctx.with(|ctx| {
+        Class::<Job>::define(&ctx.globals()).unwrap();
+        ctx.eval::<(), _>(
+            r#"
+            const job = new Job(21);
+            let error;
+            try {
+                job.output;
+            } catch (e) {
+                error = e;
+            }
+            if (!(error instanceof TypeError) || !error.message.includes("Self::is_ready")) {
+                throw new Error("expected the getter to throw: " + error);
+            }
+            job.run();
+            if (job.output !== 42) {
+                throw new Error("wrong output: " + job.output);
+            }
+            "#,
+        )
+        .catch(&ctx)
+        .unwrap();
+    })
+}
@@ -0,0 +1,40 @@
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc,
+};
+
+use rquickjs::{class::Trace, CatchResultExt, Context, JsLifetime, Runtime};
+
+/// The class is cloned when an `Arc<Counter>` is converted, the count is shared between the
+/// clones.
+#[derive(Clone, Trace, JsLifetime)]
+#[rquickjs::class]
+pub struct Counter {
+    #[qjs(skip_trace)]
+    count: Arc<AtomicU32>,
+}
+
+#[rquickjs::methods]
+impl Counter {
+    pub fn increment(&self) {
+        self.count.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+pub fn main() {
+    let rt = Runtime::new().unwrap();
+    let ctx = Context::full(&rt).unwrap();
+
+    let counter = Arc::new(Counter {
+        count: Arc::new(AtomicU32::new(0)),
+    });
+
+    ctx.with(|ctx| {
+        ctx.globals().set("counter", counter.clone()).unwrap();
+        ctx.eval::<(), _>("counter.increment(); counter.increment();")
+            .catch(&ctx)
+            .unwrap();
+    });
+
+    assert_eq!(counter.count.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,89 @@
+use rquickjs::{
+    class::Trace,
+    function::{NewTarget, This},
+    CatchResultExt, Class, Context, JsLifetime, Result, Runtime, Value,
+};
+
+#[derive(Trace, JsLifetime)]
+#[rquickjs::class]
+pub struct Base {
+    target: String,
+    this_is_target: bool,
+}
+
+#[rquickjs::methods]
+impl Base {
+    #[qjs(constructor)]
+    pub fn new<'js>(this: This<Value<'js>>, new_target: NewTarget<'js>) -> Result<Self> {
+        let (target, this_is_target) = match new_target.0 {
+            Some(constr) => {
+                let this_is_target = this.0 == constr.clone().into_value();
+                (constr.get("name")?, this_is_target)
+            }
+            None => ("none".to_string(), false),
+        };
+        Ok(Base {
+            target,
+            this_is_target,
+        })
+    }
+
+    #[qjs(get)]
+    pub fn target(&self) -> String {
+        self.target.clone()
+    }
+
+    #[qjs(get, rename = "thisIsTarget")]
+    pub fn this_is_target(&self) -> bool {
+        self.this_is_target
+    }
+
+    pub fn hello(&self) -> String {
+        "base".to_string()
+    }
+}
+
+pub fn main() {
+    let rt = Runtime::new().unwrap();
+    let ctx = Context::full(&rt).unwrap();
+
+    ctx.with(|ctx| {
+        Class::<Base>::define(&ctx.globals()).unwrap();
+
+        ctx.eval::<(), _>(
+            r#"
+            class Sub extends Base {
+                constructor() {
+                    super();
+                }
+
+                hello() {
+                    return "sub " + super.hello();
+                }
+            }
+
+            const base = new Base();
+            if (!(base instanceof Base) || base.target !== "Base" || !base.thisIsTarget) {
+                throw new Error(1);
+            }
+
+            const sub = new Sub();
+            if (!(sub instanceof Sub) || !(sub instanceof Base)) {
+                throw new Error(2);
+            }
+            if (sub.target !== "Sub" || !sub.thisIsTarget) {
+                throw new Error(3);
+            }
+            if (sub.hello() !== "sub base") {
+                throw new Error(4);
+            }
+
+            if (Base().target !== "none") {
+                throw new Error(5);
+            }
+        "#,
+        )
+        .catch(&ctx)
+        .unwrap();
+    });
+}
@@ -0,0 +1,54 @@
+use rquickjs::{CatchResultExt, Context, Function, Runtime};
+
+#[rquickjs::function]
+pub fn adder(x: i32) -> impl Fn(i32) -> i32 {
+    move |y| x + y
+}
+
+#[rquickjs::function]
+pub fn counter(start: u32) -> impl FnMut() -> u32 {
+    let mut count = start;
+    move || {
+        count += 1;
+        count
+    }
+}
+
+#[rquickjs::function]
+pub fn once(value: String) -> impl FnOnce() -> String {
+    move || value
+}
+
+fn main() {
+    let rt = Runtime::new().unwrap();
+    let ctx = Context::full(&rt).unwrap();
+
+    ctx.with(|ctx| {
+        let globals = ctx.globals();
+        globals
+            .set("adder", Function::new(ctx.clone(), js_adder).unwrap())
+            .unwrap();
+        globals
+            .set("counter", Function::new(ctx.clone(), js_counter).unwrap())
+            .unwrap();
+        globals
+            .set("once", Function::new(ctx.clone(), js_once).unwrap())
+            .unwrap();
+
+        let res: i32 = ctx.eval("adder(3)(4)").catch(&ctx).unwrap();
+        assert_eq!(res, 7);
+
+        let res: u32 = ctx
+            .eval("const count = counter(1); count(); count()")
+            .catch(&ctx)
+            .unwrap();
+        assert_eq!(res, 3);
+
+        let res: String = ctx
+            .eval("const take = once('foo'); take()")
+            .catch(&ctx)
+            .unwrap();
+        assert_eq!(res, "foo");
+        assert!(ctx.eval::<String, _>("take()").is_err());
+    })
+}
@@ -0,0 +1,62 @@
+#[cfg(feature = "futures")]
+mod test {
+    use rquickjs::{
+        async_with, class::Trace, AsyncContext, AsyncRuntime, CatchResultExt, Class, JsLifetime,
+        Promise,
+    };
+
+    #[derive(Trace, JsLifetime)]
+    #[rquickjs::class]
+    pub struct Counter {
+        value: u32,
+    }
+
+    #[rquickjs::methods]
+    impl Counter {
+        #[qjs(constructor)]
+        pub fn new(value: u32) -> Self {
+            Counter { value }
+        }
+
+        #[qjs(get, rename = "value")]
+        pub async fn value(&self) -> u32 {
+            self.value
+        }
+
+        #[qjs(set, rename = "value")]
+        pub async fn set_value(&mut self, value: u32) {
+            self.value = value;
+        }
+    }
+
+    pub async fn run() {
+        let rt = AsyncRuntime::new().unwrap();
+        let ctx = AsyncContext::full(&rt).await.unwrap();
+
+        async_with!(ctx => |ctx| {
+            Class::<Counter>::define(&ctx.globals()).unwrap();
+            let promise: Promise = ctx
+                .eval(
+                    r#"
+                    (async () => {
+                        const counter = new Counter(1);
+                        const before = await counter.value;
+                        counter.value = 2;
+                        await null;
+                        return before * 10 + await counter.value;
+                    })()
+                    "#,
+                )
+                .catch(&ctx)
+                .unwrap();
+            let res: u32 = promise.into_future().await.catch(&ctx).unwrap();
+            assert_eq!(res, 12);
+        })
+        .await;
+    }
+}
+
+pub fn main() {
+    #[cfg(feature = "futures")]
+    futures::executor::block_on(test::run());
+}
@@ -0,0 +1,65 @@
+use rquickjs::{class::Trace, CatchResultExt, Class, Context, JsLifetime, Runtime};
+
+const PREFIX: &str = "computed";
+const NAME: &str = "sumName";
+
+#[derive(Trace, JsLifetime)]
+#[rquickjs::class]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[rquickjs::methods]
+impl Point {
+    #[qjs(constructor)]
+    pub fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    #[qjs(get, rename = concat!("get_", "x"))]
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+
+    #[qjs(set, rename = concat!("get_", "x"))]
+    pub fn set_x(&mut self, x: i32) {
+        self.x = x;
+    }
+
+    #[qjs(rename = NAME)]
+    pub fn sum(&self) -> i32 {
+        self.x + self.y
+    }
+
+    #[qjs(rename = if PREFIX.is_empty() { "y" } else { "computedY" })]
+    pub fn y(&self) -> i32 {
+        self.y
+    }
+}
+
+pub fn main() {
+    let rt = Runtime::new().unwrap();
+    let ctx = Context::full(&rt).unwrap();
+
+    ctx.with(|ctx| {
+        Class::<Point>::define(&ctx.globals()).unwrap();
+        ctx.eval::<(), _>(
+            r#"
+            const p = new Point(1, 2);
+            if (p.get_x !== 1) {
+                throw new Error("wrong x: " + p.get_x);
+            }
+            p.get_x = 5;
+            if (p.sumName() !== 7) {
+                throw new Error("wrong sum: " + p.sumName());
+            }
+            if (p.computedY() !== 2 || p.x !== undefined || p.sum !== undefined) {
+                throw new Error("methods defined under the wrong name");
+            }
+            "#,
+        )
+        .catch(&ctx)
+        .unwrap();
+    })
+}
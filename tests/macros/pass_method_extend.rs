@@ -0,0 +1,61 @@
+use rquickjs::{class::Trace, CatchResultExt, Class, Context, JsLifetime, Runtime};
+
+#[derive(Trace, JsLifetime)]
+#[rquickjs::class(extend = extra::TestClassExtra)]
+pub struct TestClass {
+    value: u32,
+}
+
+#[rquickjs::methods]
+impl TestClass {
+    #[qjs(constructor)]
+    pub fn new(value: u32) -> Self {
+        TestClass { value }
+    }
+
+    #[qjs(get)]
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+mod extra {
+    use super::TestClass;
+
+    #[rquickjs::methods(extend = TestClassExtra)]
+    impl TestClass {
+        pub fn double(&self) -> u32 {
+            self.value * 2
+        }
+
+        #[qjs(get, rename = "isEven")]
+        pub fn is_even(&self) -> bool {
+            self.value % 2 == 0
+        }
+    }
+}
+
+pub fn main() {
+    let rt = Runtime::new().unwrap();
+    let ctx = Context::full(&rt).unwrap();
+
+    ctx.with(|ctx| {
+        Class::<TestClass>::define(&ctx.globals()).unwrap();
+        ctx.eval::<(), _>(
+            r#"
+            let t = new TestClass(3);
+            if(t.value !== 3){
+                throw new Error('invalid value')
+            }
+            if(t.double() !== 6){
+                throw new Error('invalid double')
+            }
+            if(t.isEven !== false){
+                throw new Error('invalid isEven')
+            }
+        "#,
+        )
+        .catch(&ctx)
+        .unwrap();
+    });
+}
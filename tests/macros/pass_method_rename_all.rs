@@ -0,0 +1,68 @@
+use rquickjs::{class::Trace, CatchResultExt, Class, Context, JsLifetime, Runtime};
+
+#[derive(Trace, JsLifetime)]
+#[rquickjs::class(rename_all = "camelCase")]
+pub struct Counter {
+    #[qjs(get)]
+    step_size: i32,
+    count: i32,
+}
+
+#[rquickjs::methods(rename_all = "camelCase")]
+impl Counter {
+    #[qjs(constructor)]
+    pub fn new(step_size: i32) -> Self {
+        Counter {
+            step_size,
+            count: 0,
+        }
+    }
+
+    pub fn do_step(&mut self) {
+        self.count += self.step_size;
+    }
+
+    #[qjs(get)]
+    pub fn current_count(&self) -> i32 {
+        self.count
+    }
+
+    #[qjs(static)]
+    pub fn default_step() -> i32 {
+        1
+    }
+
+    #[qjs(rename = "keep_name")]
+    pub fn kept_name(&self) -> i32 {
+        self.count
+    }
+}
+
+pub fn main() {
+    let rt = Runtime::new().unwrap();
+    let ctx = Context::full(&rt).unwrap();
+
+    ctx.with(|ctx| {
+        Class::<Counter>::define(&ctx.globals()).unwrap();
+        ctx.eval::<(), _>(
+            r#"
+            const c = new Counter(Counter.defaultStep() + 1);
+            if (c.stepSize !== 2) {
+                throw new Error("field not renamed");
+            }
+            c.doStep();
+            if (c.currentCount !== 2) {
+                throw new Error("method not renamed");
+            }
+            if (c.do_step !== undefined || c.current_count !== undefined) {
+                throw new Error("snake_case name still defined");
+            }
+            if (c.keep_name() !== 2) {
+                throw new Error("explicit rename overridden");
+            }
+            "#,
+        )
+        .catch(&ctx)
+        .unwrap();
+    })
+}
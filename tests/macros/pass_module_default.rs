@@ -0,0 +1,43 @@
+use rquickjs::{CatchResultExt, Context, Module, Runtime};
+
+#[rquickjs::module]
+mod greeting_mod {
+    /// Exported both as `GREETING` and as the default export of the module.
+    #[qjs(default)]
+    pub const GREETING: &str = "hello";
+
+    #[rquickjs::function]
+    pub fn shout(s: String) -> String {
+        s.to_uppercase()
+    }
+}
+
+fn main() {
+    let rt = Runtime::new().unwrap();
+    let ctx = Context::full(&rt).unwrap();
+
+    ctx.with(|ctx| {
+        Module::declare_def::<js_greeting_mod, _>(ctx.clone(), "greeting").unwrap();
+        Module::evaluate(
+            ctx.clone(),
+            "main",
+            r"
+            import greeting, { GREETING, shout } from 'greeting';
+            if (greeting !== 'hello') {
+                throw new Error(1);
+            }
+            if (greeting !== GREETING) {
+                throw new Error(2);
+            }
+            if (shout(greeting) !== 'HELLO') {
+                throw new Error(3);
+            }
+            ",
+        )
+        .catch(&ctx)
+        .unwrap()
+        .finish::<()>()
+        .catch(&ctx)
+        .unwrap();
+    })
+}
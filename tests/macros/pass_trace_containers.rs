@@ -0,0 +1,108 @@
+use rquickjs::{
+    class::{JsClass, Readable, Trace},
+    CatchResultExt, Class, Context, JsLifetime, Null, Object, Runtime, Value,
+};
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+static DROPPED: AtomicBool = AtomicBool::new(false);
+
+pub struct DropFlag;
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        DROPPED.store(true, Ordering::SeqCst);
+    }
+}
+
+#[derive(Trace, JsLifetime)]
+pub struct Holder<'js> {
+    list: Vec<Object<'js>>,
+    maybe: Option<Object<'js>>,
+    map: HashMap<String, Object<'js>>,
+    pair: (Value<'js>, Object<'js>),
+    #[qjs(skip_trace)]
+    flag: DropFlag,
+}
+
+impl<'js> JsClass<'js> for Holder<'js> {
+    const NAME: &'static str = "Holder";
+
+    type Mutable = Readable;
+
+    fn prototype(ctx: &rquickjs::Ctx<'js>) -> rquickjs::Result<Option<Object<'js>>> {
+        Object::new(ctx.clone()).map(Some)
+    }
+
+    fn constructor(
+        _ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+        Ok(None)
+    }
+}
+
+pub fn main() {
+    let rt = Runtime::new().unwrap();
+    let ctx = Context::full(&rt).unwrap();
+
+    ctx.with(|ctx| {
+        // Every held object points back at the holder, creating cycles which can only be
+        // collected if the holder traces all of its contents.
+        let new_obj = |n: i32| {
+            let obj = Object::new(ctx.clone()).unwrap();
+            obj.set("n", n).unwrap();
+            obj
+        };
+        let list = vec![new_obj(1), new_obj(2)];
+        let maybe = new_obj(3);
+        let map = HashMap::from([("four".to_string(), new_obj(4))]);
+        let pair = (new_obj(5).into_value(), new_obj(6));
+        let held = [&list[0], &list[1], &maybe, &map["four"], &pair.1]
+            .map(|x| x.clone())
+            .to_vec();
+
+        let holder = Class::instance(
+            ctx.clone(),
+            Holder {
+                list,
+                maybe: Some(maybe),
+                map,
+                pair,
+                flag: DropFlag,
+            },
+        )
+        .unwrap();
+        for obj in held {
+            obj.set("holder", holder.clone()).unwrap();
+        }
+        ctx.globals().set("holder", holder).unwrap();
+    });
+
+    rt.run_gc();
+    assert!(!DROPPED.load(Ordering::SeqCst));
+
+    ctx.with(|ctx| {
+        let holder: Class<Holder> = ctx.globals().get("holder").unwrap();
+        let holder = holder.borrow();
+        let sum = holder
+            .list
+            .iter()
+            .chain(holder.maybe.iter())
+            .chain(holder.map.values())
+            .chain(Some(&holder.pair.1))
+            .map(|x| x.get::<_, i32>("n").unwrap())
+            .sum::<i32>();
+        assert_eq!(sum, 1 + 2 + 3 + 4 + 6);
+        let n: i32 = holder.pair.0.as_object().unwrap().get("n").catch(&ctx).unwrap();
+        assert_eq!(n, 5);
+    });
+
+    ctx.with(|ctx| {
+        ctx.globals().set("holder", Null).unwrap();
+    });
+
+    rt.run_gc();
+    assert!(DROPPED.load(Ordering::SeqCst));
+}
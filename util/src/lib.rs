@@ -0,0 +1,38 @@
+//! # Browser-like globals for rquickjs
+//!
+//! QuickJS only provides the objects defined by the ECMAScript specification. Scripts written for
+//! browsers often expect a few more globals to exist; the types in this crate install minimal
+//! versions of those into a [`rquickjs`](https://docs.rs/rquickjs) context.
+//!
+//! # Features
+//!
+//! - `tokio` (default) drives [`Timers`] with the timer of tokio.
+//! - `async-std` drives [`Timers`] with the timer of async-std.
+
+#![allow(clippy::needless_lifetimes)]
+#![cfg_attr(feature = "doc-cfg", feature(doc_cfg))]
+
+mod navigator;
+mod text;
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+mod timers;
+
+pub use navigator::Navigator;
+pub use text::{TextDecoder, TextEncoder, TextEncoding};
+
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+#[cfg_attr(
+    feature = "doc-cfg",
+    doc(cfg(any(feature = "tokio", feature = "async-std")))
+)]
+pub use timers::Timers;
+
+#[cfg(test)]
+pub(crate) fn test_with<F, R>(func: F) -> R
+where
+    F: FnOnce(rquickjs_core::Ctx) -> R,
+{
+    let rt = rquickjs_core::Runtime::new().unwrap();
+    let ctx = rquickjs_core::Context::full(&rt).unwrap();
+    ctx.with(func)
+}
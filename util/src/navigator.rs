@@ -0,0 +1,121 @@
+use rquickjs_core::{object::Property, Ctx, Object, Result};
+
+/// A minimal, read-only `navigator` global.
+///
+/// Only `userAgent` and `platform` are defined, every other field scripts might expect on a
+/// browser `navigator` will be `undefined`. The installed object is frozen and the global binding
+/// is neither writable nor configurable.
+///
+/// ```
+/// # use rquickjs::{Runtime, Context};
+/// # use rquickjs_util::Navigator;
+/// # let rt = Runtime::new().unwrap();
+/// # let ctx = Context::full(&rt).unwrap();
+/// # ctx.with(|ctx| {
+/// Navigator::new()
+///     .with_user_agent("my-app/1.0")
+///     .install(&ctx)
+///     .unwrap();
+/// let ua: String = ctx.eval("navigator.userAgent").unwrap();
+/// assert_eq!(ua, "my-app/1.0");
+/// # })
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Navigator {
+    user_agent: String,
+    platform: String,
+}
+
+impl Default for Navigator {
+    fn default() -> Self {
+        Navigator {
+            user_agent: concat!("rquickjs/", env!("CARGO_PKG_VERSION")).to_string(),
+            platform: std::env::consts::OS.to_string(),
+        }
+    }
+}
+
+impl Navigator {
+    /// Create a navigator with the default user agent and the platform of the host.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the value of `navigator.userAgent`.
+    pub fn with_user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Set the value of `navigator.platform`.
+    pub fn with_platform<S: Into<String>>(mut self, platform: S) -> Self {
+        self.platform = platform.into();
+        self
+    }
+
+    /// Returns the configured user agent.
+    pub fn user_agent(&self) -> &str {
+        &self.user_agent
+    }
+
+    /// Returns the configured platform.
+    pub fn platform(&self) -> &str {
+        &self.platform
+    }
+
+    /// Define the frozen `navigator` object on the global object of the given context.
+    ///
+    /// Returns an error if the context already has a non-configurable `navigator` global, for
+    /// example when installing twice.
+    pub fn install<'js>(&self, ctx: &Ctx<'js>) -> Result<()> {
+        let navigator = Object::new(ctx.clone())?;
+        navigator.prop(
+            "userAgent",
+            Property::from(self.user_agent.as_str()).enumerable(),
+        )?;
+        navigator.prop(
+            "platform",
+            Property::from(self.platform.as_str()).enumerable(),
+        )?;
+
+        // The properties are already read-only and non-configurable, preventing extensions
+        // freezes the object without going through the mutable `Object.freeze`.
+        navigator.prevent_extensions()?;
+
+        ctx.globals().prop("navigator", navigator)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_with, Navigator};
+    use rquickjs_core::Value;
+
+    #[test]
+    fn user_agent() {
+        test_with(|ctx| {
+            ctx.eval::<(), _>("Object.freeze = () => { throw new Error('changed') }")
+                .unwrap();
+            Navigator::new()
+                .with_user_agent("test-agent/0.1")
+                .with_platform("test-os")
+                .install(&ctx)
+                .unwrap();
+
+            let ua: String = ctx.eval("navigator.userAgent").unwrap();
+            assert_eq!(ua, "test-agent/0.1");
+            let platform: String = ctx.eval("navigator.platform").unwrap();
+            assert_eq!(platform, "test-os");
+
+            let lang: Value = ctx.eval("navigator.language").unwrap();
+            assert!(lang.is_undefined());
+
+            let frozen: bool = ctx.eval("Object.isFrozen(navigator)").unwrap();
+            assert!(frozen);
+            assert!(ctx.eval::<(), _>("navigator.userAgent = 'other'").is_err());
+            assert!(ctx.eval::<(), _>("navigator = {}").is_err());
+            let ua: String = ctx.eval("navigator.userAgent").unwrap();
+            assert_eq!(ua, "test-agent/0.1");
+        })
+    }
+}
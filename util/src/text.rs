@@ -0,0 +1,390 @@
+use rquickjs_core::{
+    class::{JsClass, OwnedBorrow, Readable, Trace, Tracer},
+    convert::Coerced,
+    function::{Constructor, Opt, This},
+    object::Accessor,
+    ArrayBuffer, Class, Ctx, Exception, Function, IntoJs, JsLifetime, Object, Result, TypedArray,
+    Value,
+};
+
+/// The `TextEncoder` class, which encodes strings into UTF-8.
+///
+/// Only `encode` and the `encoding` property are implemented. Define the class on the global
+/// object with [`Class::define`].
+///
+/// ```
+/// # use rquickjs::{Runtime, Context, Class};
+/// # use rquickjs_util::{TextDecoder, TextEncoder};
+/// # let rt = Runtime::new().unwrap();
+/// # let ctx = Context::full(&rt).unwrap();
+/// # ctx.with(|ctx| {
+/// Class::<TextEncoder>::define(&ctx.globals()).unwrap();
+/// Class::<TextDecoder>::define(&ctx.globals()).unwrap();
+/// let text: String = ctx
+///     .eval("new TextDecoder().decode(new TextEncoder().encode('héllo'))")
+///     .unwrap();
+/// assert_eq!(text, "héllo");
+/// # })
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextEncoder;
+
+impl<'js> Trace<'js> for TextEncoder {
+    fn trace<'a>(&self, _tracer: Tracer<'a, 'js>) {}
+}
+
+unsafe impl<'js> JsLifetime<'js> for TextEncoder {
+    type Changed<'to> = TextEncoder;
+}
+
+impl<'js> JsClass<'js> for TextEncoder {
+    const NAME: &'static str = "TextEncoder";
+
+    type Mutable = Readable;
+
+    fn prototype(ctx: &Ctx<'js>) -> Result<Option<Object<'js>>> {
+        let proto = Object::new(ctx.clone())?;
+        let encode = Function::new(
+            ctx.clone(),
+            |ctx: Ctx<'js>,
+             _this: This<OwnedBorrow<'js, TextEncoder>>,
+             input: Opt<Coerced<String>>| {
+                let input = input.0.map(|x| x.0).unwrap_or_default();
+                TypedArray::<u8>::new(ctx, input.into_bytes())
+            },
+        )?
+        .with_name("encode")?;
+        proto.set("encode", encode)?;
+        proto.prop(
+            "encoding",
+            Accessor::from(|_this: This<OwnedBorrow<'js, TextEncoder>>| "utf-8"),
+        )?;
+        Ok(Some(proto))
+    }
+
+    fn constructor(ctx: &Ctx<'js>) -> Result<Option<Constructor<'js>>> {
+        Constructor::new_class::<TextEncoder, _, _>(ctx.clone(), || TextEncoder).map(Some)
+    }
+}
+
+impl<'js> IntoJs<'js> for TextEncoder {
+    fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+        Class::instance(ctx.clone(), self)?.into_js(ctx)
+    }
+}
+
+/// An encoding supported by [`TextDecoder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TextEncoding {
+    /// UTF-8, the default encoding.
+    Utf8,
+    /// UTF-16 with little-endian code units.
+    Utf16Le,
+}
+
+impl TextEncoding {
+    /// Look up an encoding by one of its labels from the encoding standard, like `"utf8"`.
+    ///
+    /// Labels are matched case-insensitively after trimming whitespace.
+    pub fn from_label(label: &str) -> Option<Self> {
+        match label.trim().to_ascii_lowercase().as_str() {
+            "unicode-1-1-utf-8" | "unicode11utf8" | "unicode20utf8" | "utf-8" | "utf8"
+            | "x-unicode20utf8" => Some(TextEncoding::Utf8),
+            "csunicode" | "iso-10646-ucs-2" | "ucs-2" | "unicode" | "unicodefeff" | "utf-16"
+            | "utf-16le" => Some(TextEncoding::Utf16Le),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the encoding.
+    pub fn name(self) -> &'static str {
+        match self {
+            TextEncoding::Utf8 => "utf-8",
+            TextEncoding::Utf16Le => "utf-16le",
+        }
+    }
+}
+
+/// The `TextDecoder` class, which decodes bytes into strings.
+///
+/// Supports the encodings of [`TextEncoding`] and the `fatal` and `ignoreBOM` options. Streaming
+/// decoding is not supported. Define the class on the global object with [`Class::define`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextDecoder {
+    encoding: TextEncoding,
+    fatal: bool,
+    ignore_bom: bool,
+}
+
+impl Default for TextDecoder {
+    fn default() -> Self {
+        TextDecoder::new(TextEncoding::Utf8)
+    }
+}
+
+impl TextDecoder {
+    /// Create a decoder for the given encoding which replaces invalid data and strips a BOM.
+    pub fn new(encoding: TextEncoding) -> Self {
+        TextDecoder {
+            encoding,
+            fatal: false,
+            ignore_bom: false,
+        }
+    }
+
+    /// Make decoding invalid data an error instead of replacing it with U+FFFD.
+    pub fn with_fatal(mut self, fatal: bool) -> Self {
+        self.fatal = fatal;
+        self
+    }
+
+    /// Keep a leading byte order mark in the decoded string.
+    pub fn with_ignore_bom(mut self, ignore_bom: bool) -> Self {
+        self.ignore_bom = ignore_bom;
+        self
+    }
+
+    /// Returns the encoding of the decoder.
+    pub fn encoding(&self) -> TextEncoding {
+        self.encoding
+    }
+
+    /// Decode the bytes, returns `None` if the data is invalid and the decoder is fatal.
+    pub fn decode(&self, bytes: &[u8]) -> Option<String> {
+        match self.encoding {
+            TextEncoding::Utf8 => {
+                let bytes = match bytes.strip_prefix(b"\xEF\xBB\xBF") {
+                    Some(rest) if !self.ignore_bom => rest,
+                    _ => bytes,
+                };
+                if self.fatal {
+                    std::str::from_utf8(bytes).ok().map(str::to_owned)
+                } else {
+                    Some(String::from_utf8_lossy(bytes).into_owned())
+                }
+            }
+            TextEncoding::Utf16Le => {
+                let bytes = match bytes.strip_prefix(b"\xFF\xFE") {
+                    Some(rest) if !self.ignore_bom => rest,
+                    _ => bytes,
+                };
+                let chunks = bytes.chunks_exact(2);
+                let odd = !chunks.remainder().is_empty();
+                let units = chunks.map(|x| u16::from_le_bytes([x[0], x[1]]));
+                let mut res = String::with_capacity(bytes.len() / 2);
+                for c in char::decode_utf16(units) {
+                    match c {
+                        Ok(c) => res.push(c),
+                        Err(_) if self.fatal => return None,
+                        Err(_) => res.push(char::REPLACEMENT_CHARACTER),
+                    }
+                }
+                if odd {
+                    if self.fatal {
+                        return None;
+                    }
+                    res.push(char::REPLACEMENT_CHARACTER);
+                }
+                Some(res)
+            }
+        }
+    }
+
+    fn construct<'js>(
+        ctx: Ctx<'js>,
+        label: Opt<Coerced<String>>,
+        options: Opt<Value<'js>>,
+    ) -> Result<Self> {
+        let encoding = match label.0 {
+            Some(Coerced(label)) => TextEncoding::from_label(&label).ok_or_else(|| {
+                Exception::throw_range(&ctx, &format!("The \"{label}\" encoding is not supported"))
+            })?,
+            None => TextEncoding::Utf8,
+        };
+        let mut decoder = TextDecoder::new(encoding);
+        if let Some(options) = options.0.as_ref().and_then(Value::as_object) {
+            let Coerced(fatal) = options.get("fatal")?;
+            let Coerced(ignore_bom) = options.get("ignoreBOM")?;
+            decoder = decoder.with_fatal(fatal).with_ignore_bom(ignore_bom);
+        }
+        Ok(decoder)
+    }
+
+    fn decode_js<'js>(&self, ctx: &Ctx<'js>, input: Opt<Value<'js>>) -> Result<String> {
+        let Some(input) = input.0.filter(|x| !x.is_undefined()) else {
+            return Ok(String::new());
+        };
+        let not_buffer = || Exception::throw_type(ctx, "The input must be an ArrayBuffer or view");
+        let object = input.into_object().ok_or_else(not_buffer)?;
+        let res = if let Some(buffer) = ArrayBuffer::from_object(object.clone()) {
+            self.decode(buffer.as_bytes().ok_or_else(not_buffer)?)
+        } else {
+            // Typed arrays and data views.
+            let buffer: Value = object.get("buffer")?;
+            let buffer = buffer
+                .into_object()
+                .and_then(ArrayBuffer::from_object)
+                .ok_or_else(not_buffer)?;
+            let offset: usize = object.get("byteOffset")?;
+            let length: usize = object.get("byteLength")?;
+            let bytes = buffer.as_bytes().ok_or_else(not_buffer)?;
+            let bytes = offset
+                .checked_add(length)
+                .and_then(|end| bytes.get(offset..end))
+                .ok_or_else(not_buffer)?;
+            self.decode(bytes)
+        };
+        res.ok_or_else(|| {
+            Exception::throw_type(
+                ctx,
+                &format!("The encoded data was not valid {}", self.encoding.name()),
+            )
+        })
+    }
+}
+
+impl<'js> Trace<'js> for TextDecoder {
+    fn trace<'a>(&self, _tracer: Tracer<'a, 'js>) {}
+}
+
+unsafe impl<'js> JsLifetime<'js> for TextDecoder {
+    type Changed<'to> = TextDecoder;
+}
+
+impl<'js> JsClass<'js> for TextDecoder {
+    const NAME: &'static str = "TextDecoder";
+
+    type Mutable = Readable;
+
+    fn prototype(ctx: &Ctx<'js>) -> Result<Option<Object<'js>>> {
+        let proto = Object::new(ctx.clone())?;
+        let decode = Function::new(
+            ctx.clone(),
+            |ctx: Ctx<'js>, this: This<OwnedBorrow<'js, TextDecoder>>, input: Opt<Value<'js>>| {
+                this.decode_js(&ctx, input)
+            },
+        )?
+        .with_name("decode")?;
+        proto.set("decode", decode)?;
+        proto.prop(
+            "encoding",
+            Accessor::from(|this: This<OwnedBorrow<'js, TextDecoder>>| this.encoding.name()),
+        )?;
+        proto.prop(
+            "fatal",
+            Accessor::from(|this: This<OwnedBorrow<'js, TextDecoder>>| this.fatal),
+        )?;
+        proto.prop(
+            "ignoreBOM",
+            Accessor::from(|this: This<OwnedBorrow<'js, TextDecoder>>| this.ignore_bom),
+        )?;
+        Ok(Some(proto))
+    }
+
+    fn constructor(ctx: &Ctx<'js>) -> Result<Option<Constructor<'js>>> {
+        Constructor::new_class::<TextDecoder, _, _>(ctx.clone(), TextDecoder::construct).map(Some)
+    }
+}
+
+impl<'js> IntoJs<'js> for TextDecoder {
+    fn into_js(self, ctx: &Ctx<'js>) -> Result<Value<'js>> {
+        Class::instance(ctx.clone(), self)?.into_js(ctx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{TextDecoder, TextEncoding};
+    use crate::test_with;
+    use rquickjs_core::{CatchResultExt, Class, TypedArray};
+
+    #[test]
+    fn round_trip() {
+        test_with(|ctx| {
+            Class::<super::TextEncoder>::define(&ctx.globals()).unwrap();
+            Class::<TextDecoder>::define(&ctx.globals()).unwrap();
+
+            let bytes: TypedArray<u8> = ctx
+                .eval("new TextEncoder().encode('grüße, 世界 🎉')")
+                .catch(&ctx)
+                .unwrap();
+            assert_eq!(bytes.as_bytes().unwrap(), "grüße, 世界 🎉".as_bytes());
+
+            let res: String = ctx
+                .eval(
+                    r#"
+                    const encoder = new TextEncoder();
+                    const decoder = new TextDecoder();
+                    const bytes = encoder.encode("grüße, 世界 🎉");
+                    [
+                        encoder.encoding,
+                        decoder.encoding,
+                        decoder.decode(bytes),
+                        decoder.decode(bytes.buffer),
+                        decoder.decode(new DataView(bytes.buffer, 0, 3)),
+                        decoder.decode(),
+                    ].join("|")
+                    "#,
+                )
+                .catch(&ctx)
+                .unwrap();
+            assert_eq!(res, "utf-8|utf-8|grüße, 世界 🎉|grüße, 世界 🎉|gr\u{fffd}|");
+        })
+    }
+
+    #[test]
+    fn options() {
+        test_with(|ctx| {
+            Class::<TextDecoder>::define(&ctx.globals()).unwrap();
+
+            let res: String = ctx
+                .eval(
+                    r#"
+                    const bom = new Uint8Array([0xEF, 0xBB, 0xBF, 0x61]);
+                    const utf16 = new Uint8Array([0xFF, 0xFE, 0x61, 0x00, 0x3C, 0xD8, 0x89, 0xDF]);
+                    [
+                        new TextDecoder().decode(bom),
+                        new TextDecoder("utf-8", { ignoreBOM: true }).decode(bom).length,
+                        new TextDecoder("UTF-16LE").decode(utf16),
+                        new TextDecoder("utf-16le").encoding,
+                        new TextDecoder().decode(new Uint8Array([0x61, 0xFF])),
+                        new TextDecoder("utf-16le").decode(new Uint8Array([0x61])),
+                    ].join("|")
+                    "#,
+                )
+                .catch(&ctx)
+                .unwrap();
+            assert_eq!(res, "a|2|a🎉|utf-16le|a\u{fffd}|\u{fffd}");
+
+            let res: bool = ctx
+                .eval(
+                    r#"
+                    const decoder = new TextDecoder("utf-8", { fatal: true });
+                    try {
+                        decoder.decode(new Uint8Array([0x61, 0xFF]));
+                        false
+                    } catch (e) {
+                        decoder.fatal && e instanceof TypeError
+                    }
+                    "#,
+                )
+                .catch(&ctx)
+                .unwrap();
+            assert!(res);
+
+            let res: bool = ctx
+                .eval(r#"try { new TextDecoder("latin1"); false } catch (e) { e instanceof RangeError }"#)
+                .catch(&ctx)
+                .unwrap();
+            assert!(res);
+
+            let decoder = TextDecoder::new(TextEncoding::Utf16Le).with_fatal(true);
+            assert_eq!(decoder.decode(&[0x00, 0xD8]), None);
+            assert_eq!(
+                TextEncoding::from_label(" Unicode "),
+                Some(TextEncoding::Utf16Le)
+            );
+        })
+    }
+}
@@ -0,0 +1,405 @@
+use rquickjs_core::{
+    convert::Coerced,
+    function::{Opt, Rest},
+    CatchResultExt, Ctx, Function, Result, Value,
+};
+use std::{
+    cell::{Cell, RefCell},
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+    future::{poll_fn, Future},
+    rc::{Rc, Weak},
+    task::{Poll, Waker},
+    time::{Duration, Instant},
+};
+
+/// The `setTimeout`, `clearTimeout`, `setInterval` and `clearInterval` globals.
+///
+/// The active timers of an installation are kept in a queue ordered by deadline, which is driven
+/// by a single future spawned on the runtime with [`Ctx::spawn`]. The future sleeps until the next
+/// deadline with the timer of the executor selected by the `tokio` or `async-std` feature, so
+/// timers fire while the runtime is driven from that executor, for example by
+/// [`AsyncRuntime::idle`](rquickjs_core::AsyncRuntime::idle).
+///
+/// Like in browsers the delay is in milliseconds, missing or invalid delays are treated as `0`
+/// and extra arguments are passed on to the callback. Passing a string as callback is not
+/// supported. Exceptions thrown by a callback are discarded; an interval keeps running after its
+/// callback threw.
+///
+/// ```
+/// # use rquickjs::{async_with, AsyncContext, AsyncRuntime};
+/// # use rquickjs_util::Timers;
+/// # async fn run() {
+/// let rt = AsyncRuntime::new().unwrap();
+/// let ctx = AsyncContext::full(&rt).await.unwrap();
+/// async_with!(ctx => |ctx| {
+///     Timers::new().install(&ctx).unwrap();
+///     ctx.eval::<(), _>("setTimeout(() => { globalThis.done = true }, 10)").unwrap();
+/// })
+/// .await;
+/// rt.idle().await;
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Timers {
+    _private: (),
+}
+
+impl Timers {
+    /// Create the timer globals.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Define the timer functions on the global object of the given context.
+    ///
+    /// Timers created by the functions of one installation can only be cleared by the clear
+    /// functions of the same installation.
+    pub fn install<'js>(&self, ctx: &Ctx<'js>) -> Result<()> {
+        let installation = Rc::new(Installation::default());
+        let globals = ctx.globals();
+
+        for (name, repeat) in [("setTimeout", false), ("setInterval", true)] {
+            let installation = installation.clone();
+            let func = Function::new(
+                ctx.clone(),
+                move |ctx: Ctx<'js>,
+                      callback: Function<'js>,
+                      delay: Opt<Coerced<f64>>,
+                      args: Rest<Value<'js>>| {
+                    let delay = delay.0.map(|x| x.0).unwrap_or_default();
+                    installation.schedule(&ctx, callback, delay, args.0, repeat)
+                },
+            )?
+            .with_name(name)?;
+            globals.set(name, func)?;
+        }
+
+        for name in ["clearTimeout", "clearInterval"] {
+            let installation = installation.clone();
+            let func = Function::new(ctx.clone(), move |id: Opt<Value<'js>>| {
+                let id = id.0.as_ref().and_then(Value::as_number);
+                if let Some(id) =
+                    id.filter(|x| x.fract() == 0.0 && *x >= 0.0 && *x <= u32::MAX as f64)
+                {
+                    installation.clear(id as u32);
+                }
+            })?
+            .with_name(name)?;
+            globals.set(name, func)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The state shared by the timer functions of an installation.
+///
+/// Only the future driving the timers owns the queue, so the callbacks are released together
+/// with the other futures of the runtime when it is dropped.
+#[derive(Default)]
+struct Installation<'js> {
+    last_id: Cell<u32>,
+    /// The queue of the future driving the timers, dangling if no timer is active.
+    queue: RefCell<Weak<RefCell<Queue<'js>>>>,
+}
+
+impl<'js> Installation<'js> {
+    fn schedule(
+        &self,
+        ctx: &Ctx<'js>,
+        callback: Function<'js>,
+        delay: f64,
+        args: Vec<Value<'js>>,
+        repeat: bool,
+    ) -> u32 {
+        // Browsers limit the delay to a 32 bit signed integer.
+        let delay = if delay.is_finite() && delay > 0.0 {
+            Duration::from_secs_f64(delay.min(i32::MAX as f64) / 1000.0)
+        } else {
+            Duration::ZERO
+        };
+
+        let id = self.last_id.get().wrapping_add(1).max(1);
+        self.last_id.set(id);
+
+        let queue = self.queue.borrow().upgrade();
+        let queue = queue.unwrap_or_else(|| {
+            let queue = Rc::new(RefCell::new(Queue::default()));
+            *self.queue.borrow_mut() = Rc::downgrade(&queue);
+            ctx.spawn(drive(queue.clone()));
+            queue
+        });
+
+        let timer = Timer {
+            callback,
+            args,
+            interval: repeat.then_some(delay),
+        };
+        queue.borrow_mut().insert(id, Instant::now() + delay, timer);
+        id
+    }
+
+    fn clear(&self, id: u32) {
+        if let Some(queue) = self.queue.borrow().upgrade() {
+            queue.borrow_mut().remove(id);
+        }
+    }
+}
+
+struct Timer<'js> {
+    callback: Function<'js>,
+    args: Vec<Value<'js>>,
+    interval: Option<Duration>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Deadline {
+    at: Instant,
+    id: u32,
+}
+
+impl PartialOrd for Deadline {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Deadline {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so the binary heap pops the earliest deadline first.
+        (other.at, other.id).cmp(&(self.at, self.id))
+    }
+}
+
+enum Next<'js> {
+    Fire(Function<'js>, Vec<Value<'js>>),
+    Wait(Instant),
+    Empty,
+}
+
+/// The active timers of an installation.
+#[derive(Default)]
+struct Queue<'js> {
+    timers: HashMap<u32, Timer<'js>>,
+    /// The deadlines of the timers, deadlines of cleared timers are removed lazily.
+    deadlines: BinaryHeap<Deadline>,
+    /// Set when the timers changed while waiting on a deadline.
+    changed: bool,
+    waker: Option<Waker>,
+}
+
+impl<'js> Queue<'js> {
+    /// Intervals repeat at most once per millisecond so they can't starve the runtime.
+    const MIN_INTERVAL: Duration = Duration::from_millis(1);
+
+    fn insert(&mut self, id: u32, at: Instant, timer: Timer<'js>) {
+        self.timers.insert(id, timer);
+        self.deadlines.push(Deadline { at, id });
+        self.notify();
+    }
+
+    fn remove(&mut self, id: u32) {
+        if self.timers.remove(&id).is_some() {
+            self.notify();
+        }
+    }
+
+    fn notify(&mut self) {
+        self.changed = true;
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// Take the next timer which is due, rescheduling it if it is an interval.
+    fn next(&mut self, now: Instant) -> Next<'js> {
+        while let Some(&Deadline { at, id }) = self.deadlines.peek() {
+            let Some(timer) = self.timers.get(&id) else {
+                self.deadlines.pop();
+                continue;
+            };
+            if at > now {
+                return Next::Wait(at);
+            }
+            self.deadlines.pop();
+            let next = Next::Fire(timer.callback.clone(), timer.args.clone());
+            match timer.interval {
+                Some(interval) => self.deadlines.push(Deadline {
+                    at: now + interval.max(Self::MIN_INTERVAL),
+                    id,
+                }),
+                None => {
+                    self.timers.remove(&id);
+                }
+            }
+            return next;
+        }
+        Next::Empty
+    }
+}
+
+/// Fire the timers of the queue when they are due, returns when no timer is left.
+async fn drive<'js>(queue: Rc<RefCell<Queue<'js>>>) {
+    loop {
+        let next = queue.borrow_mut().next(Instant::now());
+        match next {
+            Next::Fire(callback, args) => {
+                let ctx = callback.ctx().clone();
+                // Errors can't be reported anywhere, catching clears the pending exception.
+                let _ = callback.call::<_, ()>((Rest(args),)).catch(&ctx);
+                // Like browsers run the jobs queued by the callback before the next timer fires.
+                while ctx.execute_pending_job() {}
+            }
+            Next::Wait(deadline) => wait(&queue, deadline).await,
+            Next::Empty => return,
+        }
+    }
+}
+
+/// Wait until the deadline passed or the timers changed.
+async fn wait(queue: &RefCell<Queue<'_>>, deadline: Instant) {
+    let mut sleep = Box::pin(sleep_until(deadline));
+    queue.borrow_mut().changed = false;
+    poll_fn(|cx| {
+        let mut queue = queue.borrow_mut();
+        if queue.changed {
+            return Poll::Ready(());
+        }
+        queue.waker = Some(cx.waker().clone());
+        sleep.as_mut().poll(cx)
+    })
+    .await
+}
+
+#[cfg(feature = "tokio")]
+async fn sleep_until(deadline: Instant) {
+    tokio::time::sleep_until(deadline.into()).await
+}
+
+#[cfg(all(feature = "async-std", not(feature = "tokio")))]
+async fn sleep_until(deadline: Instant) {
+    async_std::task::sleep(deadline.saturating_duration_since(Instant::now())).await
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Timers;
+    use rquickjs::{async_with, AsyncContext, AsyncRuntime};
+    use std::time::{Duration, Instant};
+
+    #[tokio::test]
+    async fn timeout() {
+        let rt = AsyncRuntime::new().unwrap();
+        let ctx = AsyncContext::full(&rt).await.unwrap();
+        async_with!(ctx => |ctx| {
+            Timers::new().install(&ctx).unwrap();
+            ctx.eval::<(), _>(
+                r#"
+                globalThis.order = [];
+                setTimeout(() => { globalThis.x = 1 }, 0);
+                setTimeout((a, b) => order.push(a + b), 20, 1, 2);
+                setTimeout(() => order.push(1));
+                const cleared = setTimeout(() => order.push("cleared"), 10);
+                clearTimeout(cleared);
+                clearTimeout(undefined);
+                "#,
+            )
+            .unwrap();
+        })
+        .await;
+
+        let start = Instant::now();
+        rt.idle().await;
+        assert!(start.elapsed() >= Duration::from_millis(20));
+
+        async_with!(ctx => |ctx| {
+            assert_eq!(ctx.eval::<i32, _>("x").unwrap(), 1);
+            assert_eq!(ctx.eval::<Vec<i32>, _>("order").unwrap(), vec![1, 3]);
+
+            // Timers still work after all previous timers finished.
+            ctx.eval::<(), _>("setTimeout(() => order.push(4), 1)").unwrap();
+        })
+        .await;
+        rt.idle().await;
+
+        async_with!(ctx => |ctx| {
+            assert_eq!(ctx.eval::<Vec<i32>, _>("order").unwrap(), vec![1, 3, 4]);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn jobs_run_between_timers() {
+        let rt = AsyncRuntime::new().unwrap();
+        let ctx = AsyncContext::full(&rt).await.unwrap();
+        async_with!(ctx => |ctx| {
+            Timers::new().install(&ctx).unwrap();
+            ctx.eval::<(), _>(
+                r#"
+                globalThis.order = [];
+                setTimeout(() => {
+                    order.push("first");
+                    Promise.resolve().then(() => order.push("job"));
+                });
+                setTimeout(() => order.push("second"));
+                "#,
+            )
+            .unwrap();
+        })
+        .await;
+        rt.idle().await;
+
+        async_with!(ctx => |ctx| {
+            assert_eq!(
+                ctx.eval::<Vec<String>, _>("order").unwrap(),
+                vec!["first", "job", "second"]
+            );
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn interval() {
+        let rt = AsyncRuntime::new().unwrap();
+        let ctx = AsyncContext::full(&rt).await.unwrap();
+        async_with!(ctx => |ctx| {
+            Timers::new().install(&ctx).unwrap();
+            ctx.eval::<(), _>(
+                r#"
+                globalThis.count = 0;
+                const id = setInterval(() => {
+                    count += 1;
+                    if (count == 3) {
+                        clearInterval(id);
+                    }
+                    throw new Error("ignored");
+                }, 1);
+                "#,
+            )
+            .unwrap();
+        })
+        .await;
+        rt.idle().await;
+
+        async_with!(ctx => |ctx| {
+            assert_eq!(ctx.eval::<i32, _>("count").unwrap(), 3);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn pending_timers_dropped_with_runtime() {
+        let rt = AsyncRuntime::new().unwrap();
+        let ctx = AsyncContext::full(&rt).await.unwrap();
+        async_with!(ctx => |ctx| {
+            Timers::new().install(&ctx).unwrap();
+            // The callback references the global object which holds the timer functions.
+            ctx.eval::<(), _>("setTimeout(() => { globalThis.x = 1 }, 60000)")
+                .unwrap();
+        })
+        .await;
+        drop(ctx);
+        drop(rt);
+    }
+}